@@ -1,4 +1,5 @@
 use adw::prelude::*;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use relm4::{
@@ -7,8 +8,8 @@ use relm4::{
 };
 
 use crate::{
-    components::news_tile::NewsTile,
-    types::{cache::ImageCache, news_article::NewsArticle},
+    components::news_tile::{NewsTile, NewsTileOutput},
+    types::{cache::ImageCache, news_article::NewsArticle, persistent_articel::PersistentArticle},
 };
 
 #[derive(Debug)]
@@ -17,6 +18,8 @@ pub struct CategorisedNewsSection {
     articles: Vec<Arc<dyn NewsArticle>>,
     tiles: FactoryVecDeque<NewsTile>,
     cache: ImageCache,
+    // ids of the articles with a note
+    noted: Arc<HashSet<String>>,
     grid: gtk::FlowBox,
 }
 
@@ -24,13 +27,19 @@ pub struct CategorisedNewsSection {
 pub enum SectionInput {
     Initialize,
     RequestNewspage((Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>)),
+    Bookmark(Arc<dyn NewsArticle>),
 }
 
 #[relm4::factory(pub)]
 impl FactoryComponent for CategorisedNewsSection {
-    type Init = (String, Vec<Arc<dyn NewsArticle>>, ImageCache);
+    type Init = (
+        String,
+        Vec<Arc<dyn NewsArticle>>,
+        ImageCache,
+        Arc<HashSet<String>>,
+    );
     type Input = SectionInput;
-    type Output = (Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>);
+    type Output = NewsTileOutput;
     type ParentWidget = gtk::Box;
     type CommandOutput = ();
 
@@ -69,7 +78,7 @@ impl FactoryComponent for CategorisedNewsSection {
     }
 
     fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
-        let (category, articles, cache) = init;
+        let (category, articles, cache, noted) = init;
         sender.input(SectionInput::Initialize);
         let grid = gtk::FlowBox::builder()
             .selection_mode(gtk::SelectionMode::None)
@@ -98,8 +107,9 @@ impl FactoryComponent for CategorisedNewsSection {
         println!("Initialized for {category}");
         let tiles = FactoryVecDeque::builder()
             .launch(grid.clone())
-            .forward(sender.input_sender(), move |data| {
-                SectionInput::RequestNewspage(data)
+            .forward(sender.input_sender(), move |output| match output {
+                NewsTileOutput::Open(data) => SectionInput::RequestNewspage(data),
+                NewsTileOutput::Bookmark(article) => SectionInput::Bookmark(article),
             });
 
         let model = Self {
@@ -107,6 +117,7 @@ impl FactoryComponent for CategorisedNewsSection {
             articles,
             tiles,
             cache,
+            noted,
             grid,
         };
 
@@ -150,7 +161,10 @@ impl FactoryComponent for CategorisedNewsSection {
                     .position(|a| a.title() == article.title())
                     .unwrap_or(0),
             );
-            guard.push_back((article, related, self.cache.clone()));
+            let has_note = self
+                .noted
+                .contains(&PersistentArticle::id_for(&article.url()));
+            guard.push_back((article, related, self.cache.clone(), has_note));
         }
     }
 
@@ -158,7 +172,12 @@ impl FactoryComponent for CategorisedNewsSection {
         match msg {
             SectionInput::Initialize => {}
             SectionInput::RequestNewspage(data) => {
-                let _ = sender.output_sender().send(data);
+                let _ = sender.output_sender().send(NewsTileOutput::Open(data));
+            }
+            SectionInput::Bookmark(article) => {
+                let _ = sender
+                    .output_sender()
+                    .send(NewsTileOutput::Bookmark(article));
             }
         }
     }
@@ -1,5 +1,6 @@
 use gtk::prelude::*;
 use relm4::prelude::*;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::{
@@ -14,24 +15,39 @@ pub struct HistoryBucket {
     articles: Vec<Arc<PersistentArticle>>,
     listbox: gtk::ListBox,
     select_mode_on: bool,
+    // ids of this day's rows that are selected
+    selected: HashSet<String>,
 }
 
 #[derive(Debug)]
 pub enum HistoryBucketOutput {
     DeleteEntry(String),
+    Selected(String),
+    Deselected(String),
     Dummy,
 }
 
 #[derive(Debug, Clone)]
 pub enum HistoryBucketInput {
     ActivateSelectionMode,
+    DeactivateSelectionMode,
+    SelectAll(bool),
+    RowSelected(String),
+    RowDeselected(String),
     DeleteEntry(String),
     Dummy,
 }
 
 #[relm4::factory(pub)]
 impl FactoryComponent for HistoryBucket {
-    type Init = (String, Vec<Arc<PersistentArticle>>);
+    /// Day label, its entries, and the selection to restore when the page is in selection mode
+    type Init = (
+        String,
+        Vec<Arc<PersistentArticle>>,
+        // ids of the articles with a note
+        Arc<HashSet<String>>,
+        Option<HashSet<String>>,
+    );
     type Input = HistoryBucketInput;
     type Output = HistoryBucketOutput;
     type ParentWidget = gtk::Box;
@@ -71,6 +87,19 @@ impl FactoryComponent for HistoryBucket {
                 gtk::Image {
                     set_icon_name: Some("pan-down-symbolic"),
                     set_pixel_size: 25
+                },
+
+                gtk::CheckButton {
+                    set_label: Some("Select All"),
+                    set_hexpand: true,
+                    set_halign: gtk::Align::End,
+                    #[watch]
+                    set_visible: self.select_mode_on,
+                    #[watch]
+                    set_active: self.all_selected(),
+                    connect_toggled[sender] => move |btn| {
+                        sender.input(HistoryBucketInput::SelectAll(btn.is_active()));
+                    }
                 }
             },
 
@@ -87,7 +116,7 @@ impl FactoryComponent for HistoryBucket {
     }
 
     fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
-        let (title, articles) = init;
+        let (title, articles, noted, selection) = init;
 
         let listbox = gtk::ListBox::new();
         listbox.add_css_class("boxed-list");
@@ -97,15 +126,33 @@ impl FactoryComponent for HistoryBucket {
             sender.input_sender(),
             move |message| match message {
                 HistoryRowOutput::Delete(id) => HistoryBucketInput::DeleteEntry(id),
+                HistoryRowOutput::Selected(id) => HistoryBucketInput::RowSelected(id),
+                HistoryRowOutput::Deselected(id) => HistoryBucketInput::RowDeselected(id),
                 _ => HistoryBucketInput::Dummy,
             },
         );
 
         // 2. Populate the rows immediately inside init_model
+        let select_mode_on = selection.is_some();
+        let selected: HashSet<String> = articles
+            .iter()
+            .filter(|article| {
+                selection
+                    .as_ref()
+                    .is_some_and(|ids| ids.contains(&article.id))
+            })
+            .map(|article| article.id.clone())
+            .collect();
+
         {
             let mut guard = rows.guard();
             for article in &articles {
-                guard.push_back(article.clone());
+                guard.push_back((
+                    article.clone(),
+                    noted.contains(&article.id),
+                    select_mode_on,
+                    selected.contains(&article.id),
+                ));
             }
         }
 
@@ -114,20 +161,43 @@ impl FactoryComponent for HistoryBucket {
             rows,
             articles,
             listbox,
-            select_mode_on: false,
+            select_mode_on,
+            selected,
         }
     }
 
     // Standard update
     fn update(&mut self, message: Self::Input, sender: FactorySender<Self>) {
         match message {
+            HistoryBucketInput::DeactivateSelectionMode => {
+                self.select_mode_on = false;
+                self.selected.clear();
+                self.rows.broadcast(HistoryRowInput::SeletctMode(false));
+            }
+            HistoryBucketInput::SelectAll(select) => {
+                // the check button also toggles when it follows the rows, which needs no action
+                if select && !self.all_selected() {
+                    self.rows.broadcast(HistoryRowInput::ActivateSelectAll);
+                } else if !select && self.all_selected() {
+                    self.rows.broadcast(HistoryRowInput::DeactivateSelectAll);
+                }
+            }
+            HistoryBucketInput::RowSelected(id) => {
+                self.selected.insert(id.clone());
+                let _ = sender.output(HistoryBucketOutput::Selected(id));
+            }
+            HistoryBucketInput::RowDeselected(id) => {
+                self.selected.remove(&id);
+                let _ = sender.output(HistoryBucketOutput::Deselected(id));
+            }
             HistoryBucketInput::DeleteEntry(id) => {
-                //self.rows.broadcast(HistoryRowInput::DeactivateSelectAll);
+                let _ = sender.output(HistoryBucketOutput::DeleteEntry(id));
             }
             HistoryBucketInput::Dummy => {
                 sender.output(HistoryBucketOutput::Dummy);
             }
             HistoryBucketInput::ActivateSelectionMode => {
+                self.select_mode_on = true;
                 self.rows.broadcast(HistoryRowInput::ActivateSelectionMode);
             }
         }
@@ -135,6 +205,10 @@ impl FactoryComponent for HistoryBucket {
 }
 
 impl HistoryBucket {
+    fn all_selected(&self) -> bool {
+        !self.articles.is_empty() && self.selected.len() == self.articles.len()
+    }
+
     pub fn setup_collapsible_section(
         header: &gtk::Box,
         revealer: &gtk::Revealer,
@@ -9,14 +9,14 @@ pub struct HistoryRow {
     article: Arc<PersistentArticle>,
     selection_mode_on: bool,
     selected: bool,
+    has_note: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum HistoryRowInput {
     ShowMenu,
     SeletctMode(bool),
-    Select,
-    Deselect,
+    Toggled(bool),
     ActivateSelectAll,
     DeactivateSelectAll,
     ActivateSelectionMode,
@@ -32,7 +32,9 @@ pub enum HistoryRowOutput {
 
 #[relm4::factory(pub)]
 impl FactoryComponent for HistoryRow {
-    type Init = Arc<PersistentArticle>;
+    /// The article, whether it has a note, whether selection mode is on and whether the
+    /// row starts out selected
+    type Init = (Arc<PersistentArticle>, bool, bool, bool);
     type Input = HistoryRowInput;
     type Output = HistoryRowOutput;
     type ParentWidget = gtk::ListBox;
@@ -55,13 +57,10 @@ impl FactoryComponent for HistoryRow {
 
                     gtk::CheckButton{
                         add_css_class: "selection-mode",
+                        #[watch]
+                        set_active: self.selected,
                         connect_toggled[sender] => move |btn|{
-                            if btn.is_active() {
-                                sender.input(HistoryRowInput::Select);
-                            } else {
-                                 sender.input(HistoryRowInput::Deselect);
-                            }
-
+                            sender.input(HistoryRowInput::Toggled(btn.is_active()));
                         }
                     }
 
@@ -98,6 +97,14 @@ impl FactoryComponent for HistoryRow {
                     }
                 },
 
+                gtk::Image {
+                    set_icon_name: Some("document-edit-symbolic"),
+                    set_tooltip_text: Some("You have notes on this article"),
+                    set_valign: gtk::Align::Center,
+                    set_visible: self.has_note,
+                    add_css_class: "dim-label",
+                },
+
                 gtk::Box {
                     set_orientation: gtk::Orientation::Vertical,
                     set_valign: gtk::Align::Center,
@@ -119,11 +126,14 @@ impl FactoryComponent for HistoryRow {
         }
     }
 
-    fn init_model(article: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
+    fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
+        let (article, has_note, selection_mode_on, selected) = init;
+
         Self {
             article,
-            selection_mode_on: false,
-            selected: false,
+            selection_mode_on,
+            selected: selection_mode_on && selected,
+            has_note,
         }
     }
 
@@ -140,40 +150,51 @@ impl FactoryComponent for HistoryRow {
             }
             HistoryRowInput::SeletctMode(on) => {
                 self.selection_mode_on = on;
+                if !on {
+                    // the page drops its selection along with the mode
+                    self.selected = false;
+                }
             }
-            HistoryRowInput::Select => {
-                self.selected = true;
-                let _ = sender.output(HistoryRowOutput::Selected(self.article.id.clone()));
-                println!("Selected: {}", self.selected);
-            }
-            HistoryRowInput::Deselect => {
-                self.selected = false;
-                let _ = sender.output(HistoryRowOutput::Deselected(self.article.id.clone()));
-                println!("Selected: {}", self.selected);
+            HistoryRowInput::Toggled(active) => {
+                self.set_selected(active, &sender);
             }
             HistoryRowInput::ActivateSelectAll => {
                 if !self.selection_mode_on {
                     return;
                 }
-
-                self.selected = true;
+                self.set_selected(true, &sender);
             }
             HistoryRowInput::DeactivateSelectAll => {
                 if !self.selection_mode_on {
                     return;
                 }
-                self.selected = false;
+                self.set_selected(false, &sender);
             }
             HistoryRowInput::ActivateSelectionMode => {
                 self.selection_mode_on = true;
             }
         }
 
-        self.update(message, sender);
+        self.update_view(widgets, sender);
     }
 }
 
 impl HistoryRow {
+    /// Reports a change of selection, unless the row was already in that state
+    fn set_selected(&mut self, selected: bool, sender: &FactorySender<Self>) {
+        if self.selected == selected {
+            return;
+        }
+
+        self.selected = selected;
+        let id = self.article.id.clone();
+        let _ = sender.output(if selected {
+            HistoryRowOutput::Selected(id)
+        } else {
+            HistoryRowOutput::Deselected(id)
+        });
+    }
+
     fn show_menu(entry_id: String, button: &gtk::Button, sender: FactorySender<Self>) {
         let menu_list = gtk::ListBox::builder().build();
 
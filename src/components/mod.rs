@@ -2,4 +2,6 @@ pub mod categorised_news;
 pub mod history_bucket;
 pub mod history_row;
 pub mod news_tile;
+pub mod saved_bucket;
+pub mod saved_row;
 pub mod sidebar;
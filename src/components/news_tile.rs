@@ -9,18 +9,34 @@ pub struct NewsTile {
     article: Arc<dyn NewsArticle>,
     cache: ImageCache,
     related_articles: Vec<Arc<dyn NewsArticle>>,
+    // one link per collapsed copy of the story, shown in the "also covered by" popover
+    coverage_links: Vec<gtk::LinkButton>,
+    has_note: bool,
 }
 
 #[derive(Debug)]
 pub enum NewsTileInput {
     Clicked,
+    Bookmark,
+}
+
+#[derive(Debug)]
+pub enum NewsTileOutput {
+    Open((Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>)),
+    Bookmark(Arc<dyn NewsArticle>),
 }
 
 #[relm4::factory(pub)]
 impl FactoryComponent for NewsTile {
-    type Init = (Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>, ImageCache);
+    type Init = (
+        Arc<dyn NewsArticle>,
+        Vec<Arc<dyn NewsArticle>>,
+        ImageCache,
+        // whether the article has a note, looked up once for the whole page
+        bool,
+    );
     type Input = NewsTileInput;
-    type Output = (Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>);
+    type Output = NewsTileOutput;
     type ParentWidget = gtk::FlowBox;
     type CommandOutput = ();
 
@@ -72,12 +88,72 @@ impl FactoryComponent for NewsTile {
                 add_css_class: "spacer",
             },
 
-            gtk::Label {
-                #[watch]
-                set_label: chrono::DateTime::parse_from_rfc3339(&self.article.published_at()).map(|dt| dt.format("%b %d").to_string()).unwrap_or_else(|_| self.article.published_at().clone()).as_str(),
-                set_xalign: 0.0,
+            gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+                set_spacing: 6,
                 set_margin_horizontal: 2,
-                add_css_class: "news-tile-date",
+
+                gtk::Label {
+                    #[watch]
+                    set_label: chrono::DateTime::parse_from_rfc3339(&self.article.published_at()).map(|dt| dt.format("%b %d").to_string()).unwrap_or_else(|_| self.article.published_at().clone()).as_str(),
+                    set_xalign: 0.0,
+                    add_css_class: "news-tile-date",
+                },
+
+                gtk::MenuButton {
+                    set_visible: !self.coverage_links.is_empty(),
+                    set_label: &match self.coverage_links.len() {
+                        1 => "also covered by 1 source".to_string(),
+                        n => format!("also covered by {} sources", n),
+                    },
+                    set_always_show_arrow: true,
+                    add_css_class: "flat",
+                    add_css_class: "caption",
+
+                    #[wrap(Some)]
+                    set_popover = &gtk::Popover {
+                        gtk::ScrolledWindow {
+                            set_hscrollbar_policy: gtk::PolicyType::Never,
+                            set_propagate_natural_height: true,
+                            set_max_content_height: 300,
+
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 2,
+
+                                #[iterate]
+                                append: self.coverage_links.iter(),
+                            }
+                        }
+                    },
+                },
+
+                gtk::Label {
+                    set_visible: self.article.provider().is_some(),
+                    set_label: &format!("via {}", self.article.provider().unwrap_or_default()),
+                    set_hexpand: true,
+                    set_xalign: 1.0,
+                    set_ellipsize: pango::EllipsizeMode::End,
+                    add_css_class: "caption",
+                    add_css_class: "dim-label",
+                },
+
+                gtk::Image {
+                    set_icon_name: Some("document-edit-symbolic"),
+                    set_tooltip_text: Some("You have notes on this article"),
+                    set_visible: self.has_note,
+                    add_css_class: "dim-label",
+                },
+
+                gtk::Button {
+                    set_icon_name: "user-bookmarks-symbolic",
+                    set_tooltip: "Save",
+                    add_css_class: "flat",
+                    add_css_class: "circular",
+                    connect_clicked[sender] => move |_| {
+                        sender.input(NewsTileInput::Bookmark);
+                    }
+                },
             },
 
             add_controller = gtk::GestureClick {
@@ -91,12 +167,29 @@ impl FactoryComponent for NewsTile {
     }
 
     fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
-        let (article, related_articles, cache) = init;
+        let (article, related_articles, cache, has_note) = init;
+
+        let coverage_links = article
+            .coverage()
+            .iter()
+            .map(|copy| {
+                let label = match copy.provider() {
+                    Some(provider) => format!("{} ({})", copy.source(), provider),
+                    None => copy.source(),
+                };
+                let link = gtk::LinkButton::with_label(&copy.url(), &label);
+                link.set_halign(gtk::Align::Start);
+                link.set_tooltip_text(Some(&copy.title()));
+                link
+            })
+            .collect();
 
         Self {
             article,
             cache,
             related_articles,
+            coverage_links,
+            has_note,
         }
     }
 
@@ -116,9 +209,15 @@ impl FactoryComponent for NewsTile {
     fn update(&mut self, msg: Self::Input, sender: FactorySender<Self>) {
         match msg {
             NewsTileInput::Clicked => {
+                let _ = sender.output_sender().send(NewsTileOutput::Open((
+                    self.article.clone(),
+                    self.related_articles.clone(),
+                )));
+            }
+            NewsTileInput::Bookmark => {
                 let _ = sender
                     .output_sender()
-                    .send((self.article.clone(), self.related_articles.clone()));
+                    .send(NewsTileOutput::Bookmark(self.article.clone()));
             }
         }
     }
@@ -0,0 +1,69 @@
+use gtk::prelude::*;
+use relm4::prelude::*;
+
+use crate::{
+    components::saved_row::{SavedRow, SavedRowOutput},
+    types::bookmark::Bookmark,
+};
+
+/// Bookmarks saved on the same day
+#[derive(Debug)]
+pub struct SavedBucket {
+    title: String,
+    rows: FactoryVecDeque<SavedRow>,
+    listbox: gtk::ListBox,
+}
+
+#[relm4::factory(pub)]
+impl FactoryComponent for SavedBucket {
+    type Init = (String, Vec<Bookmark>);
+    type Input = SavedRowOutput;
+    type Output = SavedRowOutput;
+    type ParentWidget = gtk::Box;
+    type CommandOutput = ();
+
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 12,
+
+            gtk::Label {
+                set_label: &self.title.to_uppercase(),
+                set_xalign: 0.0,
+                add_css_class: "section-label",
+                add_css_class: "dimmed",
+            },
+
+            append: &self.listbox,
+        }
+    }
+
+    fn init_model(init: Self::Init, _index: &DynamicIndex, sender: FactorySender<Self>) -> Self {
+        let (title, bookmarks) = init;
+
+        let listbox = gtk::ListBox::new();
+        listbox.add_css_class("boxed-list");
+        listbox.set_selection_mode(gtk::SelectionMode::None);
+
+        let mut rows = FactoryVecDeque::builder()
+            .launch(listbox.clone())
+            .forward(sender.input_sender(), |output| output);
+
+        {
+            let mut guard = rows.guard();
+            for bookmark in bookmarks {
+                guard.push_back(bookmark);
+            }
+        }
+
+        Self {
+            title,
+            rows,
+            listbox,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: FactorySender<Self>) {
+        let _ = sender.output(msg);
+    }
+}
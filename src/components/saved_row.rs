@@ -0,0 +1,168 @@
+use gtk::{pango, prelude::*};
+use relm4::prelude::*;
+
+use crate::types::bookmark::Bookmark;
+
+#[derive(Debug)]
+pub struct SavedRow {
+    bookmark: Bookmark,
+}
+
+#[derive(Debug)]
+pub enum SavedRowInput {
+    Open,
+    Edit,
+    Remove,
+}
+
+#[derive(Debug)]
+pub enum SavedRowOutput {
+    Open(Bookmark),
+    Edit(Bookmark),
+    Remove(String),
+}
+
+#[relm4::factory(pub)]
+impl FactoryComponent for SavedRow {
+    type Init = Bookmark;
+    type Input = SavedRowInput;
+    type Output = SavedRowOutput;
+    type ParentWidget = gtk::ListBox;
+    type CommandOutput = ();
+
+    view! {
+        gtk::ListBoxRow {
+            set_activatable: true,
+
+            gtk::Box {
+                set_spacing: 16,
+                set_margin_all: 10,
+
+                add_controller = gtk::GestureClick {
+                    connect_released[sender] => move |_, _, _, _| {
+                        sender.input(SavedRowInput::Open);
+                    }
+                },
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_hexpand: true,
+                    set_spacing: 4,
+
+                    gtk::Label {
+                        set_label: &self.bookmark.article.title,
+                        set_wrap: true,
+                        set_lines: 2,
+                        set_xalign: 0.0,
+                        set_ellipsize: pango::EllipsizeMode::End,
+                        add_css_class: "document",
+                    },
+
+                    gtk::Label {
+                        set_label: self.bookmark.article.description.as_deref().unwrap_or(""),
+                        set_visible: self.bookmark.article.description.is_some(),
+                        set_wrap: true,
+                        set_lines: 2,
+                        set_xalign: 0.0,
+                        set_ellipsize: pango::EllipsizeMode::End,
+                        add_css_class: "document",
+                        add_css_class: "dim-label",
+                    },
+
+                    gtk::Label {
+                        set_label: &self.caption(),
+                        set_xalign: 0.0,
+                        set_ellipsize: pango::EllipsizeMode::End,
+                        add_css_class: "caption",
+                        add_css_class: "dim-label",
+                    },
+
+                    gtk::Label {
+                        set_label: &self.labels(),
+                        set_visible: self.bookmark.folder.is_some() || !self.bookmark.tags.is_empty(),
+                        set_xalign: 0.0,
+                        set_ellipsize: pango::EllipsizeMode::End,
+                        add_css_class: "caption",
+                        add_css_class: "accent",
+                    },
+                },
+
+                gtk::Button {
+                    set_icon_name: "document-edit-symbolic",
+                    set_tooltip: "Folder and Tags",
+                    set_valign: gtk::Align::Center,
+                    add_css_class: "flat",
+                    add_css_class: "circular",
+                    connect_clicked[sender] => move |_| {
+                        sender.input(SavedRowInput::Edit);
+                    }
+                },
+
+                gtk::Button {
+                    set_icon_name: "user-trash-symbolic",
+                    set_tooltip: "Remove from Saved",
+                    set_valign: gtk::Align::Center,
+                    add_css_class: "flat",
+                    add_css_class: "circular",
+                    connect_clicked[sender] => move |_| {
+                        sender.input(SavedRowInput::Remove);
+                    }
+                }
+            }
+        }
+    }
+
+    fn init_model(bookmark: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { bookmark }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: FactorySender<Self>) {
+        match msg {
+            SavedRowInput::Open => {
+                let _ = sender.output(SavedRowOutput::Open(self.bookmark.clone()));
+            }
+            SavedRowInput::Edit => {
+                let _ = sender.output(SavedRowOutput::Edit(self.bookmark.clone()));
+            }
+            SavedRowInput::Remove => {
+                let _ = sender.output(SavedRowOutput::Remove(self.bookmark.article.id.clone()));
+            }
+        }
+    }
+}
+
+impl SavedRow {
+    /// Folder followed by the tags, e.g. "Project X · #rust #gtk"
+    fn labels(&self) -> String {
+        let tags = self
+            .bookmark
+            .tags
+            .iter()
+            .map(|tag| format!("#{}", tag))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        [self.bookmark.folder.clone().unwrap_or_default(), tags]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+
+    /// Host of the article link and the day it was published
+    fn caption(&self) -> String {
+        let host = url::Url::parse(&self.bookmark.article.url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.trim_start_matches("www.").to_string()))
+            .unwrap_or_default();
+        let published = chrono::DateTime::parse_from_rfc3339(&self.bookmark.article.published_at)
+            .map(|dt| dt.format("%b %d, %Y").to_string())
+            .unwrap_or_default();
+
+        [host, published]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
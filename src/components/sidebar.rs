@@ -1,39 +1,89 @@
+use std::sync::Arc;
+
 use adw::prelude::*;
 use relm4::{Component, ComponentParts, ComponentSender, prelude::*};
 
-use crate::{data::sections::SECTIONS, types::news_category::NewsSection};
+use crate::{
+    data::sections::SECTIONS,
+    services::workers::{
+        endpoint_worker::{EndpointWorker, EndpointWorkerInput},
+        subscription_worker::{SubscriptionWorker, SubscriptionWorkerInput},
+        topic_worker::{TopicWorker, TopicWorkerInput},
+    },
+    types::{
+        custom_endpoint::{CustomEndpoint, FieldMapping},
+        feed_subscription::FeedSubscription,
+        news_category::NewsSection,
+        saved_topic::SavedTopic,
+    },
+};
 
 #[derive(Debug)]
 pub enum NavigationPage {
     Saved,
+    Notes,
     History,
+    Stats,
     Settings,
-    CustomEndpoint(),
+    Search,
+    CustomEndpoint(CustomEndpoint),
+    Topic(SavedTopic),
     Category(String),
 }
 
 impl NavigationPage {
     pub fn to_key(&self) -> String {
-        format!("{:?}", self)
+        match self {
+            Self::CustomEndpoint(endpoint) => format!("CustomEndpoint({})", endpoint.id),
+            Self::Topic(topic) => format!("Topic({})", topic.id),
+            _ => format!("{:?}", self),
+        }
     }
 }
 
 #[derive(Debug)]
-pub struct SideBar {}
+pub struct SideBar {
+    subscription_worker: Arc<Controller<SubscriptionWorker>>,
+    endpoint_worker: Arc<Controller<EndpointWorker>>,
+    topic_worker: Arc<Controller<TopicWorker>>,
+    subscriptions: Vec<FeedSubscription>,
+    endpoints: Vec<CustomEndpoint>,
+    topics: Vec<SavedTopic>,
+}
 
 #[derive(Debug)]
 pub enum SidebarMessage {
     ToggleSidebar,
     SelectSection(NewsSection),
     SelectPage(NavigationPage),
+    Search(String),
 }
 
 #[derive(Debug)]
-pub enum SidebarInput {}
+pub enum SidebarInput {
+    UpdateSubscriptions(Vec<FeedSubscription>),
+    UpdateEndpoints(Vec<CustomEndpoint>),
+    UpdateTopics(Vec<SavedTopic>),
+    OpenCustomRow(String),
+    OpenTopic(i64),
+    MoveTopic(i64, i32),
+    DeleteTopic(i64),
+    AddFeed,
+    SaveFeed(FeedSubscription),
+    AddEndpoint,
+    SaveEndpoint(CustomEndpoint),
+    ImportOpml,
+    ExportOpml,
+    ShowResult(String),
+}
 
 #[relm4::component(pub)]
 impl Component for SideBar {
-    type Init = ();
+    type Init = (
+        Arc<Controller<SubscriptionWorker>>,
+        Arc<Controller<EndpointWorker>>,
+        Arc<Controller<TopicWorker>>,
+    );
     type Input = SidebarInput;
     type Output = SidebarMessage;
     type CommandOutput = ();
@@ -64,6 +114,12 @@ impl Component for SideBar {
                     gtk::SearchEntry {
                         set_placeholder_text: Some("Search..."),
                         set_margin_horizontal: 16,
+                        connect_activate[sender] => move |entry| {
+                            let query = entry.text().trim().to_string();
+                            if !query.is_empty() {
+                                let _ = sender.output(SidebarMessage::Search(query));
+                            }
+                        }
                     },
 
                     #[name = "topheadlineslist"]
@@ -96,60 +152,34 @@ impl Component for SideBar {
                 },
 
                 #[wrap(Some)]
-                set_content = &gtk::ScrolledWindow {
-                    set_hscrollbar_policy: gtk::PolicyType::Never,
-
-                    gtk::Box {
-                        set_orientation: gtk::Orientation::Vertical,
-                        set_spacing: 8,
-
-                        // --- Library Section ---
-                        #[name = "library_header"]
-                        gtk::Box {
-                            add_css_class: "sidebar-header-box",
-                            set_margin_horizontal: 20,
-                            gtk::Label {
-                                set_label: "Library",
-                                add_css_class: "sidebar-section-title",
-                                add_css_class: "dimmed",
-                            },
-                            gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
-                            #[name = "library_chevron"]
-                            gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
-                        },
-                        #[name = "library_revealer"]
-                        gtk::Revealer {
-                            set_reveal_child: true,
-                            #[name = "library"]
-                            gtk::ListBox {
-                                // Start with None to prevent auto-selection during population
-                                set_selection_mode: gtk::SelectionMode::None,
-                                set_margin_horizontal: 12,
-                                add_css_class: "navigation-sidebar"
-                            }
-                        },
+                #[name = "toast_overlay"]
+                set_content = &adw::ToastOverlay {
+                    #[wrap(Some)]
+                    set_child = &gtk::ScrolledWindow {
+                        set_hscrollbar_policy: gtk::PolicyType::Never,
 
-                         // --- Custom End Point---
                         gtk::Box {
-                            set_visible: false,
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 8,
 
-                            #[name = "custom_endpoints_header"]
+                            // --- Library Section ---
+                            #[name = "library_header"]
                             gtk::Box {
                                 add_css_class: "sidebar-header-box",
                                 set_margin_horizontal: 20,
                                 gtk::Label {
-                                    set_label: "Custom Endpoints",
+                                    set_label: "Library",
                                     add_css_class: "sidebar-section-title",
                                     add_css_class: "dimmed",
                                 },
                                 gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
-                                #[name = "custom_endpoints_chevron"]
+                                #[name = "library_chevron"]
                                 gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
                             },
-                            #[name = "custom_endpoints_revealer"]
+                            #[name = "library_revealer"]
                             gtk::Revealer {
                                 set_reveal_child: true,
-                                #[name = "custom_endpoints"]
+                                #[name = "library"]
                                 gtk::ListBox {
                                     // Start with None to prevent auto-selection during population
                                     set_selection_mode: gtk::SelectionMode::None,
@@ -157,29 +187,27 @@ impl Component for SideBar {
                                     add_css_class: "navigation-sidebar"
                                 }
                             },
-                        },
-
-                        // --- Categories ---
-                        gtk::Box{
-                            set_visible: false,
 
-                            #[name = "categories_header"]
+                            // --- Topics Section ---
+                            #[name = "topics_header"]
                             gtk::Box {
                                 add_css_class: "sidebar-header-box",
                                 set_margin_horizontal: 20,
+                                #[watch]
+                                set_visible: !model.topics.is_empty(),
                                 gtk::Label {
-                                    set_label: "Categories",
+                                    set_label: "Topics",
                                     add_css_class: "sidebar-section-title",
                                     add_css_class: "dimmed",
                                 },
                                 gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
-                                #[name = "categories_chevron"]
+                                #[name = "topics_chevron"]
                                 gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
                             },
-                            #[name = "categories_revealer"]
+                            #[name = "topics_revealer"]
                             gtk::Revealer {
                                 set_reveal_child: true,
-                                #[name = "categories"]
+                                #[name = "topics"]
                                 gtk::ListBox {
                                     // Start with None to prevent auto-selection during population
                                     set_selection_mode: gtk::SelectionMode::None,
@@ -188,36 +216,141 @@ impl Component for SideBar {
                                 }
                             },
 
-                        },
+                             // --- Custom End Point---
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 8,
 
+                                #[name = "custom_endpoints_header"]
+                                gtk::Box {
+                                    add_css_class: "sidebar-header-box",
+                                    set_margin_horizontal: 20,
+                                    gtk::Label {
+                                        set_label: "Custom Endpoints",
+                                        add_css_class: "sidebar-section-title",
+                                        add_css_class: "dimmed",
+                                    },
+                                    gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
+                                    #[name = "custom_endpoints_chevron"]
+                                    gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
+                                },
+                                #[name = "custom_endpoints_revealer"]
+                                gtk::Revealer {
+                                    set_reveal_child: true,
 
-                        // --- sections Section ---
-                        #[name = "section_header"]
-                        gtk::Box {
-                            add_css_class: "sidebar-header-box",
-                            set_margin_horizontal: 20,
-                            gtk::Label {
-                                set_label: "Sections",
-                                add_css_class: "sidebar-section-title",
-                                add_css_class: "dimmed",
+                                    gtk::Box {
+                                        set_orientation: gtk::Orientation::Vertical,
+
+                                        #[name = "custom_endpoints"]
+                                        gtk::ListBox {
+                                            // Start with None to prevent auto-selection during population
+                                            set_selection_mode: gtk::SelectionMode::None,
+                                            set_margin_horizontal: 12,
+                                            add_css_class: "navigation-sidebar"
+                                        },
+
+                                        gtk::Box {
+                                            set_orientation: gtk::Orientation::Horizontal,
+                                            set_margin_horizontal: 12,
+                                            set_spacing: 4,
+
+                                            gtk::Button {
+                                                set_icon_name: "list-add-symbolic",
+                                                set_tooltip: "Add Feed",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender] => move |_| {
+                                                    sender.input(SidebarInput::AddFeed);
+                                                }
+                                            },
+                                            gtk::Button {
+                                                set_icon_name: "network-server-symbolic",
+                                                set_tooltip: "Add JSON Endpoint",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender] => move |_| {
+                                                    sender.input(SidebarInput::AddEndpoint);
+                                                }
+                                            },
+                                            gtk::Button {
+                                                set_icon_name: "document-open-symbolic",
+                                                set_tooltip: "Import OPML",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender] => move |_| {
+                                                    sender.input(SidebarInput::ImportOpml);
+                                                }
+                                            },
+                                            gtk::Button {
+                                                set_icon_name: "document-save-symbolic",
+                                                set_tooltip: "Export OPML",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender] => move |_| {
+                                                    sender.input(SidebarInput::ExportOpml);
+                                                }
+                                            },
+                                        }
+                                    }
+                                },
                             },
-                            gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
-                            #[name = "section_chevron"]
-                            gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
-                        },
-                        #[name = "sections_revealer"]
-                        gtk::Revealer {
-                            set_reveal_child: true,
-                            #[name = "sections"]
-                            gtk::ListBox {
-                                set_selection_mode: gtk::SelectionMode::None,
-                                set_margin_horizontal: 12,
-                                add_css_class: "navigation-sidebar"
-                            }
-                        },
 
+                            // --- Categories ---
+                            gtk::Box{
+                                set_visible: false,
 
-                    }
+                                #[name = "categories_header"]
+                                gtk::Box {
+                                    add_css_class: "sidebar-header-box",
+                                    set_margin_horizontal: 20,
+                                    gtk::Label {
+                                        set_label: "Categories",
+                                        add_css_class: "sidebar-section-title",
+                                        add_css_class: "dimmed",
+                                    },
+                                    gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
+                                    #[name = "categories_chevron"]
+                                    gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
+                                },
+                                #[name = "categories_revealer"]
+                                gtk::Revealer {
+                                    set_reveal_child: true,
+                                    #[name = "categories"]
+                                    gtk::ListBox {
+                                        // Start with None to prevent auto-selection during population
+                                        set_selection_mode: gtk::SelectionMode::None,
+                                        set_margin_horizontal: 12,
+                                        add_css_class: "navigation-sidebar"
+                                    }
+                                },
+
+                            },
+
+
+                            // --- sections Section ---
+                            #[name = "section_header"]
+                            gtk::Box {
+                                add_css_class: "sidebar-header-box",
+                                set_margin_horizontal: 20,
+                                gtk::Label {
+                                    set_label: "Sections",
+                                    add_css_class: "sidebar-section-title",
+                                    add_css_class: "dimmed",
+                                },
+                                gtk::Separator { set_hexpand: true, add_css_class: "spacer" },
+                                #[name = "section_chevron"]
+                                gtk::Image { set_icon_name: Some("pan-down-symbolic"), add_css_class: "dimmed" }
+                            },
+                            #[name = "sections_revealer"]
+                            gtk::Revealer {
+                                set_reveal_child: true,
+                                #[name = "sections"]
+                                gtk::ListBox {
+                                    set_selection_mode: gtk::SelectionMode::None,
+                                    set_margin_horizontal: 12,
+                                    add_css_class: "navigation-sidebar"
+                                }
+                            },
+
+
+                        }
+                    },
                 },
 
                 add_bottom_bar = &gtk::Box {
@@ -244,19 +377,39 @@ impl Component for SideBar {
     }
 
     fn init(
-        _init: Self::Init,
+        init: Self::Init,
         _root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let model = SideBar {};
+        let (subscription_worker, endpoint_worker, topic_worker) = init;
+
+        subscription_worker.emit(SubscriptionWorkerInput::Subscribe(sender.clone()));
+        subscription_worker.emit(SubscriptionWorkerInput::Fetch);
+        endpoint_worker.emit(EndpointWorkerInput::Subscribe(sender.clone()));
+        endpoint_worker.emit(EndpointWorkerInput::Fetch);
+        topic_worker.emit(TopicWorkerInput::Subscribe(sender.clone()));
+        topic_worker.emit(TopicWorkerInput::Fetch);
+
+        let model = SideBar {
+            subscription_worker,
+            endpoint_worker,
+            topic_worker,
+            subscriptions: Vec::new(),
+            endpoints: Vec::new(),
+            topics: Vec::new(),
+        };
 
         let widgets = view_output!();
 
         Self::populate_sections(&widgets, &sender);
         Self::render_library_list(&widgets, &sender);
+        Self::connect_custom_endpoints(&widgets, &sender);
+        Self::connect_topics(&widgets, &sender);
 
         widgets.library.set_can_focus(false);
         widgets.sections.set_can_focus(false);
+        widgets.custom_endpoints.set_can_focus(false);
+        widgets.topics.set_can_focus(false);
 
         widgets
             .library
@@ -264,15 +417,27 @@ impl Component for SideBar {
         widgets
             .sections
             .set_selection_mode(gtk::SelectionMode::Single);
+        widgets
+            .custom_endpoints
+            .set_selection_mode(gtk::SelectionMode::Single);
+        widgets
+            .topics
+            .set_selection_mode(gtk::SelectionMode::Single);
 
         widgets.library.unselect_all();
         widgets.sections.unselect_all();
+        widgets.custom_endpoints.unselect_all();
+        widgets.topics.unselect_all();
 
         let w_cat = widgets.sections.clone();
         let w_lib = widgets.library.clone();
+        let w_custom = widgets.custom_endpoints.clone();
+        let w_topics = widgets.topics.clone();
         widgets.topheadlineslist.connect_row_activated(move |_, _| {
             w_cat.unselect_all();
             w_lib.unselect_all();
+            w_custom.unselect_all();
+            w_topics.unselect_all();
         });
 
         if let Some(row) = widgets
@@ -302,17 +467,123 @@ impl Component for SideBar {
             &widgets.library_revealer,
             &widgets.library_chevron,
         );
+        Self::setup_collapsible_section(
+            &widgets.custom_endpoints_header,
+            &widgets.custom_endpoints_revealer,
+            &widgets.custom_endpoints_chevron,
+        );
+        Self::setup_collapsible_section(
+            &widgets.topics_header,
+            &widgets.topics_revealer,
+            &widgets.topics_chevron,
+        );
 
         ComponentParts { model, widgets }
     }
 
     fn update_with_view(
         &mut self,
-        _widgets: &mut Self::Widgets,
-        _msg: Self::Input,
-        _sender: ComponentSender<Self>,
-        _root: &Self::Root,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
     ) {
+        match msg {
+            SidebarInput::UpdateSubscriptions(subscriptions) => {
+                self.subscriptions = subscriptions;
+                self.render_custom_endpoints(&widgets.custom_endpoints);
+            }
+            SidebarInput::UpdateEndpoints(endpoints) => {
+                self.endpoints = endpoints;
+                self.render_custom_endpoints(&widgets.custom_endpoints);
+            }
+            SidebarInput::UpdateTopics(topics) => {
+                self.topics = topics;
+                self.render_topics(&widgets.topics);
+            }
+            SidebarInput::OpenTopic(id) => {
+                if let Some(topic) = self.topics.iter().find(|t| t.id == id) {
+                    let _ = sender.output(SidebarMessage::SelectPage(NavigationPage::Topic(
+                        topic.clone(),
+                    )));
+                }
+            }
+            SidebarInput::MoveTopic(id, offset) => {
+                self.topic_worker.emit(TopicWorkerInput::Move(id, offset));
+            }
+            SidebarInput::DeleteTopic(id) => {
+                self.topic_worker.emit(TopicWorkerInput::Delete(id));
+            }
+            SidebarInput::OpenCustomRow(name) => {
+                if let Some(id) = name.strip_prefix("feed-") {
+                    if let Some(subscription) =
+                        self.subscriptions.iter().find(|s| s.id.to_string() == id)
+                    {
+                        let _ = sender.output(SidebarMessage::SelectSection(NewsSection::Feed(
+                            subscription.clone(),
+                        )));
+                    }
+                } else if let Some(id) = name.strip_prefix("endpoint-") {
+                    if let Some(endpoint) = self.endpoints.iter().find(|e| e.id.to_string() == id)
+                    {
+                        let _ = sender.output(SidebarMessage::SelectPage(
+                            NavigationPage::CustomEndpoint(endpoint.clone()),
+                        ));
+                    }
+                }
+            }
+            SidebarInput::AddEndpoint => {
+                Self::show_add_endpoint_dialog(root, &sender);
+            }
+            SidebarInput::SaveEndpoint(endpoint) => {
+                self.endpoint_worker.emit(EndpointWorkerInput::Save(endpoint));
+            }
+            SidebarInput::AddFeed => {
+                Self::show_add_feed_dialog(root, &sender);
+            }
+            SidebarInput::SaveFeed(subscription) => {
+                self.subscription_worker
+                    .emit(SubscriptionWorkerInput::Add(subscription));
+            }
+            SidebarInput::ImportOpml => {
+                let dialog = gtk::FileDialog::builder()
+                    .title("Import OPML")
+                    .modal(true)
+                    .filters(&Self::opml_filters())
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let worker = self.subscription_worker.clone();
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.open_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            worker.emit(SubscriptionWorkerInput::ImportOpml(path));
+                        }
+                    }
+                });
+            }
+            SidebarInput::ExportOpml => {
+                let dialog = gtk::FileDialog::builder()
+                    .title("Export OPML")
+                    .modal(true)
+                    .initial_name("frostnews-subscriptions.opml")
+                    .filters(&Self::opml_filters())
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let worker = self.subscription_worker.clone();
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.save_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            worker.emit(SubscriptionWorkerInput::ExportOpml(path));
+                        }
+                    }
+                });
+            }
+            SidebarInput::ShowResult(message) => {
+                widgets.toast_overlay.add_toast(adw::Toast::new(&message));
+            }
+        }
     }
 }
 
@@ -352,10 +623,14 @@ impl SideBar {
         let s = sender.clone();
         let w_headlines = widgets.topheadlineslist.clone();
         let w_library = widgets.library.clone();
+        let w_custom = widgets.custom_endpoints.clone();
+        let w_topics = widgets.topics.clone();
 
         sections_list.connect_row_activated(move |_, row| {
             w_headlines.unselect_all();
             w_library.unselect_all();
+            w_custom.unselect_all();
+            w_topics.unselect_all();
 
             let id = row.widget_name();
             if let Some(matched) = SECTIONS.iter().find(|c| c.id == id) {
@@ -368,7 +643,9 @@ impl SideBar {
         let listbox = &widgets.library;
         let items = [
             ("user-bookmarks-symbolic", "Saved"),
+            ("document-edit-symbolic", "Notes"),
             ("document-open-recent-symbolic", "History"),
+            ("utilities-system-monitor-symbolic", "Statistics"),
         ];
 
         for (icon_name, label_text) in items {
@@ -402,16 +679,21 @@ impl SideBar {
 
         let w_headlines = widgets.topheadlineslist.clone();
         let w_sections = widgets.sections.clone();
+        let w_custom = widgets.custom_endpoints.clone();
+        let w_topics = widgets.topics.clone();
 
         let sender_clone = sender.clone();
         listbox.connect_row_activated(move |_, row| {
             w_headlines.unselect_all();
             w_sections.unselect_all();
+            w_custom.unselect_all();
+            w_topics.unselect_all();
 
-            let page = if row.widget_name().as_str() == "Saved" {
-                NavigationPage::Saved
-            } else {
-                NavigationPage::History
+            let page = match row.widget_name().as_str() {
+                "Saved" => NavigationPage::Saved,
+                "Notes" => NavigationPage::Notes,
+                "Statistics" => NavigationPage::Stats,
+                _ => NavigationPage::History,
             };
             let _ = sender_clone
                 .output_sender()
@@ -419,6 +701,354 @@ impl SideBar {
         });
     }
 
+    fn connect_custom_endpoints(widgets: &SideBarWidgets, sender: &ComponentSender<Self>) {
+        let w_headlines = widgets.topheadlineslist.clone();
+        let w_sections = widgets.sections.clone();
+        let w_library = widgets.library.clone();
+        let w_topics = widgets.topics.clone();
+
+        let sender_clone = sender.clone();
+        widgets
+            .custom_endpoints
+            .connect_row_activated(move |_, row| {
+                w_headlines.unselect_all();
+                w_sections.unselect_all();
+                w_library.unselect_all();
+                w_topics.unselect_all();
+
+                sender_clone.input(SidebarInput::OpenCustomRow(row.widget_name().to_string()));
+            });
+    }
+
+    fn connect_topics(widgets: &SideBarWidgets, sender: &ComponentSender<Self>) {
+        let w_headlines = widgets.topheadlineslist.clone();
+        let w_sections = widgets.sections.clone();
+        let w_library = widgets.library.clone();
+        let w_custom = widgets.custom_endpoints.clone();
+
+        let sender_clone = sender.clone();
+        widgets.topics.connect_row_activated(move |_, row| {
+            w_headlines.unselect_all();
+            w_sections.unselect_all();
+            w_library.unselect_all();
+            w_custom.unselect_all();
+
+            if let Some(id) = Self::topic_id(row) {
+                sender_clone.input(SidebarInput::OpenTopic(id));
+            }
+        });
+
+        // right click opens the reorder/delete menu for the row under the pointer
+        let gesture = gtk::GestureClick::builder().button(3).build();
+        let listbox = widgets.topics.clone();
+        let sender_clone = sender.clone();
+        gesture.connect_pressed(move |_, _, _, y| {
+            if let Some(row) = listbox.row_at_y(y as i32) {
+                if let Some(id) = Self::topic_id(&row) {
+                    Self::show_topic_menu(&row, id, &sender_clone);
+                }
+            }
+        });
+        widgets.topics.add_controller(gesture);
+    }
+
+    fn topic_id(row: &gtk::ListBoxRow) -> Option<i64> {
+        row.widget_name().strip_prefix("topic-")?.parse().ok()
+    }
+
+    fn render_topics(&self, listbox: &gtk::ListBox) {
+        while let Some(child) = listbox.first_child() {
+            listbox.remove(&child);
+        }
+
+        for topic in &self.topics {
+            let unread = (topic.unread > 0).then(|| topic.unread.to_string());
+            listbox.append(&Self::custom_row(
+                "system-search-symbolic",
+                &topic.name,
+                unread.as_deref(),
+                &format!("topic-{}", topic.id),
+                &topic.query,
+            ));
+        }
+    }
+
+    fn show_topic_menu(row: &gtk::ListBoxRow, id: i64, sender: &ComponentSender<Self>) {
+        let menu = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .build();
+        let popover = gtk::Popover::builder()
+            .child(&menu)
+            .has_arrow(false)
+            .build();
+
+        let items = [
+            ("Move Up", SidebarInput::MoveTopic(id, -1)),
+            ("Move Down", SidebarInput::MoveTopic(id, 1)),
+            ("Delete", SidebarInput::DeleteTopic(id)),
+        ];
+        for (label, input) in items {
+            let button = gtk::Button::builder()
+                .label(label)
+                .css_classes(vec!["flat"])
+                .build();
+            let sender = sender.clone();
+            let p = popover.clone();
+            let input = std::cell::Cell::new(Some(input));
+            button.connect_clicked(move |_| {
+                p.popdown();
+                if let Some(input) = input.take() {
+                    sender.input(input);
+                }
+            });
+            menu.append(&button);
+        }
+
+        popover.set_parent(row);
+        popover.connect_closed(|p| {
+            let p = p.clone();
+            gtk::glib::idle_add_local_once(move || p.unparent());
+        });
+        popover.popup();
+    }
+
+    fn render_custom_endpoints(&self, listbox: &gtk::ListBox) {
+        while let Some(child) = listbox.first_child() {
+            listbox.remove(&child);
+        }
+
+        for subscription in &self.subscriptions {
+            listbox.append(&Self::custom_row(
+                "application-rss+xml-symbolic",
+                &subscription.title,
+                subscription.folder.as_deref(),
+                &format!("feed-{}", subscription.id),
+                &subscription.url,
+            ));
+        }
+
+        for endpoint in &self.endpoints {
+            listbox.append(&Self::custom_row(
+                "network-server-symbolic",
+                &endpoint.name,
+                None,
+                &format!("endpoint-{}", endpoint.id),
+                &endpoint.base_url,
+            ));
+        }
+    }
+
+    fn custom_row(
+        icon_name: &str,
+        label_text: &str,
+        suffix: Option<&str>,
+        name: &str,
+        tooltip: &str,
+    ) -> gtk::ListBoxRow {
+        let row_box = gtk::Box::builder()
+            .spacing(16)
+            .css_classes(vec!["Category"])
+            .build();
+
+        let icon = gtk::Image::from_icon_name(icon_name);
+        icon.set_pixel_size(18);
+        icon.set_margin_start(8);
+        icon.add_css_class("sidebar_icon");
+
+        let label = gtk::Label::builder()
+            .label(label_text)
+            .ellipsize(gtk::pango::EllipsizeMode::End)
+            .css_classes(vec!["sidebar-label"])
+            .build();
+
+        row_box.append(&icon);
+        row_box.append(&label);
+
+        if let Some(suffix) = suffix {
+            let suffix_label = gtk::Label::builder()
+                .label(suffix)
+                .hexpand(true)
+                .halign(gtk::Align::End)
+                .css_classes(vec!["dimmed", "caption"])
+                .build();
+            row_box.append(&suffix_label);
+        }
+
+        gtk::ListBoxRow::builder()
+            .child(&row_box)
+            .name(name)
+            .tooltip_text(tooltip)
+            .margin_end(0)
+            .margin_start(0)
+            .build()
+    }
+
+    fn show_add_feed_dialog(root: &adw::NavigationPage, sender: &ComponentSender<Self>) {
+        let dialog = adw::AlertDialog::new(
+            Some("Add Feed"),
+            Some("Subscribe to an RSS or Atom feed"),
+        );
+
+        let url_row = adw::EntryRow::builder().title("Feed URL").build();
+        let title_row = adw::EntryRow::builder().title("Title").build();
+        let folder_row = adw::EntryRow::builder().title("Folder (optional)").build();
+
+        let fields = gtk::ListBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .css_classes(vec!["boxed-list"])
+            .build();
+        fields.append(&url_row);
+        fields.append(&title_row);
+        fields.append(&folder_row);
+
+        dialog.set_extra_child(Some(&fields));
+        dialog.add_responses(&[("cancel", "Cancel"), ("add", "Add")]);
+        dialog.set_response_appearance("add", adw::ResponseAppearance::Suggested);
+        dialog.set_default_response(Some("add"));
+        dialog.set_close_response("cancel");
+
+        let sender = sender.clone();
+        dialog.connect_response(None, move |_, response| {
+            if response != "add" {
+                return;
+            }
+
+            let url = url_row.text().trim().to_string();
+            if url.is_empty() {
+                return;
+            }
+
+            let title = title_row.text().trim().to_string();
+            let title = if title.is_empty() { url.clone() } else { title };
+            let folder = Some(folder_row.text().to_string());
+
+            sender.input(SidebarInput::SaveFeed(FeedSubscription::new(title, url, folder)));
+        });
+
+        dialog.present(Some(root));
+    }
+
+    fn show_add_endpoint_dialog(root: &adw::NavigationPage, sender: &ComponentSender<Self>) {
+        let dialog = adw::AlertDialog::new(
+            Some("Add JSON Endpoint"),
+            Some("Read articles from any HTTP API that returns JSON. Field paths are dot separated, e.g. data.items or media.0.url"),
+        );
+
+        let name_row = adw::EntryRow::builder().title("Name").build();
+        let url_row = adw::EntryRow::builder().title("Base URL").build();
+        let params_row = adw::EntryRow::builder()
+            .title("Query parameters (key=value&key2=value2)")
+            .build();
+        let header_row = adw::EntryRow::builder()
+            .title("API key header (optional)")
+            .build();
+        let key_row = adw::PasswordEntryRow::builder().title("API key").build();
+        let page_row = adw::EntryRow::builder()
+            .title("Page parameter (optional)")
+            .build();
+
+        let articles_row = adw::EntryRow::builder().title("Articles path").build();
+        let title_row = adw::EntryRow::builder().title("Title path").text("title").build();
+        let link_row = adw::EntryRow::builder().title("URL path").text("url").build();
+        let image_row = adw::EntryRow::builder().title("Image path").build();
+        let date_row = adw::EntryRow::builder().title("Published date path").build();
+        let description_row = adw::EntryRow::builder().title("Description path").build();
+
+        let endpoint_fields = gtk::ListBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .css_classes(vec!["boxed-list"])
+            .build();
+        endpoint_fields.append(&name_row);
+        endpoint_fields.append(&url_row);
+        endpoint_fields.append(&params_row);
+        endpoint_fields.append(&header_row);
+        endpoint_fields.append(&key_row);
+        endpoint_fields.append(&page_row);
+
+        let mapping_fields = gtk::ListBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .css_classes(vec!["boxed-list"])
+            .build();
+        mapping_fields.append(&articles_row);
+        mapping_fields.append(&title_row);
+        mapping_fields.append(&link_row);
+        mapping_fields.append(&image_row);
+        mapping_fields.append(&date_row);
+        mapping_fields.append(&description_row);
+
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(12)
+            .build();
+        content.append(&endpoint_fields);
+        content.append(&mapping_fields);
+
+        let scroller = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .min_content_height(420)
+            .child(&content)
+            .build();
+
+        dialog.set_extra_child(Some(&scroller));
+        dialog.add_responses(&[("cancel", "Cancel"), ("add", "Add")]);
+        dialog.set_response_appearance("add", adw::ResponseAppearance::Suggested);
+        dialog.set_default_response(Some("add"));
+        dialog.set_close_response("cancel");
+
+        let optional = |row: &adw::EntryRow| -> Option<String> {
+            let text = row.text().trim().to_string();
+            if text.is_empty() { None } else { Some(text) }
+        };
+
+        let sender = sender.clone();
+        dialog.connect_response(None, move |_, response| {
+            if response != "add" {
+                return;
+            }
+
+            let base_url = url_row.text().trim().to_string();
+            if base_url.is_empty() {
+                return;
+            }
+
+            let name = optional(&name_row).unwrap_or_else(|| base_url.clone());
+            let api_key = key_row.text().trim().to_string();
+
+            let endpoint = CustomEndpoint {
+                id: 0,
+                name,
+                base_url,
+                query_params: CustomEndpoint::parse_query_params(&params_row.text()),
+                api_key_header: optional(&header_row),
+                api_key: if api_key.is_empty() { None } else { Some(api_key) },
+                page_param: optional(&page_row),
+                mapping: FieldMapping {
+                    articles: articles_row.text().trim().to_string(),
+                    title: optional(&title_row).unwrap_or_else(|| "title".into()),
+                    url: optional(&link_row).unwrap_or_else(|| "url".into()),
+                    image: optional(&image_row),
+                    published_at: optional(&date_row),
+                    description: optional(&description_row),
+                },
+            };
+
+            sender.input(SidebarInput::SaveEndpoint(endpoint));
+        });
+
+        dialog.present(Some(root));
+    }
+
+    fn opml_filters() -> gtk::gio::ListStore {
+        let filter = gtk::FileFilter::new();
+        filter.set_name(Some("OPML"));
+        filter.add_pattern("*.opml");
+        filter.add_pattern("*.xml");
+
+        let filters = gtk::gio::ListStore::new::<gtk::FileFilter>();
+        filters.append(&filter);
+        filters
+    }
+
     pub fn setup_collapsible_section(
         header: &gtk::Box,
         revealer: &gtk::Revealer,
@@ -0,0 +1,256 @@
+use chrono::DateTime;
+use reqwest::Client;
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    custom_api::data_structures::{CustomApiError, CustomArticle},
+    types::custom_endpoint::CustomEndpoint,
+};
+
+#[derive(Debug, Clone)]
+pub struct CustomApiClient {
+    endpoint: CustomEndpoint,
+    client: Client,
+}
+
+impl CustomApiClient {
+    pub fn new(endpoint: CustomEndpoint) -> Self {
+        Self {
+            endpoint,
+            client: Client::new(),
+        }
+    }
+
+    pub fn is_paginated(&self) -> bool {
+        self.endpoint.page_param.is_some()
+    }
+
+    fn build_url(&self, page: Option<i32>) -> Result<Url, url::ParseError> {
+        let mut url = Url::parse(self.endpoint.base_url.trim())?;
+
+        {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in &self.endpoint.query_params {
+                pairs.append_pair(key, value);
+            }
+            if let (Some(param), Some(page)) = (&self.endpoint.page_param, page) {
+                pairs.append_pair(param, &page.to_string());
+            }
+        }
+
+        Ok(url)
+    }
+
+    async fn perform_request(&self, url: Url) -> Result<Value, CustomApiError> {
+        let mut request = self
+            .client
+            .get(url)
+            .header("User-Agent", "FrostNews/1.0")
+            .header("Accept", "application/json");
+
+        if let (Some(header), Some(key)) = (&self.endpoint.api_key_header, &self.endpoint.api_key)
+        {
+            request = request.header(header.as_str(), key.as_str());
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(CustomApiError::Http(status.as_u16()));
+        }
+
+        let bytes = response.bytes().await?;
+        Ok(serde_json::from_slice::<Value>(&bytes)?)
+    }
+
+    pub async fn fetch_articles(&self, page: Option<i32>) -> Result<Vec<CustomArticle>, CustomApiError> {
+        let url = self.build_url(page)?;
+        let body = self.perform_request(url).await?;
+        self.map_articles(&body)
+    }
+
+    /// Applies the endpoint's field mapping to a decoded response
+    pub fn map_articles(&self, body: &Value) -> Result<Vec<CustomArticle>, CustomApiError> {
+        let mapping = &self.endpoint.mapping;
+
+        let items = Self::resolve(body, &mapping.articles)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| CustomApiError::MissingArticles(mapping.articles.clone()))?;
+
+        Ok(items
+            .iter()
+            .filter_map(|item| {
+                let title = Self::resolve_string(item, &mapping.title)?;
+                let url = Self::resolve_string(item, &mapping.url)?;
+
+                Some(CustomArticle {
+                    title,
+                    url,
+                    image_url: mapping
+                        .image
+                        .as_deref()
+                        .and_then(|p| Self::resolve_string(item, p)),
+                    published_at: mapping
+                        .published_at
+                        .as_deref()
+                        .and_then(|p| Self::resolve(item, p))
+                        .and_then(Self::normalise_date)
+                        .unwrap_or_default(),
+                    description: mapping
+                        .description
+                        .as_deref()
+                        .and_then(|p| Self::resolve_string(item, p)),
+                    source: self.endpoint.name.clone(),
+                })
+            })
+            .collect())
+    }
+
+    /// Walks a dot separated path (`data.items.0.title`) through a JSON value
+    pub fn resolve<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+        path.split('.')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .try_fold(value, |current, segment| match current {
+                Value::Object(map) => map.get(segment),
+                Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+                _ => None,
+            })
+    }
+
+    fn resolve_string(value: &Value, path: &str) -> Option<String> {
+        match Self::resolve(value, path)? {
+            Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Accepts RFC 3339, RFC 2822 or unix timestamps (seconds or milliseconds)
+    fn normalise_date(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(
+                DateTime::parse_from_rfc3339(s)
+                    .or_else(|_| DateTime::parse_from_rfc2822(s))
+                    .map(|dt| dt.to_rfc3339())
+                    .unwrap_or_else(|_| s.clone()),
+            ),
+            Value::Number(n) => {
+                let ts = n.as_i64()?;
+                let dt = if ts > 10_000_000_000 {
+                    DateTime::from_timestamp_millis(ts)
+                } else {
+                    DateTime::from_timestamp(ts, 0)
+                };
+                dt.map(|dt| dt.to_rfc3339())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::custom_endpoint::FieldMapping;
+    use serde_json::json;
+
+    fn client(mapping: FieldMapping) -> CustomApiClient {
+        CustomApiClient::new(CustomEndpoint {
+            name: "Example".to_string(),
+            base_url: "https://api.example.com/news".to_string(),
+            mapping,
+            ..Default::default()
+        })
+    }
+
+    fn mapping(articles: &str) -> FieldMapping {
+        FieldMapping {
+            articles: articles.to_string(),
+            title: "title".to_string(),
+            url: "link".to_string(),
+            image: Some("media.0.url".to_string()),
+            published_at: Some("date".to_string()),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn resolves_paths_through_arrays() {
+        let body = json!({ "data": { "items": [{ "title": "First" }, { "title": "Second" }] } });
+
+        assert_eq!(
+            CustomApiClient::resolve(&body, "data.items.1.title"),
+            Some(&json!("Second"))
+        );
+        assert_eq!(CustomApiClient::resolve(&body, "data.items.2.title"), None);
+        assert_eq!(CustomApiClient::resolve(&body, "data.items.first"), None);
+        assert_eq!(CustomApiClient::resolve(&body, ""), Some(&body));
+    }
+
+    #[test]
+    fn maps_nested_articles() {
+        let body = json!({ "data": { "items": [{
+            "title": " Launch day ",
+            "link": "https://example.com/launch",
+            "media": [{ "url": "https://example.com/launch.jpg" }],
+        }] } });
+
+        let articles = client(mapping("data.items")).map_articles(&body).unwrap();
+
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Launch day");
+        assert_eq!(articles[0].url, "https://example.com/launch");
+        assert_eq!(
+            articles[0].image_url.as_deref(),
+            Some("https://example.com/launch.jpg")
+        );
+        assert_eq!(articles[0].source, "Example");
+    }
+
+    #[test]
+    fn reports_a_missing_article_list() {
+        let body = json!({ "data": { "items": [] } });
+
+        let result = client(mapping("data.results")).map_articles(&body);
+
+        assert!(
+            matches!(result, Err(CustomApiError::MissingArticles(path)) if path == "data.results")
+        );
+    }
+
+    #[test]
+    fn skips_items_without_title_or_url() {
+        let body = json!([
+            { "title": "Kept", "link": "https://example.com/kept" },
+            { "title": "No link" },
+            { "title": "  ", "link": "https://example.com/blank" },
+            { "link": "https://example.com/untitled" },
+        ]);
+
+        let articles = client(mapping("")).map_articles(&body).unwrap();
+
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Kept");
+    }
+
+    #[test]
+    fn normalises_dates() {
+        let expected = Some("2024-03-01T10:00:00+00:00".to_string());
+
+        for date in [
+            json!("2024-03-01T10:00:00Z"),
+            json!("Fri, 01 Mar 2024 10:00:00 +0000"),
+            json!(1709287200),
+            json!(1709287200000i64),
+        ] {
+            assert_eq!(CustomApiClient::normalise_date(&date), expected, "{}", date);
+        }
+        assert_eq!(
+            CustomApiClient::normalise_date(&json!("yesterday")),
+            Some("yesterday".to_string())
+        );
+        assert_eq!(CustomApiClient::normalise_date(&json!(null)), None);
+    }
+}
@@ -0,0 +1,23 @@
+#[derive(Debug, Clone)]
+pub struct CustomArticle {
+    pub title: String,
+    pub url: String,
+    pub image_url: Option<String>,
+    pub published_at: String,
+    pub description: Option<String>,
+    pub source: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CustomApiError {
+    #[error("Invalid endpoint URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+    #[error("Network Error: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("The endpoint answered with status {0}.")]
+    Http(u16),
+    #[error("The endpoint did not return valid JSON: {0}")]
+    Decode(#[from] serde_json::Error),
+    #[error("No article list found at \"{0}\" in the response.")]
+    MissingArticles(String),
+}
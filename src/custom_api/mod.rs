@@ -0,0 +1,2 @@
+pub mod custom_api_client;
+pub mod data_structures;
\ No newline at end of file
@@ -40,7 +40,7 @@ pub enum NewsError {
     #[error("Forbidden: Daily quota reached. Resets at 00:00 UTC.")]
     Forbidden,
     #[error("Too Many Requests: Rate limit exceeded.")]
-    TooManyRequests,
+    TooManyRequests(Option<u64>),
     #[error("Internal Server Error: Something went wrong on the server.")]
     InternalServerError,
     #[error("Service Unavailable: Server is offline for maintenance.")]
@@ -2,12 +2,14 @@ use reqwest::Client;
 use url::Url;
 
 use crate::gnews::data_structures::{GNewsResponse, NewsError};
+use crate::utils::http::{RetryPolicy, retry_after};
 
 #[derive(Debug, Clone)]
 pub struct GNewsClient {
     api_token: String,
     base_url: Url,
     client: Client,
+    retry: RetryPolicy,
 }
 
 impl GNewsClient {
@@ -16,9 +18,15 @@ impl GNewsClient {
             api_token: api_token.into(),
             base_url: Url::parse("https://gnews.io/api/v4/").unwrap(),
             client: Client::new(),
+            retry: RetryPolicy::new(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     fn build_url(
         &self,
         endpoint: &str,
@@ -46,10 +54,8 @@ impl GNewsClient {
 
     async fn perform_request(&self, url: Url) -> Result<GNewsResponse, NewsError> {
         let response = self
-            .client
-            .get(url)
-            .header("User-Agent", "FrostNews/1.0")
-            .send()
+            .retry
+            .send(self.client.get(url).header("User-Agent", "FrostNews/1.0"))
             .await?;
 
         let status = response.status();
@@ -64,7 +70,7 @@ impl GNewsClient {
             400 => Err(NewsError::BadRequest),
             401 => Err(NewsError::Unauthorized),
             403 => Err(NewsError::Forbidden),
-            429 => Err(NewsError::TooManyRequests),
+            429 => Err(NewsError::TooManyRequests(retry_after(response.headers()))),
             500 => Err(NewsError::InternalServerError),
             503 => Err(NewsError::ServiceUnavailable),
             other => Err(NewsError::Unknown(other)),
@@ -78,6 +84,7 @@ impl GNewsClient {
         lang: Option<String>,
         country: Option<String>,
         max_results: Option<i32>,
+        page: Option<i32>,
     ) -> Result<GNewsResponse, NewsError> {
         let url = self
             .build_url(
@@ -87,6 +94,7 @@ impl GNewsClient {
                     ("lang", lang),
                     ("country", country),
                     ("max", max_results.map(|v| v.to_string())),
+                    ("page", page.map(|v| v.to_string())),
                 ],
             )
             .unwrap();
@@ -101,6 +109,11 @@ impl GNewsClient {
         lang: Option<String>,
         country: Option<String>,
         max_results: Option<i32>,
+        from: Option<String>,
+        to: Option<String>,
+        sort_by: Option<String>,
+        search_in: Option<String>,
+        page: Option<i32>,
     ) -> Result<GNewsResponse, NewsError> {
         let url = self
             .build_url(
@@ -110,6 +123,11 @@ impl GNewsClient {
                     ("lang", lang),
                     ("country", country),
                     ("max", max_results.map(|v| v.to_string())),
+                    ("from", from),
+                    ("to", to),
+                    ("sortby", sort_by),
+                    ("in", search_in),
+                    ("page", page.map(|v| v.to_string())),
                 ],
             )
             .unwrap();
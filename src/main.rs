@@ -4,24 +4,46 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 mod components;
+mod custom_api;
 mod data;
 mod gnews;
 mod news_api;
 mod newsdata;
 mod pages;
+mod rss;
 mod services;
 mod types;
 mod utils;
 
 use crate::components::sidebar::{NavigationPage, SideBar, SidebarMessage};
 use crate::pages::category_page::{CategoryPage, PageInput, PageOutput};
-use crate::pages::history_page::{HistoryPage, HistoryPagePageOutput};
+use crate::pages::history_page::{HistoryPage, HistoryPageInput, HistoryPagePageOutput};
+use crate::pages::notes_page::{NotesPage, NotesPageInput, NotesPageOutput};
+use crate::pages::saved_page::{SavedPage, SavedPageInput, SavedPageOutput};
+use crate::pages::search_page::{SearchPage, SearchPageInput, SearchPageOutput};
+use crate::pages::settings_page::{SettingsPage, SettingsPageInput, SettingsPageOutput};
+use crate::pages::stats_page::{StatsPage, StatsPageInput, StatsPageOutput};
+use crate::services::article_store_service::article_store::{ArticleStore, ArticleStoreService};
+use crate::services::bookmark_service::bookmarks::BookmarkService;
+use crate::services::custom_endpoint_service::endpoints::EndpointService;
 use crate::services::history_service::history::HistoryService;
 use crate::services::news_service::fetch_service::NewsFetchService;
 use crate::services::news_settings_service::settings::NewsServiceSettings;
+use crate::services::note_service::notes::{NoteService, Notes};
+use crate::services::response_cache_service::response_cache::{ResponseCache, ResponseCacheService};
+use crate::services::subscription_service::subscriptions::SubscriptionService;
+use crate::services::topic_service::topics::TopicService;
+use crate::services::usage_service::usage::{UsageService, UsageTracker};
+use crate::services::workers::bookmark_worker::{BookmarkWorker, BookmarkWorkerInput};
+use crate::services::workers::endpoint_worker::EndpointWorker;
 use crate::services::workers::history_worker::HistoryWorker;
+use crate::services::workers::subscription_worker::SubscriptionWorker;
+use crate::services::workers::topic_worker::{TopicWorker, TopicWorkerInput};
 use crate::types::cache::ImageCache;
+use crate::types::news_article::NewsArticle;
 use crate::types::news_category::NewsSection;
+use crate::types::persistent_articel::PersistentArticle;
+use crate::types::saved_topic::SavedTopic;
 use dotenv::dotenv;
 
 const APP_ID: &'static str = "com.example.frostnews";
@@ -29,6 +51,11 @@ const APP_ID: &'static str = "com.example.frostnews";
 enum PageController {
     Category(Controller<CategoryPage>),
     History(Controller<HistoryPage>),
+    Settings(Controller<SettingsPage>),
+    Search(Controller<SearchPage>),
+    Saved(Controller<SavedPage>),
+    Notes(Controller<NotesPage>),
+    Stats(Controller<StatsPage>),
 }
 
 impl PageController {
@@ -36,6 +63,11 @@ impl PageController {
         match self {
             Self::Category(c) => c.widget(),
             Self::History(c) => c.widget(),
+            Self::Settings(c) => c.widget(),
+            Self::Search(c) => c.widget(),
+            Self::Saved(c) => c.widget(),
+            Self::Notes(c) => c.widget(),
+            Self::Stats(c) => c.widget(),
         }
     }
 }
@@ -44,10 +76,13 @@ struct App {
     sidebar_visible: bool,
     fetch_service: NewsFetchService,
     image_cache: ImageCache,
+    notes: Notes,
     pages_cache: HashMap<String, PageController>,
     current_page_key: String,
     sidebar: Controller<SideBar>,
     history_worker: Arc<Controller<HistoryWorker>>,
+    bookmark_worker: Arc<Controller<BookmarkWorker>>,
+    topic_worker: Arc<Controller<TopicWorker>>,
 }
 
 #[derive(Debug)]
@@ -55,6 +90,11 @@ enum Msg {
     ToggleSidebar,
     ChangeSection(NewsSection),
     ChangeSectionPage(NavigationPage),
+    Search(String),
+    SaveTopic(SavedTopic),
+    TopicSeen(i64, Vec<String>),
+    Bookmark(Arc<dyn NewsArticle>),
+    RefreshSaved,
 }
 
 #[relm4::component]
@@ -94,12 +134,44 @@ impl SimpleComponent for App {
             std::env::var("GNEWS_API_KEY").unwrap_or_default(),
             std::env::var("NEWSDATA_API_KEY").unwrap_or_default(),
             NewsServiceSettings::new(APP_ID),
+            UsageTracker::new(UsageService::new().expect("Failed to init DB")),
+            ResponseCache::new(ResponseCacheService::new().expect("Failed to init DB")),
+            ArticleStore::new(ArticleStoreService::new().expect("Failed to init DB")),
         );
 
         let history_service = HistoryService::new().expect("Failed to init DB");
-        let history_worker = Arc::new(HistoryWorker::builder().launch(history_service).detach());
+        let history_worker = Arc::new(
+            HistoryWorker::builder()
+                .launch((
+                    history_service,
+                    BookmarkService::new().expect("Failed to init DB"),
+                    fetch_service.get_settings(),
+                ))
+                .detach(),
+        );
+
+        let bookmark_service = BookmarkService::new().expect("Failed to init DB");
+        let bookmark_worker = Arc::new(BookmarkWorker::builder().launch(bookmark_service).detach());
+
+        let subscription_service = SubscriptionService::new().expect("Failed to init DB");
+        let subscription_worker = Arc::new(
+            SubscriptionWorker::builder()
+                .launch(subscription_service)
+                .detach(),
+        );
+
+        let endpoint_service = EndpointService::new().expect("Failed to init DB");
+        let endpoint_worker = Arc::new(EndpointWorker::builder().launch(endpoint_service).detach());
+
+        let topic_service = TopicService::new().expect("Failed to init DB");
+        let topic_worker = Arc::new(
+            TopicWorker::builder()
+                .launch((topic_service, fetch_service.clone()))
+                .detach(),
+        );
 
         let image_cache = ImageCache::new();
+        let notes = Notes::new(NoteService::new().expect("Failed to init DB"));
 
         let initial_section = NewsSection::General;
         let initial_key = initial_section.to_key();
@@ -110,32 +182,35 @@ impl SimpleComponent for App {
                 fetch_service.clone(),
                 history_worker.clone(),
                 image_cache.clone(),
+                notes.clone(),
                 false,
             ))
-            .forward(sender.input_sender(), |msg| match msg {
-                PageOutput::ToggleSidebar => Msg::ToggleSidebar,
-            });
+            .forward(sender.input_sender(), Self::page_output);
 
         let mut pages_cache = HashMap::new();
         pages_cache.insert(initial_key.clone(), PageController::Category(category_page));
 
         let sidebar =
             SideBar::builder()
-                .launch(())
+                .launch((subscription_worker, endpoint_worker, topic_worker.clone()))
                 .forward(sender.input_sender(), move |message| match message {
                     SidebarMessage::ToggleSidebar => Msg::ToggleSidebar,
                     SidebarMessage::SelectSection(section) => Msg::ChangeSection(section),
                     SidebarMessage::SelectPage(page) => Msg::ChangeSectionPage(page),
+                    SidebarMessage::Search(query) => Msg::Search(query),
                 });
 
         let model = App {
             sidebar_visible: true,
             fetch_service,
             image_cache,
+            notes,
             pages_cache,
             current_page_key: initial_key,
             sidebar,
             history_worker,
+            bookmark_worker,
+            topic_worker,
         };
 
         let widgets = view_output!();
@@ -146,8 +221,29 @@ impl SimpleComponent for App {
         match msg {
             Msg::ToggleSidebar => {
                 self.sidebar_visible = !self.sidebar_visible;
-                if let Some(PageController::Category(c)) = self.pages_cache.get(&self.current_page_key) {
-                    let _ = c.sender().send(PageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                match self.pages_cache.get(&self.current_page_key) {
+                    Some(PageController::Category(c)) => {
+                        let _ = c.sender().send(PageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::Search(c)) => {
+                        let _ = c.sender().send(SearchPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::Saved(c)) => {
+                        let _ = c.sender().send(SavedPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::Notes(c)) => {
+                        let _ = c.sender().send(NotesPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::Stats(c)) => {
+                        let _ = c.sender().send(StatsPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::Settings(c)) => {
+                        let _ = c.sender().send(SettingsPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    Some(PageController::History(c)) => {
+                        let _ = c.sender().send(HistoryPageInput::ShowSidebarToggleBtn(!self.sidebar_visible));
+                    }
+                    None => {}
                 }
             }
 
@@ -161,11 +257,10 @@ impl SimpleComponent for App {
                             self.fetch_service.clone(),
                             self.history_worker.clone(),
                             self.image_cache.clone(),
+                            self.notes.clone(),
                             !self.sidebar_visible,
                         ))
-                        .forward(sender.input_sender(), |msg| match msg {
-                            PageOutput::ToggleSidebar => Msg::ToggleSidebar,
-                        });
+                        .forward(sender.input_sender(), Self::page_output);
 
                     self.pages_cache.insert(key.clone(), PageController::Category(new_page));
                 }
@@ -175,24 +270,184 @@ impl SimpleComponent for App {
 
             Msg::ChangeSectionPage(page) => {
                 let key = page.to_key();
+                let is_topic = matches!(page, NavigationPage::Topic(_));
+                let is_notes = matches!(page, NavigationPage::Notes);
                 
                 if !self.pages_cache.contains_key(&key) {
                     match page {
                         NavigationPage::History => {
                             let history_page = HistoryPage::builder()
-                                .launch((self.history_worker.clone(), !self.sidebar_visible))
+                                .launch((
+                                    self.history_worker.clone(),
+                                    self.notes.clone(),
+                                    !self.sidebar_visible,
+                                ))
                                 .forward(sender.input_sender(), |msg| match msg {
                                     HistoryPagePageOutput::ToggleSidebar => Msg::ToggleSidebar,
+                                    HistoryPagePageOutput::SavedChanged => Msg::RefreshSaved,
                                 });
                             
                             self.pages_cache.insert(key.clone(), PageController::History(history_page));
                         }
+                        NavigationPage::Saved => {
+                            let saved_page = SavedPage::builder()
+                                .launch((
+                                    self.bookmark_worker.clone(),
+                                    self.history_worker.clone(),
+                                    self.image_cache.clone(),
+                                    self.notes.clone(),
+                                    !self.sidebar_visible,
+                                ))
+                                .forward(sender.input_sender(), |msg| match msg {
+                                    SavedPageOutput::ToggleSidebar => Msg::ToggleSidebar,
+                                    SavedPageOutput::Bookmark(article) => Msg::Bookmark(article),
+                                });
+
+                            self.pages_cache.insert(key.clone(), PageController::Saved(saved_page));
+                        }
+                        NavigationPage::Notes => {
+                            let notes_page = NotesPage::builder()
+                                .launch((self.notes.clone(), !self.sidebar_visible))
+                                .forward(sender.input_sender(), |msg| match msg {
+                                    NotesPageOutput::ToggleSidebar => Msg::ToggleSidebar,
+                                });
+
+                            self.pages_cache.insert(key.clone(), PageController::Notes(notes_page));
+                        }
+                        NavigationPage::Stats => {
+                            let stats_page = StatsPage::builder()
+                                .launch((self.history_worker.clone(), !self.sidebar_visible))
+                                .forward(sender.input_sender(), |msg| match msg {
+                                    StatsPageOutput::ToggleSidebar => Msg::ToggleSidebar,
+                                });
+
+                            self.pages_cache.insert(key.clone(), PageController::Stats(stats_page));
+                        }
+                        NavigationPage::Settings => {
+                            let settings_page = SettingsPage::builder()
+                                .launch((self.fetch_service.clone(), !self.sidebar_visible))
+                                .forward(sender.input_sender(), |msg| match msg {
+                                    SettingsPageOutput::ToggleSidebar => Msg::ToggleSidebar,
+                                });
+
+                            self.pages_cache.insert(key.clone(), PageController::Settings(settings_page));
+                        }
+                        NavigationPage::CustomEndpoint(endpoint) => {
+                            let endpoint_page = CategoryPage::builder()
+                                .launch((
+                                    NewsSection::CustomEndpoint(endpoint),
+                                    self.fetch_service.clone(),
+                                    self.history_worker.clone(),
+                                    self.image_cache.clone(),
+                                    self.notes.clone(),
+                                    !self.sidebar_visible,
+                                ))
+                                .forward(sender.input_sender(), Self::page_output);
+
+                            self.pages_cache.insert(key.clone(), PageController::Category(endpoint_page));
+                        }
+                        NavigationPage::Topic(topic) => {
+                            let topic_page = SearchPage::builder()
+                                .launch((
+                                    self.fetch_service.clone(),
+                                    self.history_worker.clone(),
+                                    self.image_cache.clone(),
+                                    self.notes.clone(),
+                                    !self.sidebar_visible,
+                                    Some(topic),
+                                ))
+                                .forward(sender.input_sender(), Self::search_output);
+
+                            self.pages_cache.insert(key.clone(), PageController::Search(topic_page));
+                        }
                         _ => {}
                     }
                 }
 
+                // topics always show what is new, like reopening a section would
+                if is_topic {
+                    if let Some(PageController::Search(c)) = self.pages_cache.get(&key) {
+                        c.emit(SearchPageInput::ApplyFilters);
+                    }
+                }
+
+                // notes are edited from article pages, so pick up changes made since last time
+                if is_notes {
+                    if let Some(PageController::Notes(c)) = self.pages_cache.get(&key) {
+                        c.emit(NotesPageInput::Refresh);
+                    }
+                }
+
+                self.current_page_key = key;
+            }
+
+            Msg::Search(query) => {
+                let key = NavigationPage::Search.to_key();
+
+                if !self.pages_cache.contains_key(&key) {
+                    let search_page = SearchPage::builder()
+                        .launch((
+                            self.fetch_service.clone(),
+                            self.history_worker.clone(),
+                            self.image_cache.clone(),
+                            self.notes.clone(),
+                            !self.sidebar_visible,
+                            None,
+                        ))
+                        .forward(sender.input_sender(), Self::search_output);
+
+                    self.pages_cache.insert(key.clone(), PageController::Search(search_page));
+                }
+
+                if let Some(PageController::Search(c)) = self.pages_cache.get(&key) {
+                    c.emit(SearchPageInput::Search(query));
+                }
+
                 self.current_page_key = key;
             }
+
+            Msg::SaveTopic(topic) => {
+                self.topic_worker.emit(TopicWorkerInput::Add(topic));
+            }
+
+            Msg::TopicSeen(id, urls) => {
+                self.topic_worker.emit(TopicWorkerInput::Seen(id, urls));
+            }
+
+            Msg::Bookmark(article) => {
+                let bookmark = PersistentArticle::auto_create(
+                    article.title(),
+                    article.url(),
+                    article.description(),
+                    article.content(),
+                    article.published_at(),
+                    article.url_to_image().unwrap_or_default(),
+                );
+                self.bookmark_worker.emit(BookmarkWorkerInput::Save(bookmark));
+            }
+
+            Msg::RefreshSaved => {
+                self.bookmark_worker.emit(BookmarkWorkerInput::Fetch);
+            }
+        }
+    }
+}
+
+impl App {
+    fn page_output(msg: PageOutput) -> Msg {
+        match msg {
+            PageOutput::ToggleSidebar => Msg::ToggleSidebar,
+            PageOutput::OpenSettings => Msg::ChangeSectionPage(NavigationPage::Settings),
+            PageOutput::Bookmark(article) => Msg::Bookmark(article),
+        }
+    }
+
+    fn search_output(msg: SearchPageOutput) -> Msg {
+        match msg {
+            SearchPageOutput::ToggleSidebar => Msg::ToggleSidebar,
+            SearchPageOutput::Bookmark(article) => Msg::Bookmark(article),
+            SearchPageOutput::SaveTopic(topic) => Msg::SaveTopic(topic),
+            SearchPageOutput::TopicSeen(id, urls) => Msg::TopicSeen(id, urls),
         }
     }
 }
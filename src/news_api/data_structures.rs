@@ -65,6 +65,7 @@ pub enum NewsAPICusteomError {
     Api {
         code: String,
         message: String,
+        retry_after: Option<u64>,
     },
 
     #[error("Unknown error occurred")]
@@ -4,12 +4,14 @@ use url::Url;
 use crate::news_api::data_structures::{
     NewsAPIArticle, NewsAPICusteomError, NewsAPIError, NewsAPIResponse, NewsAPISource
 };
+use crate::utils::http::{RetryPolicy, retry_after};
 
 #[derive(Clone)]
 pub struct NewsAPIClient {
     api_key: String,
     base_url: Url,
     client: Client,
+    retry: RetryPolicy,
 }
 
 impl NewsAPIClient {
@@ -23,9 +25,15 @@ impl NewsAPIClient {
             api_key,
             base_url,
             client: Client::new(),
+            retry: RetryPolicy::new(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     fn build_url(
         &self,
         endpoint: &str,
@@ -57,16 +65,15 @@ impl NewsAPIClient {
     ) -> Result<NewsAPIResponse<T>, NewsAPICusteomError> {
         println!("🌐 Making request to: {}", url);
 
-        // 1. Send the request (Wraps reqwest::Error automatically via #[from])
+        // 1. Send the request, retrying transient failures (Wraps reqwest::Error automatically via #[from])
         let response = self
-            .client
-            .get(url)
-            .header("User-Agent", "FrostNews/1.0")
-            .send()
+            .retry
+            .send(self.client.get(url).header("User-Agent", "FrostNews/1.0"))
             .await?;
 
         let status = response.status();
         println!("📡 HTTP Status: {}", status);
+        let retry_after = retry_after(response.headers());
 
         // 2. Get the raw bytes
         let bytes = response.bytes().await?;
@@ -79,6 +86,7 @@ impl NewsAPIClient {
                     message: api_err
                         .message
                         .unwrap_or_else(|| "Unknown API error".into()),
+                    retry_after,
                 });
             }
             return Err(NewsAPICusteomError::Unknown);
@@ -130,6 +138,7 @@ impl NewsAPIClient {
         to: Option<String>,
         language: Option<String>,
         sort_by: Option<String>,
+        search_in: Option<String>,
         page_size: Option<i32>,
         page: Option<i32>,
     ) -> Result<NewsAPIResponse<NewsAPIArticle>, NewsAPICusteomError> {
@@ -144,6 +153,7 @@ impl NewsAPIClient {
                     ("to", to),
                     ("language", language),
                     ("sortBy", sort_by),
+                    ("searchIn", search_in),
                     ("pageSize", page_size.map(|v| v.to_string())),
                     ("page", page.map(|v| v.to_string())),
                 ],
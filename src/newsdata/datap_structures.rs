@@ -66,7 +66,7 @@ pub enum NewsdataError {
     #[error("Unprocessable Entity: Semantic error in the request.")]
     UnprocessableEntity,
     #[error("Too Many Requests: Rate limit exceeded for your plan.")]
-    RateLimitExceeded,
+    RateLimitExceeded(Option<u64>),
     #[error("Internal Server Error: An unexpected error occurred on the server.")]
     InternalServerError,
     #[error("Network Error: {0}")]
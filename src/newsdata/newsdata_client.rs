@@ -2,6 +2,7 @@ use reqwest::Client;
 use url::Url;
 
 use crate::newsdata::datap_structures::{NewsdataError, NewsDataResponse};
+use crate::utils::http::{RetryPolicy, retry_after};
 pub enum NewsEndpoint {
     Latest,
     Crypto,
@@ -27,6 +28,7 @@ pub struct NewsdataClient {
     api_token: String,
     base_url: Url,
     client: Client,
+    retry: RetryPolicy,
 }
 
 impl NewsdataClient {
@@ -35,9 +37,15 @@ impl NewsdataClient {
             api_token: api_token.into(),
             base_url: Url::parse("https://newsdata.io/api/1/").unwrap(),
             client: Client::new(),
+            retry: RetryPolicy::new(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     fn build_url(
         &self,
         endpoint: &str,
@@ -64,10 +72,8 @@ impl NewsdataClient {
 
     async fn perform_request(&self, url: Url) -> Result<NewsDataResponse, NewsdataError> {
         let response = self
-            .client
-            .get(url)
-            .header("User-Agent", "FrostNews/1.0")
-            .send()
+            .retry
+            .send(self.client.get(url).header("User-Agent", "FrostNews/1.0"))
             .await?;
 
         let status = response.status();
@@ -93,7 +99,7 @@ impl NewsdataClient {
             409 => Err(NewsdataError::ParameterDuplicate),
             415 => Err(NewsdataError::UnsupportedType),
             422 => Err(NewsdataError::UnprocessableEntity),
-            429 => Err(NewsdataError::RateLimitExceeded),
+            429 => Err(NewsdataError::RateLimitExceeded(retry_after(response.headers()))),
             500 => Err(NewsdataError::InternalServerError),
             code => Err(NewsdataError::Unknown(code)),
         }
@@ -1,7 +1,12 @@
 use crate::NewsFetchService;
 use crate::components::categorised_news::CategorisedNewsSection;
+use crate::components::news_tile::NewsTileOutput;
 use crate::pages::news_page::{NewsPage, NewsPageInput, NewsPageOutput};
+use crate::types::article_page::PageInfo;
+use crate::services::note_service::notes::Notes;
+use crate::services::workers::history_worker::HistoryWorker;
 use crate::types::cache::ImageCache;
+use crate::types::fetch_error::FetchError;
 use crate::types::news_article::NewsArticle;
 use crate::types::news_category::NewsSection;
 use crate::types::news_handler::NewsHandler;
@@ -19,6 +24,8 @@ pub struct BusinessPage {
     sections: FactoryVecDeque<CategorisedNewsSection>,
     fetch_service: NewsFetchService,
     cache: ImageCache,
+    notes: Notes,
+    history_worker: Arc<Controller<HistoryWorker>>,
     news_page_controller: Option<Controller<NewsPage>>,
     navigation_view: adw::NavigationView,
     category: NewsSection,
@@ -28,8 +35,12 @@ pub struct BusinessPage {
 pub enum BusinessPageInput {
     FetchNews,
     NewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>),
+    PageLoaded(PageInfo),
+    OfflineNewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, i64),
     GotoNews((Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>)),
-    ApiError(String),
+    Bookmark(Arc<dyn NewsArticle>),
+    ApiError(FetchError),
+    SourceFallback(String, String),
     ShowSidebarToggleBtn(bool),
 }
 
@@ -37,19 +48,36 @@ impl NewsHandler for BusinessPageInput {
     fn on_news_received(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>) -> Self {
         BusinessPageInput::NewsReceived(grouped)
     }
-    fn on_error(err: String) -> Self {
+    fn on_error(err: FetchError) -> Self {
         BusinessPageInput::ApiError(err)
     }
+    fn on_page_loaded(info: PageInfo) -> Self {
+        BusinessPageInput::PageLoaded(info)
+    }
+    fn on_fallback(failed: String, served_by: String) -> Self {
+        BusinessPageInput::SourceFallback(failed, served_by)
+    }
+    fn on_offline(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, fetched_at: i64) -> Self {
+        BusinessPageInput::OfflineNewsReceived(grouped, fetched_at)
+    }
 }
 
 #[derive(Debug)]
 pub enum BusinessPageOutput {
     ToggleSidebar,
+    Bookmark(Arc<dyn NewsArticle>),
 }
 
 #[relm4::component(pub)]
 impl Component for BusinessPage {
-    type Init = (NewsSection, NewsFetchService, ImageCache, bool);
+    type Init = (
+        NewsSection,
+        NewsFetchService,
+        ImageCache,
+        Notes,
+        Arc<Controller<HistoryWorker>>,
+        bool,
+    );
     type Input = BusinessPageInput;
     type Output = BusinessPageOutput;
     type CommandOutput = ();
@@ -123,11 +151,14 @@ impl Component for BusinessPage {
         root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let (category, fetch_service, cache, show_sidebar_toggle_btn) = init;
+        let (category, fetch_service, cache, notes, history_worker, show_sidebar_toggle_btn) = init;
 
         let sections = FactoryVecDeque::builder()
             .launch(gtk::Box::new(gtk::Orientation::Vertical, 12))
-            .forward(sender.input_sender(), move |data| BusinessPageInput::GotoNews(data));
+            .forward(sender.input_sender(), move |output| match output {
+                NewsTileOutput::Open(data) => BusinessPageInput::GotoNews(data),
+                NewsTileOutput::Bookmark(article) => BusinessPageInput::Bookmark(article),
+            });
 
         let navigation_view = adw::NavigationView::builder().build();
 
@@ -136,6 +167,8 @@ impl Component for BusinessPage {
             sections,
             fetch_service,
             cache,
+            notes,
+            history_worker,
             navigation_view,
             news_page_controller: None,
             category
@@ -164,21 +197,36 @@ impl Component for BusinessPage {
                 let source = NewsSource::NewsAPI;
                 let sender_clone = sender.clone();
                 self.fetch_service
-                    .fetch_news(self.category.clone(),0, sender_clone);
+                    .fetch_news(self.category.clone(), 0, BTreeMap::new(), sender_clone);
             }
             BusinessPageInput::NewsReceived(grouped_data) => {
                 let mut guard = self.sections.guard();
                 guard.clear();
+                let noted = Arc::new(self.notes.noted_ids());
                 let mut buckets: Vec<_> = grouped_data.keys().cloned().collect();
                 buckets
                     .sort_by(|a, b| time_organizer::UITimeOrganiser::comapre(a.clone(), b.clone()));
 
                 for bucket in buckets {
                     if let Some(articles) = grouped_data.get(&bucket) {
-                        guard.push_back((bucket, articles.to_vec(), self.cache.clone()));
+                        guard.push_back((
+                                bucket,
+                                articles.to_vec(),
+                                self.cache.clone(),
+                                noted.clone(),
+                            ));
                     }
                 }
             }
+            // this page shows a single page of results
+            BusinessPageInput::PageLoaded(_) => {}
+            BusinessPageInput::OfflineNewsReceived(grouped_data, fetched_at) => {
+                eprintln!("Offline, showing news stored at {}", fetched_at);
+                sender.input(BusinessPageInput::NewsReceived(grouped_data));
+            }
+            BusinessPageInput::Bookmark(article) => {
+                let _ = sender.output(BusinessPageOutput::Bookmark(article));
+            }
             BusinessPageInput::GotoNews(data) => {
                 let (article, related_articles) = data;
 
@@ -188,10 +236,13 @@ impl Component for BusinessPage {
                             article.clone(),
                             related_articles.clone(),
                             self.cache.clone(),
+                            self.notes.clone(),
+                            self.history_worker.clone(),
                             self.show_sidebar_toggle_btn,
                         ))
                         .forward(sender.output_sender(), move |action| match action {
                             NewsPageOutput::ToggleSidebar => BusinessPageOutput::ToggleSidebar,
+                            NewsPageOutput::Bookmark(article) => BusinessPageOutput::Bookmark(article),
                         }),
                 );
 
@@ -203,6 +254,9 @@ impl Component for BusinessPage {
             BusinessPageInput::ApiError(e) => {
                 eprintln!("Error loading news: {}", e);
             }
+            BusinessPageInput::SourceFallback(failed, served_by) => {
+                eprintln!("{} is unavailable, showing news from {}", failed, served_by);
+            }
         }
     }
 }
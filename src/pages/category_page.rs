@@ -1,12 +1,17 @@
 use crate::NewsFetchService;
 use crate::components::categorised_news::CategorisedNewsSection;
+use crate::components::news_tile::NewsTileOutput;
 use crate::pages::news_page::{NewsPage, NewsPageInput, NewsPageOutput};
 use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput};
+use crate::types::article_page::PageInfo;
+use crate::services::note_service::notes::Notes;
 use crate::types::cache::ImageCache;
+use crate::types::fetch_error::FetchError;
 use crate::types::news_article::NewsArticle;
 use crate::types::news_category::NewsSection;
 use crate::types::news_handler::NewsHandler;
 use crate::types::persistent_articel::PersistentArticle;
+use crate::types::visit_origin::VisitOrigin;
 use crate::utils::page_pignation::NewsPagination;
 use crate::utils::time_organizer;
 
@@ -21,11 +26,21 @@ pub struct CategoryPage {
     sections: FactoryVecDeque<CategorisedNewsSection>,
     fetch_service: NewsFetchService,
     cache: ImageCache,
+    notes: Notes,
     news_page_controller: Option<Controller<NewsPage>>,
     navigation_view: adw::NavigationView,
     category: NewsSection,
     is_refreshing: bool,
     error_message: Option<String>,
+    error: Option<FetchError>,
+    // seconds left before a rate limited page retries on its own
+    retry_countdown: Option<u64>,
+    // bumped on every new countdown so ticks from an older one are ignored
+    countdown_generation: u32,
+    // set when the selected source failed and a fallback served the page
+    fallback_notice: Option<String>,
+    // fetch time of the stored articles shown while the network is unreachable
+    offline_since: Option<i64>,
     is_loading: bool,
     pagination: NewsPagination,
     reached_end: bool,
@@ -37,13 +52,21 @@ pub struct CategoryPage {
 pub enum PageInput {
     FetchNews,
     Refresh,
+    ForceRefresh,
     NextPage,
     PreviousPage,
     CopyError,
     LoadPage(i32),
     NewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>),
+    PageLoaded(PageInfo),
+    OfflineNewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, i64),
     GotoNews((Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>)),
-    ApiError(String),
+    Bookmark(Arc<dyn NewsArticle>),
+    ApiError(FetchError),
+    RetryCountdownTick(u32),
+    OpenSettings,
+    BannerAction,
+    SourceFallback(String, String),
     ShowSidebarToggleBtn(bool),
 }
 
@@ -51,14 +74,66 @@ impl NewsHandler for PageInput {
     fn on_news_received(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>) -> Self {
         PageInput::NewsReceived(grouped)
     }
-    fn on_error(err: String) -> Self {
+    fn on_error(err: FetchError) -> Self {
         PageInput::ApiError(err)
     }
+    fn on_page_loaded(info: PageInfo) -> Self {
+        PageInput::PageLoaded(info)
+    }
+    fn on_fallback(failed: String, served_by: String) -> Self {
+        PageInput::SourceFallback(failed, served_by)
+    }
+    fn on_offline(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, fetched_at: i64) -> Self {
+        PageInput::OfflineNewsReceived(grouped, fetched_at)
+    }
 }
 
 #[derive(Debug)]
 pub enum PageOutput {
     ToggleSidebar,
+    OpenSettings,
+    Bookmark(Arc<dyn NewsArticle>),
+}
+
+impl CategoryPage {
+    /// Auth and quota problems can only be fixed by changing the source or the key
+    fn needs_settings(&self) -> bool {
+        matches!(
+            self.error,
+            Some(FetchError::Auth(_)) | Some(FetchError::Quota(_))
+        )
+    }
+
+    fn error_title(&self) -> &'static str {
+        match self.error {
+            Some(FetchError::Auth(_)) => "API Key Problem",
+            Some(FetchError::Quota(_)) => "Quota Reached",
+            Some(FetchError::RateLimited { .. }) => "Rate Limited",
+            Some(FetchError::Network(_)) => "No Connection",
+            Some(FetchError::Server(_)) => "Server Error",
+            _ => "Error",
+        }
+    }
+
+    fn offline_label(&self) -> String {
+        match self
+            .offline_since
+            .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+        {
+            Some(fetched_at) => format!(
+                "Offline — showing cached news from {}",
+                fetched_at.with_timezone(&chrono::Local).format("%H:%M")
+            ),
+            None => String::new(),
+        }
+    }
+
+    fn retry_label(&self) -> String {
+        match self.retry_countdown {
+            Some(seconds) => format!("Retrying in {}s", seconds),
+            None => String::new(),
+        }
+    }
 }
 
 #[relm4::component(pub)]
@@ -68,6 +143,7 @@ impl Component for CategoryPage {
         NewsFetchService,
         Arc<Controller<HistoryWorker>>,
         ImageCache,
+        Notes,
         bool,
     );
     type Input = PageInput;
@@ -134,7 +210,8 @@ impl Component for CategoryPage {
                                                         },
 
                                                         gtk::Label{
-                                                            set_label: "Error",
+                                                            #[watch]
+                                                            set_label: model.error_title(),
                                                         }
                                                     },
 
@@ -150,6 +227,14 @@ impl Component for CategoryPage {
                                                         add_css_class: "error",
                                                     },
 
+                                                    gtk::Label {
+                                                        #[watch]
+                                                        set_label: &model.retry_label(),
+                                                        #[watch]
+                                                        set_visible: model.retry_countdown.is_some(),
+                                                        add_css_class: "dim-label",
+                                                    },
+
                                                      gtk::Box {
                                                         set_orientation: gtk::Orientation::Horizontal,
                                                         set_halign: gtk::Align::Center,
@@ -179,10 +264,24 @@ impl Component for CategoryPage {
                                                             set_icon_name: "view-refresh-symbolic",
                                                             add_css_class: "circular",
                                                             set_tooltip_text: Some("Retry"),
+                                                            #[watch]
+                                                            set_sensitive: model.retry_countdown.is_none(),
                                                             connect_clicked[sender] => move |_| {
                                                                 sender.input(PageInput::FetchNews);
                                                             }
                                                         }
+                                                    },
+
+                                                    gtk::Button {
+                                                        set_label: "Open Settings",
+                                                        set_halign: gtk::Align::Center,
+                                                        add_css_class: "pill",
+                                                        add_css_class: "suggested-action",
+                                                        #[watch]
+                                                        set_visible: model.needs_settings(),
+                                                        connect_clicked[sender] => move |_| {
+                                                            sender.input(PageInput::OpenSettings);
+                                                        }
                                                     }
                                                 },
                                             }
@@ -241,16 +340,37 @@ impl Component for CategoryPage {
                                             set_halign: gtk::Align::Fill,
                                         },
 
+                                        adw::Banner {
+                                            #[watch]
+                                            set_title: &model.offline_label(),
+                                            #[watch]
+                                            set_revealed: model.offline_since.is_some() && !model.is_refreshing,
+                                            set_button_label: Some("Retry"),
+                                            connect_button_clicked[sender] => move |_| {
+                                                sender.input(PageInput::Refresh);
+                                            }
+                                        },
+
 
 
                                     adw::Banner {
                                         #[watch]
-                                        set_title: model.error_message.as_deref().unwrap_or(""),
+                                        set_title: &match model.retry_countdown {
+                                            Some(_) => format!("{} {}", model.error_message.as_deref().unwrap_or(""), model.retry_label()),
+                                            None => model.error_message.clone().unwrap_or_default(),
+                                        },
                                         #[watch]
                                         set_revealed: model.error_message.is_some(),
-                                        set_button_label: Some("Retry"),
+                                        #[watch]
+                                        set_button_label: if model.needs_settings() {
+                                            Some("Open Settings")
+                                        } else if model.retry_countdown.is_some() {
+                                            None
+                                        } else {
+                                            Some("Retry")
+                                        },
                                         connect_button_clicked[sender] => move |_| {
-                                            sender.input(PageInput::Refresh);
+                                            sender.input(PageInput::BannerAction);
                                         }
                                     },
 
@@ -279,6 +399,16 @@ impl Component for CategoryPage {
                                                         set_xalign: 0.0,
                                                     },
 
+                                                    gtk::Label {
+                                                        #[watch]
+                                                        set_label: model.fallback_notice.as_deref().unwrap_or(""),
+                                                        #[watch]
+                                                        set_visible: model.fallback_notice.is_some(),
+                                                        add_css_class: "caption",
+                                                        add_css_class: "warning",
+                                                        set_xalign: 0.0,
+                                                    },
+
                                                     #[local_ref]
                                                     sections -> gtk::Box {
                                                         set_orientation: gtk::Orientation::Vertical,
@@ -337,11 +467,14 @@ impl Component for CategoryPage {
         root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let (category, fetch_service, history_worker, cache, show_sidebar_toggle_btn) = init;
+        let (category, fetch_service, history_worker, cache, notes, show_sidebar_toggle_btn) = init;
 
         let sections = FactoryVecDeque::builder()
             .launch(gtk::Box::new(gtk::Orientation::Vertical, 12))
-            .forward(sender.input_sender(), move |data| PageInput::GotoNews(data));
+            .forward(sender.input_sender(), move |output| match output {
+                NewsTileOutput::Open(data) => PageInput::GotoNews(data),
+                NewsTileOutput::Bookmark(article) => PageInput::Bookmark(article),
+            });
 
         let navigation_view = adw::NavigationView::builder().build();
 
@@ -350,11 +483,17 @@ impl Component for CategoryPage {
             sections,
             fetch_service,
             cache,
+            notes,
             navigation_view,
             news_page_controller: None,
             category,
             is_refreshing: false,
             error_message: None,
+            error: None,
+            retry_countdown: None,
+            countdown_generation: 0,
+            fallback_notice: None,
+            offline_since: None,
             is_loading: true,
             pagination: NewsPagination::new(),
             reached_end: false,
@@ -396,7 +535,7 @@ impl Component for CategoryPage {
         widgets: &mut Self::Widgets, // <--- Now you have access!
         msg: Self::Input,
         sender: ComponentSender<Self>,
-        _root: &Self::Root,
+        root: &Self::Root,
     ) {
         match msg {
             PageInput::ShowSidebarToggleBtn(visible) => {
@@ -409,11 +548,44 @@ impl Component for CategoryPage {
             }
             PageInput::FetchNews => {
                 self.error_message = None;
+                self.error = None;
+                self.retry_countdown = None;
+                self.fallback_notice = None;
+                self.offline_since = None;
                 let sender_clone = sender.clone();
-                self.fetch_service
-                    .fetch_news(self.category.clone(), 0, sender_clone);
+                let page = self.pagination.current_page;
+                self.fetch_service.fetch_news(
+                    self.category.clone(),
+                    page,
+                    self.pagination.cursors_for(page),
+                    sender_clone,
+                );
             }
-            PageInput::Refresh => {
+            PageInput::Refresh => match self.fetch_service.quota_warning(&self.category) {
+                Some(warning) => {
+                    let dialog = adw::AlertDialog::new(
+                        Some("Quota Running Low"),
+                        Some(&format!(
+                            "{}\n\nRefreshing now may go over the free tier.",
+                            warning
+                        )),
+                    );
+                    dialog.add_responses(&[("cancel", "Cancel"), ("refresh", "Refresh Anyway")]);
+                    dialog.set_response_appearance("refresh", adw::ResponseAppearance::Destructive);
+                    dialog.set_default_response(Some("cancel"));
+                    dialog.set_close_response("cancel");
+
+                    let sender = sender.clone();
+                    dialog.connect_response(None, move |_, response| {
+                        if response == "refresh" {
+                            sender.input(PageInput::ForceRefresh);
+                        }
+                    });
+                    dialog.present(Some(root));
+                }
+                None => sender.input(PageInput::ForceRefresh),
+            },
+            PageInput::ForceRefresh => {
                 self.is_refreshing = true;
                 self.error_message = None;
                 self.pagination.reset();
@@ -435,7 +607,8 @@ impl Component for CategoryPage {
                         self.pagination.current_page -= 1;
                     }
                 } else {
-                    self.reached_end = false;
+                    // every provider has said it has nothing after this page
+                    self.reached_end = !self.pagination.has_next(self.pagination.current_page);
 
                     // 1. Update the cache/pagination data
                     self.pagination
@@ -445,6 +618,7 @@ impl Component for CategoryPage {
                     // 2. Update the Factory (The actual UI elements)
                     let mut guard = self.sections.guard();
                     guard.clear();
+                    let noted = Arc::new(self.notes.noted_ids());
 
                     let mut buckets: Vec<_> = grouped_data.keys().cloned().collect();
                     buckets.sort_by(|a, b| {
@@ -453,13 +627,28 @@ impl Component for CategoryPage {
 
                     for bucket in buckets {
                         if let Some(articles) = grouped_data.get(&bucket) {
-                            guard.push_back((bucket, articles.to_vec(), self.cache.clone()));
+                            guard.push_back((
+                                bucket,
+                                articles.to_vec(),
+                                self.cache.clone(),
+                                noted.clone(),
+                            ));
                         }
                     }
                 }
 
                 self.is_loading = false;
             }
+            PageInput::PageLoaded(info) => {
+                self.pagination.record(self.pagination.current_page, info);
+            }
+            PageInput::OfflineNewsReceived(grouped_data, fetched_at) => {
+                self.offline_since = Some(fetched_at);
+                sender.input(PageInput::NewsReceived(grouped_data));
+            }
+            PageInput::Bookmark(article) => {
+                let _ = sender.output(PageOutput::Bookmark(article));
+            }
             PageInput::GotoNews(data) => {
                 let (article, related_articles) = data;
 
@@ -472,18 +661,22 @@ impl Component for CategoryPage {
                     article.url_to_image().unwrap_or("".to_string()),
                 );
 
+                let origin = VisitOrigin::new(article.source(), Some(self.category.label()));
                 self.history_worker
-                    .emit(HistoryWorkerInput::Save(persistent_arcticle));
+                    .emit(HistoryWorkerInput::Save(persistent_arcticle, origin));
                 self.news_page_controller = Some(
                     NewsPage::builder()
                         .launch((
                             article.clone(),
                             related_articles.clone(),
                             self.cache.clone(),
+                            self.notes.clone(),
+                            self.history_worker.clone(),
                             self.show_sidebar_toggle_btn,
                         ))
                         .forward(sender.output_sender(), move |action| match action {
                             NewsPageOutput::ToggleSidebar => PageOutput::ToggleSidebar,
+                            NewsPageOutput::Bookmark(article) => PageOutput::Bookmark(article),
                         }),
                 );
 
@@ -512,14 +705,64 @@ impl Component for CategoryPage {
                     self.is_refreshing = true;
                     self.pagination.current_page = page_num;
                     // Pass the page number to your fetch service
-                    self.fetch_service
-                        .fetch_news(self.category.clone(), page_num, sender.clone());
+                    self.fetch_service.fetch_news(
+                        self.category.clone(),
+                        page_num,
+                        self.pagination.cursors_for(page_num),
+                        sender.clone(),
+                    );
                 }
             }
             PageInput::ApiError(e) => {
                 self.is_refreshing = false;
                 self.error_message = Some(format!("{}", e));
                 println!("eror: {}", self.error_message.clone().unwrap());
+
+                if let FetchError::RateLimited { .. } = &e {
+                    // providers that don't say how long to wait usually reset within a minute
+                    self.retry_countdown = Some(e.retry_after().unwrap_or(60));
+                    self.countdown_generation += 1;
+                    let generation = self.countdown_generation;
+                    let sender = sender.clone();
+                    gtk::glib::timeout_add_seconds_local_once(1, move || {
+                        sender.input(PageInput::RetryCountdownTick(generation));
+                    });
+                }
+                self.error = Some(e);
+            }
+            PageInput::RetryCountdownTick(generation) => {
+                if generation != self.countdown_generation {
+                    return;
+                }
+                match self.retry_countdown {
+                    Some(0) | Some(1) => sender.input(PageInput::Refresh),
+                    Some(seconds) => {
+                        self.retry_countdown = Some(seconds - 1);
+                        let sender = sender.clone();
+                        gtk::glib::timeout_add_seconds_local_once(1, move || {
+                            sender.input(PageInput::RetryCountdownTick(generation));
+                        });
+                    }
+                    // a manual retry already cleared the countdown
+                    None => {}
+                }
+            }
+            PageInput::BannerAction => {
+                if self.needs_settings() {
+                    sender.input(PageInput::OpenSettings);
+                } else {
+                    sender.input(PageInput::Refresh);
+                }
+            }
+            PageInput::OpenSettings => {
+                let _ = sender.output(PageOutput::OpenSettings);
+            }
+            PageInput::SourceFallback(failed, served_by) => {
+                let notice = format!("{} is unavailable, showing news from {}", failed, served_by);
+                let toast = adw::Toast::new(&notice);
+                toast.set_timeout(5);
+                widgets.toast_overlay.add_toast(toast);
+                self.fallback_notice = Some(notice);
             }
             PageInput::CopyError => {
                 gtk::gdk::Display::default().unwrap().clipboard().set_text(
@@ -1,42 +1,155 @@
 use adw::prelude::*;
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
 use relm4::{Component, ComponentParts, ComponentSender, Controller, prelude::*};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
 use crate::components::history_bucket::{HistoryBucket, HistoryBucketInput, HistoryBucketOutput};
-use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput};
+use crate::services::history_service::export::ExportFormat;
+use crate::services::note_service::notes::Notes;
+use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput, UNDO_SECS};
+use crate::types::history_filter::HistoryFilter;
 use crate::types::persistent_articel::PersistentArticle;
 
+const DATE_RANGES: [&str; 5] = [
+    "Any Time",
+    "Today",
+    "Past Week",
+    "Past Month",
+    "Custom Range",
+];
+const CUSTOM_RANGE: usize = 4;
+
 #[derive(Debug)]
 pub struct HistoryPage {
     history_worker: Arc<Controller<HistoryWorker>>,
+    notes: Notes,
     show_sidebar_toggle_btn: bool,
     navigation_view: adw::NavigationView,
     sections: FactoryVecDeque<HistoryBucket>,
     history_available: bool,
     select_mode_on: bool,
+    // ids picked in selection mode, across every day
+    selected: HashSet<String>,
+    query: String,
+    range: usize,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    has_more: bool,
+    // a page was asked for and has not arrived yet
+    loading_more: bool,
 }
 
 #[derive(Debug)]
 pub enum HistoryPageInput {
-    UpdateHistory(Vec<Arc<PersistentArticle>>),
+    UpdateHistory(Vec<Arc<PersistentArticle>>, bool),
+    SetQuery(String),
+    SetRange(u32),
+    SetFrom(NaiveDate),
+    SetTo(NaiveDate),
+    LoadMore,
+    Export(ExportFormat),
+    Import,
+    SavedChanged,
     DeleteHistoryEntry(String),
+    // entries were deleted and can be brought back until the toast goes away
+    Trashed(i64, usize),
+    Undo(i64),
+    // how an export or import went
+    ShowResult(String),
     ShowSidebarToggleBtn(bool),
     ClearHistory,
+    ClearRecent(Duration),
     ActivateSelectionMode,
     DeactivateSelectMode,
+    EntrySelected(String),
+    EntryDeselected(String),
+    DeleteSelected,
+    BookmarkSelected,
+    ExportSelected(ExportFormat),
     Dummy,
 }
 
 #[derive(Debug)]
 pub enum HistoryPagePageOutput {
     ToggleSidebar,
+    // an import added saved articles
+    SavedChanged,
+}
+
+impl HistoryPage {
+    fn filter(&self) -> HistoryFilter {
+        let start_of = |date: NaiveDate| {
+            date.and_hms_opt(0, 0, 0)
+                .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+                .map(|midnight| midnight.timestamp())
+        };
+        let today = Local::now().date_naive();
+
+        let (since, until) = match self.range {
+            1 => (start_of(today), None),
+            2 => (start_of(today - Duration::days(6)), None),
+            3 => (start_of(today - Duration::days(29)), None),
+            CUSTOM_RANGE => (
+                self.from.and_then(start_of),
+                // the end date is included, so stop at the midnight after it
+                self.to.and_then(|date| date.succ_opt()).and_then(start_of),
+            ),
+            _ => (None, None),
+        };
+
+        HistoryFilter {
+            query: self.query.clone(),
+            since,
+            until,
+        }
+    }
+
+    fn apply_filter(&mut self) {
+        self.loading_more = false;
+        self.history_worker
+            .emit(HistoryWorkerInput::Filter(self.filter()));
+    }
+
+    fn date_label(date: Option<NaiveDate>, placeholder: &str) -> String {
+        date.map(|date| date.format("%b %d, %Y").to_string())
+            .unwrap_or_else(|| placeholder.to_string())
+    }
+
+    fn calendar_date(calendar: &gtk::Calendar) -> Option<NaiveDate> {
+        let date = calendar.date();
+        NaiveDate::from_ymd_opt(date.year(), date.month() as u32, date.day_of_month() as u32)
+    }
+
+    fn selection_label(&self) -> String {
+        match self.selected.len() {
+            0 => "No entries selected".to_string(),
+            1 => "1 entry selected".to_string(),
+            count => format!("{} entries selected", count),
+        }
+    }
+
+    /// Hands the selection to a bulk action and leaves selection mode
+    fn take_selection(&mut self) -> Vec<String> {
+        let ids = self.selected.drain().collect();
+        self.select_mode_on = false;
+        self.sections
+            .broadcast(HistoryBucketInput::DeactivateSelectionMode);
+        ids
+    }
+
+    fn empty_message(&self) -> &'static str {
+        if self.filter().is_empty() {
+            "No History Available."
+        } else {
+            "No history matches the search."
+        }
+    }
 }
 
 #[relm4::component(pub)]
 impl Component for HistoryPage {
-    type Init = (Arc<Controller<HistoryWorker>>, bool);
+    type Init = (Arc<Controller<HistoryWorker>>, Notes, bool);
     type Input = HistoryPageInput;
     type Output = HistoryPagePageOutput;
     type CommandOutput = ();
@@ -61,8 +174,115 @@ impl Component for HistoryPage {
                                         let _ = sender.output(HistoryPagePageOutput::ToggleSidebar);
                                     },
                                 },
+                                pack_end = &gtk::MenuButton {
+                                    set_icon_name: "open-menu-symbolic",
+                                    set_tooltip_text: Some("Export and Import"),
+
+                                    #[wrap(Some)]
+                                    #[name = "history_menu"]
+                                    set_popover = &gtk::Popover {
+                                        gtk::Box {
+                                            set_orientation: gtk::Orientation::Vertical,
+
+                                            gtk::Button {
+                                                set_label: "Export as JSON",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender, history_menu] => move |_| {
+                                                    history_menu.popdown();
+                                                    sender.input(HistoryPageInput::Export(ExportFormat::Json));
+                                                }
+                                            },
+                                            gtk::Button {
+                                                set_label: "Export as CSV",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender, history_menu] => move |_| {
+                                                    history_menu.popdown();
+                                                    sender.input(HistoryPageInput::Export(ExportFormat::Csv));
+                                                }
+                                            },
+                                            gtk::Button {
+                                                set_label: "Export as HTML Bookmarks",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender, history_menu] => move |_| {
+                                                    history_menu.popdown();
+                                                    sender.input(HistoryPageInput::Export(ExportFormat::Html));
+                                                }
+                                            },
+                                            gtk::Separator {},
+                                            gtk::Button {
+                                                set_label: "Import JSON…",
+                                                add_css_class: "flat",
+                                                connect_clicked[sender, history_menu] => move |_| {
+                                                    history_menu.popdown();
+                                                    sender.input(HistoryPageInput::Import);
+                                                }
+                                            },
+                                        }
+                                    },
+                                },
+
+
+                            },
+
+                            adw::Clamp {
+                                set_margin_bottom: 12,
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Horizontal,
+                                    set_margin_horizontal: 40,
+                                    set_spacing: 10,
+
+                                    gtk::SearchEntry {
+                                        set_placeholder_text: Some("Search history"),
+                                        set_hexpand: true,
+                                        connect_search_changed[sender] => move |entry| {
+                                            sender.input(HistoryPageInput::SetQuery(entry.text().to_string()));
+                                        }
+                                    },
+
+                                    gtk::DropDown::from_strings(&DATE_RANGES) {
+                                        set_tooltip_text: Some("Visited"),
+                                        connect_selected_notify[sender] => move |dropdown| {
+                                            sender.input(HistoryPageInput::SetRange(dropdown.selected()));
+                                        }
+                                    },
 
+                                    gtk::MenuButton {
+                                        #[watch]
+                                        set_label: &HistoryPage::date_label(model.from, "From"),
+                                        #[watch]
+                                        set_visible: model.range == CUSTOM_RANGE,
+
+                                        #[wrap(Some)]
+                                        set_popover = &gtk::Popover {
+                                            gtk::Calendar {
+                                                connect_day_selected[sender] => move |calendar| {
+                                                    if let Some(date) = HistoryPage::calendar_date(calendar) {
+                                                        sender.input(HistoryPageInput::SetFrom(date));
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    },
 
+                                    gtk::MenuButton {
+                                        #[watch]
+                                        set_label: &HistoryPage::date_label(model.to, "To"),
+                                        #[watch]
+                                        set_visible: model.range == CUSTOM_RANGE,
+
+                                        #[wrap(Some)]
+                                        set_popover = &gtk::Popover {
+                                            gtk::Calendar {
+                                                connect_day_selected[sender] => move |calendar| {
+                                                    if let Some(date) = HistoryPage::calendar_date(calendar) {
+                                                        sender.input(HistoryPageInput::SetTo(date));
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    },
+                                },
                             },
 
                             adw::Clamp{
@@ -74,16 +294,15 @@ impl Component for HistoryPage {
                                     set_margin_horizontal: 40,
                                     set_spacing: 10,
 
-                                    gtk::Button {
+                                    adw::SplitButton {
                                         add_css_class: "destructive-action",
-                                        add_css_class: "circular",
                                         set_halign: gtk::Align::End,
+                                        set_dropdown_tooltip: "Clear Recent History",
                                         #[watch]
                                         set_visible: model.history_available.clone(),
 
-
-
-                                        adw::ButtonContent {
+                                        #[wrap(Some)]
+                                        set_child = &adw::ButtonContent {
                                             set_icon_name: "edit-clear-all-symbolic",
                                             set_label: "Clear",
                                             set_margin_all: 10,
@@ -92,14 +311,47 @@ impl Component for HistoryPage {
 
                                         connect_clicked[sender] => move |_| {
                                             sender.input(HistoryPageInput::ClearHistory);
-                                        }
+                                        },
+
+                                        #[wrap(Some)]
+                                        #[name = "clear_menu"]
+                                        set_popover = &gtk::Popover {
+                                            gtk::Box {
+                                                set_orientation: gtk::Orientation::Vertical,
+
+                                                gtk::Button {
+                                                    set_label: "Clear Last Hour",
+                                                    add_css_class: "flat",
+                                                    connect_clicked[sender, clear_menu] => move |_| {
+                                                        clear_menu.popdown();
+                                                        sender.input(HistoryPageInput::ClearRecent(Duration::hours(1)));
+                                                    }
+                                                },
+                                                gtk::Button {
+                                                    set_label: "Clear Last Day",
+                                                    add_css_class: "flat",
+                                                    connect_clicked[sender, clear_menu] => move |_| {
+                                                        clear_menu.popdown();
+                                                        sender.input(HistoryPageInput::ClearRecent(Duration::days(1)));
+                                                    }
+                                                },
+                                                gtk::Button {
+                                                    set_label: "Clear Last Week",
+                                                    add_css_class: "flat",
+                                                    connect_clicked[sender, clear_menu] => move |_| {
+                                                        clear_menu.popdown();
+                                                        sender.input(HistoryPageInput::ClearRecent(Duration::weeks(1)));
+                                                    }
+                                                },
+                                            }
+                                        },
                                     },
 
                                     gtk::Button {
                                         add_css_class: "circular",
                                         set_halign: gtk::Align::End,
                                         #[watch]
-                                        set_visible: model.history_available.clone(),
+                                        set_visible: model.history_available && !model.select_mode_on,
 
 
 
@@ -122,44 +374,145 @@ impl Component for HistoryPage {
 
                         },
 
-                        #[wrap(Some)]
-                        set_content = &gtk::ScrolledWindow {
-                            set_hscrollbar_policy: gtk::PolicyType::Never,
+                        add_bottom_bar = &gtk::ActionBar {
+                            #[watch]
+                            set_revealed: model.select_mode_on,
 
-                            adw::Clamp {
-                                set_margin_top: 20,
-                                set_margin_bottom: 40,
+                            pack_start = &gtk::Button {
+                                set_label: "Done",
+                                connect_clicked[sender] => move |_| {
+                                    sender.input(HistoryPageInput::DeactivateSelectMode);
+                                }
+                            },
 
-                                gtk::Box{
-                                    set_orientation: gtk::Orientation::Vertical,
+                            #[wrap(Some)]
+                            set_center_widget = &gtk::Label {
+                                #[watch]
+                                set_label: &model.selection_label(),
+                            },
 
+                            pack_end = &gtk::Button {
+                                set_icon_name: "user-trash-symbolic",
+                                set_tooltip: "Delete Selected",
+                                add_css_class: "destructive-action",
+                                #[watch]
+                                set_sensitive: !model.selected.is_empty(),
+                                connect_clicked[sender] => move |_| {
+                                    sender.input(HistoryPageInput::DeleteSelected);
+                                }
+                            },
 
-                                    gtk::Label{
-                                        set_label: "History",
-                                        set_xalign: 0.0,
-                                        add_css_class: "frost-brand-title",
-                                        set_margin_bottom: 20,
-                                    },
+                            pack_end = &gtk::Button {
+                                set_icon_name: "user-bookmarks-symbolic",
+                                set_tooltip: "Save Selected",
+                                #[watch]
+                                set_sensitive: !model.selected.is_empty(),
+                                connect_clicked[sender] => move |_| {
+                                    sender.input(HistoryPageInput::BookmarkSelected);
+                                }
+                            },
 
+                            pack_end = &gtk::MenuButton {
+                                set_icon_name: "document-save-symbolic",
+                                set_tooltip_text: Some("Export Selected"),
+                                #[watch]
+                                set_sensitive: !model.selected.is_empty(),
+
+                                #[wrap(Some)]
+                                #[name = "selection_export_menu"]
+                                set_popover = &gtk::Popover {
+                                    gtk::Box {
+                                        set_orientation: gtk::Orientation::Vertical,
+
+                                        gtk::Button {
+                                            set_label: "Export as JSON",
+                                            add_css_class: "flat",
+                                            connect_clicked[sender, selection_export_menu] => move |_| {
+                                                selection_export_menu.popdown();
+                                                sender.input(HistoryPageInput::ExportSelected(ExportFormat::Json));
+                                            }
+                                        },
+                                        gtk::Button {
+                                            set_label: "Export as CSV",
+                                            add_css_class: "flat",
+                                            connect_clicked[sender, selection_export_menu] => move |_| {
+                                                selection_export_menu.popdown();
+                                                sender.input(HistoryPageInput::ExportSelected(ExportFormat::Csv));
+                                            }
+                                        },
+                                        gtk::Button {
+                                            set_label: "Export as HTML Bookmarks",
+                                            add_css_class: "flat",
+                                            connect_clicked[sender, selection_export_menu] => move |_| {
+                                                selection_export_menu.popdown();
+                                                sender.input(HistoryPageInput::ExportSelected(ExportFormat::Html));
+                                            }
+                                        },
+                                    }
+                                },
+                            },
+                        },
 
+                        #[wrap(Some)]
+                        #[name = "toast_overlay"]
+                        set_content = &adw::ToastOverlay {
+                            #[wrap(Some)]
+                            set_child = &gtk::ScrolledWindow {
+                                set_hscrollbar_policy: gtk::PolicyType::Never,
+                                connect_edge_reached[sender] => move |_, position| {
+                                    if position == gtk::PositionType::Bottom {
+                                        sender.input(HistoryPageInput::LoadMore);
+                                    }
+                                },
+
+                                adw::Clamp {
+                                    set_margin_top: 20,
+                                    set_margin_bottom: 40,
 
                                     gtk::Box{
-                                        #[watch]
-                                        set_visible: !model.history_available.clone(),
+                                        set_orientation: gtk::Orientation::Vertical,
+
 
                                         gtk::Label{
-                                            set_label: "No History Availbe."
-                                        }
-                                    },
+                                            set_label: "History",
+                                            set_xalign: 0.0,
+                                            add_css_class: "frost-brand-title",
+                                            set_margin_bottom: 20,
+                                        },
 
-                                    #[local_ref]
-                                    sections_widget -> gtk::Box {
-                                        #[watch]
-                                        set_visible: model.history_available.clone(),
+
+
+                                        gtk::Box{
+                                            #[watch]
+                                            set_visible: !model.history_available.clone(),
+
+                                            gtk::Label{
+                                                #[watch]
+                                                set_label: model.empty_message(),
+                                            }
+                                        },
+
+                                        #[local_ref]
+                                        sections_widget -> gtk::Box {
+                                            #[watch]
+                                            set_visible: model.history_available.clone(),
+                                        },
+
+                                        gtk::Button {
+                                            set_label: "Show More",
+                                            set_halign: gtk::Align::Center,
+                                            set_margin_top: 20,
+                                            add_css_class: "pill",
+                                            #[watch]
+                                            set_visible: model.has_more,
+                                            connect_clicked[sender] => move |_| {
+                                                sender.input(HistoryPageInput::LoadMore);
+                                            }
+                                        }
                                     }
-                                }
 
-                            }
+                                }
+                            },
                         },
                     },
                  },
@@ -172,7 +525,7 @@ impl Component for HistoryPage {
         _root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let (history_worker, show_sidebar_toggle_btn) = init;
+        let (history_worker, notes, show_sidebar_toggle_btn) = init;
 
         history_worker.emit(HistoryWorkerInput::Subscribe(sender.clone()));
         history_worker.emit(HistoryWorkerInput::Fetch);
@@ -183,16 +536,26 @@ impl Component for HistoryPage {
             .launch(gtk::Box::new(gtk::Orientation::Vertical, 12))
             .forward(sender.input_sender(), move |message| match message {
                 HistoryBucketOutput::DeleteEntry(id) => HistoryPageInput::DeleteHistoryEntry(id),
+                HistoryBucketOutput::Selected(id) => HistoryPageInput::EntrySelected(id),
+                HistoryBucketOutput::Deselected(id) => HistoryPageInput::EntryDeselected(id),
                 HistoryBucketOutput::Dummy => HistoryPageInput::Dummy,
             });
 
         let model = HistoryPage {
             history_worker,
+            notes,
             show_sidebar_toggle_btn,
             navigation_view,
             sections,
             history_available: false,
             select_mode_on: false,
+            selected: HashSet::new(),
+            query: String::new(),
+            range: 0,
+            from: None,
+            to: None,
+            has_more: false,
+            loading_more: false,
         };
 
         let sections_widget = model.sections.widget();
@@ -206,19 +569,23 @@ impl Component for HistoryPage {
         widgets: &mut Self::Widgets,
         msg: Self::Input,
         sender: ComponentSender<Self>,
-        _root: &Self::Root,
+        root: &Self::Root,
     ) {
         match msg {
-            HistoryPageInput::UpdateHistory(articles) => {
-                self.history_available = true;
+            HistoryPageInput::UpdateHistory(articles, has_more) => {
+                self.history_available = !articles.is_empty();
+                self.has_more = has_more;
+                self.loading_more = false;
+
+                // entries that were deleted or filtered out cannot stay selected
+                let ids: HashSet<&String> = articles.iter().map(|article| &article.id).collect();
+                self.selected.retain(|id| ids.contains(id));
+                let selection = self.select_mode_on.then(|| self.selected.clone());
+                let noted = Arc::new(self.notes.noted_ids());
+
                 let mut guard = self.sections.guard();
                 guard.clear();
 
-                if articles.is_empty() {
-                    self.history_available = false;
-                    return;
-                }
-
                 let mut groups: BTreeMap<i64, (String, Vec<Arc<PersistentArticle>>)> =
                     BTreeMap::new();
 
@@ -248,25 +615,156 @@ impl Component for HistoryPage {
                 }
 
                 for (_key, (label, items)) in groups {
-                    guard.push_back((label, items));
+                    guard.push_back((label, items, noted.clone(), selection.clone()));
+                }
+            }
+            HistoryPageInput::SetQuery(query) => {
+                if self.query != query {
+                    self.query = query;
+                    self.apply_filter();
+                }
+            }
+            HistoryPageInput::SetRange(range) => {
+                self.range = range as usize;
+                self.apply_filter();
+            }
+            HistoryPageInput::SetFrom(date) => {
+                self.from = Some(date);
+                self.apply_filter();
+            }
+            HistoryPageInput::SetTo(date) => {
+                self.to = Some(date);
+                self.apply_filter();
+            }
+            HistoryPageInput::LoadMore => {
+                if self.has_more && !self.loading_more {
+                    self.loading_more = true;
+                    self.history_worker.emit(HistoryWorkerInput::LoadMore);
                 }
             }
+            HistoryPageInput::Export(format) => {
+                let dialog = gtk::FileDialog::builder()
+                    .title("Export History")
+                    .modal(true)
+                    .initial_name(format!("frostnews-history.{}", format.extension()))
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let worker = self.history_worker.clone();
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.save_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            worker.emit(HistoryWorkerInput::Export(path, format));
+                        }
+                    }
+                });
+            }
+            HistoryPageInput::Import => {
+                let filter = gtk::FileFilter::new();
+                filter.set_name(Some("FrostNews History (JSON)"));
+                filter.add_pattern("*.json");
+                let filters = gtk::gio::ListStore::new::<gtk::FileFilter>();
+                filters.append(&filter);
+
+                let dialog = gtk::FileDialog::builder()
+                    .title("Import History")
+                    .modal(true)
+                    .filters(&filters)
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let worker = self.history_worker.clone();
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.open_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            worker.emit(HistoryWorkerInput::Import(path));
+                        }
+                    }
+                });
+            }
+            HistoryPageInput::SavedChanged => {
+                let _ = sender.output(HistoryPagePageOutput::SavedChanged);
+            }
             HistoryPageInput::ShowSidebarToggleBtn(visible) => {
                 self.show_sidebar_toggle_btn = visible;
             }
             HistoryPageInput::DeleteHistoryEntry(id) => {
                 self.history_worker.emit(HistoryWorkerInput::Delete(id));
             }
+            HistoryPageInput::Trashed(batch, count) => {
+                let title = if count == 1 {
+                    "Deleted 1 entry".to_string()
+                } else {
+                    format!("Deleted {} entries", count)
+                };
+                let toast = adw::Toast::builder()
+                    .title(title)
+                    .button_label("Undo")
+                    .timeout(UNDO_SECS)
+                    .build();
+                let undo_sender = sender.clone();
+                toast.connect_button_clicked(move |_| {
+                    undo_sender.input(HistoryPageInput::Undo(batch));
+                });
+                widgets.toast_overlay.add_toast(toast);
+            }
+            HistoryPageInput::Undo(batch) => {
+                self.history_worker.emit(HistoryWorkerInput::Undo(batch));
+            }
+            HistoryPageInput::ShowResult(message) => {
+                widgets.toast_overlay.add_toast(adw::Toast::new(&message));
+            }
             HistoryPageInput::ClearHistory => {
                 self.history_worker.emit(HistoryWorkerInput::DeleterAll);
                 self.history_available = true;
             }
+            HistoryPageInput::ClearRecent(period) => {
+                let since = (Utc::now() - period).timestamp();
+                self.history_worker
+                    .emit(HistoryWorkerInput::ClearSince(since));
+            }
             HistoryPageInput::ActivateSelectionMode => {
                 self.select_mode_on = true;
-                self.sections.broadcast(HistoryBucketInput::ActivateSelectionMode);
+                self.selected.clear();
+                self.sections
+                    .broadcast(HistoryBucketInput::ActivateSelectionMode);
+            }
+            HistoryPageInput::DeactivateSelectMode => {
+                self.take_selection();
+            }
+            HistoryPageInput::EntrySelected(id) => {
+                self.selected.insert(id);
+            }
+            HistoryPageInput::EntryDeselected(id) => {
+                self.selected.remove(&id);
+            }
+            HistoryPageInput::DeleteSelected => {
+                let ids = self.take_selection();
+                self.history_worker
+                    .emit(HistoryWorkerInput::DeleteMany(ids));
+            }
+            HistoryPageInput::BookmarkSelected => {
+                let ids = self.take_selection();
+                self.history_worker
+                    .emit(HistoryWorkerInput::BookmarkMany(ids));
             }
-            HistoryPageInput::DeactivateSelectMode=>{
-                self.select_mode_on = false;
+            HistoryPageInput::ExportSelected(format) => {
+                let ids = self.take_selection();
+                let dialog = gtk::FileDialog::builder()
+                    .title("Export Selected History")
+                    .modal(true)
+                    .initial_name(format!("frostnews-history.{}", format.extension()))
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let worker = self.history_worker.clone();
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.save_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            worker.emit(HistoryWorkerInput::ExportMany(ids, path, format));
+                        }
+                    }
+                });
             }
             HistoryPageInput::Dummy => {}
         }
@@ -1,4 +1,9 @@
 pub mod business_page;
 pub mod category_page;
 pub mod news_page;
-pub mod history_page;
\ No newline at end of file
+pub mod notes_page;
+pub mod saved_page;
+pub mod history_page;
+pub mod search_page;
+pub mod settings_page;
+pub mod stats_page;
@@ -1,29 +1,58 @@
 use std::sync::Arc;
+use std::time::Instant;
 
 use adw::prelude::*;
 use relm4::{factory::FactoryVecDeque, prelude::*};
 
 use crate::{
     components::categorised_news::CategorisedNewsSection,
-    types::{cache::ImageCache, news_article::NewsArticle},
+    services::{
+        note_service::notes::Notes,
+        workers::history_worker::{HistoryWorker, HistoryWorkerInput},
+    },
+    types::{
+        article_note::ArticleNote, cache::ImageCache, news_article::NewsArticle,
+        persistent_articel::PersistentArticle,
+    },
 };
 
+const HIGHLIGHT_SPAN: &str = "<span background=\"#f8e45c\" foreground=\"#241f31\">";
+/// Longest stretch counted as reading, so an article left open overnight does not count
+const MAX_READ_SECS: i64 = 30 * 60;
+
 #[derive(Debug)]
 pub struct NewsPage {
     show_sidebar_toggle_btn: bool,
     article: Arc<dyn NewsArticle>,
     cache: ImageCache,
     related_section: FactoryVecDeque<CategorisedNewsSection>,
+    bookmarked: bool,
+    notes: Notes,
+    note: ArticleNote,
+    note_buffer: gtk::TextBuffer,
+    /// The note text was edited since it was last saved
+    note_dirty: bool,
+    history_worker: Arc<Controller<HistoryWorker>>,
+    /// When the page was last shown, `None` while hidden
+    shown_at: Option<Instant>,
 }
 
 #[derive(Debug, Clone)]
 pub enum NewsPageInput {
     ShowSidebarToggleBtn(bool),
+    Bookmark,
+    HighlightSelection,
+    RemoveHighlight(usize),
+    NoteEdited,
+    SaveNote,
+    Shown,
+    Hidden,
 }
 
 #[derive(Debug, Clone)]
 pub enum NewsPageOutput {
     ToggleSidebar,
+    Bookmark(Arc<dyn NewsArticle>),
 }
 
 #[relm4::component(pub)]
@@ -32,6 +61,8 @@ impl Component for NewsPage {
         Arc<dyn NewsArticle>,
         Vec<Arc<dyn NewsArticle>>,
         ImageCache,
+        Notes,
+        Arc<Controller<HistoryWorker>>,
         bool,
     );
     type Input = NewsPageInput;
@@ -41,6 +72,12 @@ impl Component for NewsPage {
     view! {
         adw::NavigationPage {
             set_title: "News Article",
+            connect_map[sender] => move |_| {
+                sender.input(NewsPageInput::Shown);
+            },
+            connect_unmap[sender] => move |_| {
+                sender.input(NewsPageInput::Hidden);
+            },
             #[wrap(Some)]
             set_child = &adw::ToolbarView {
                 add_top_bar = &adw::HeaderBar {
@@ -107,6 +144,7 @@ impl Component for NewsPage {
                                     #[name = "content_label"]
                                     gtk::Label {
                                         set_label: model.article.content().unwrap_or_default().as_str(),
+                                        set_selectable: true,
                                         set_wrap: true,
                                         set_xalign: 0.0,
                                         set_justify: gtk::Justification::Fill,
@@ -115,15 +153,100 @@ impl Component for NewsPage {
                                         add_css_class: "document",
                                     },
 
-                                    gtk::Button {
-                                        set_label: "Open Link",
-                                        set_halign: gtk::Align::Start,
-                                        add_css_class: "suggested-action",
-                                        add_css_class: "pill",
+                                    gtk::Box {
+                                        set_orientation: gtk::Orientation::Horizontal,
+                                        set_spacing: 12,
+
+                                        gtk::Button {
+                                            set_label: "Open Link",
+                                            set_halign: gtk::Align::Start,
+                                            add_css_class: "suggested-action",
+                                            add_css_class: "pill",
+                                        },
+
+                                        gtk::Button {
+                                            add_css_class: "pill",
+                                            #[watch]
+                                            set_sensitive: !model.bookmarked,
+                                            connect_clicked[sender] => move |_| {
+                                                sender.input(NewsPageInput::Bookmark);
+                                            },
+
+                                            adw::ButtonContent {
+                                                set_icon_name: "user-bookmarks-symbolic",
+                                                #[watch]
+                                                set_label: if model.bookmarked { "Saved" } else { "Save" },
+                                            },
+                                        },
                                     }
                                 },
                             },
 
+                            // --- NOTES ---
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 12,
+                                set_margin_horizontal: 6,
+
+                                gtk::Label {
+                                    set_label: "NOTES",
+                                    set_xalign: 0.0,
+                                    add_css_class: "section-label",
+                                },
+
+                                #[name = "highlights_list"]
+                                gtk::ListBox {
+                                    set_selection_mode: gtk::SelectionMode::None,
+                                    add_css_class: "boxed-list",
+                                    #[watch]
+                                    set_visible: !model.note.highlights.is_empty(),
+                                },
+
+                                gtk::Frame {
+                                    gtk::TextView {
+                                        set_buffer: Some(&model.note_buffer),
+                                        set_wrap_mode: gtk::WrapMode::WordChar,
+                                        set_monospace: true,
+                                        set_height_request: 140,
+                                        set_top_margin: 12,
+                                        set_bottom_margin: 12,
+                                        set_left_margin: 12,
+                                        set_right_margin: 12,
+                                    }
+                                },
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Horizontal,
+                                    set_spacing: 12,
+
+                                    gtk::Button {
+                                        set_label: "Highlight Selection",
+                                        set_tooltip: "Select a passage in the article text first",
+                                        set_visible: model.article.content().is_some(),
+                                        add_css_class: "pill",
+                                        connect_clicked[sender] => move |_| {
+                                            sender.input(NewsPageInput::HighlightSelection);
+                                        }
+                                    },
+
+                                    gtk::Button {
+                                        set_label: "Save Note",
+                                        add_css_class: "pill",
+                                        #[watch]
+                                        set_sensitive: model.note_dirty,
+                                        connect_clicked[sender] => move |_| {
+                                            sender.input(NewsPageInput::SaveNote);
+                                        }
+                                    },
+
+                                    gtk::Label {
+                                        set_label: "Notes support Markdown",
+                                        add_css_class: "caption",
+                                        add_css_class: "dim-label",
+                                    },
+                                }
+                            },
+
                         }
                     }
                 }
@@ -136,7 +259,8 @@ impl Component for NewsPage {
         _root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let (article, related_articles, cache, show_sidebar_toggle_btn) = init;
+        let (article, related_articles, cache, notes, history_worker, show_sidebar_toggle_btn) =
+            init;
 
         let mut related_section = FactoryVecDeque::builder()
             .launch(gtk::Box::new(gtk::Orientation::Vertical, 12))
@@ -148,14 +272,35 @@ impl Component for NewsPage {
                 "Related".to_string(),
                 related_articles.clone(),
                 cache.clone(),
+                Arc::new(notes.noted_ids()),
             ));
         }
 
+        let article_id = PersistentArticle::id_for(&article.url());
+        let note = notes
+            .get(&article_id)
+            .unwrap_or_else(|| ArticleNote::new(article_id, article.title(), article.url()));
+
+        // filled before listening for edits so loading the note does not count as one
+        let note_buffer = gtk::TextBuffer::new(None);
+        note_buffer.set_text(&note.body);
+        let edit_sender = sender.clone();
+        note_buffer.connect_changed(move |_| {
+            edit_sender.input(NewsPageInput::NoteEdited);
+        });
+
         let model = Self {
             show_sidebar_toggle_btn,
             article,
             cache,
             related_section,
+            bookmarked: false,
+            notes,
+            note,
+            note_buffer,
+            note_dirty: false,
+            history_worker,
+            shown_at: None,
         };
 
         let widgets = view_output!();
@@ -172,14 +317,152 @@ impl Component for NewsPage {
             loader.load_picture_image(&widgets.thumbnail, Some(url.clone()), model.cache.clone());
         }
 
+        model.render_highlights(&widgets, &sender);
+
         ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>, _root: &Self::Root) {
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
         match msg {
             NewsPageInput::ShowSidebarToggleBtn(visible) => {
                 self.show_sidebar_toggle_btn = visible;
             }
+            NewsPageInput::Bookmark => {
+                self.bookmarked = true;
+                let _ = sender.output(NewsPageOutput::Bookmark(self.article.clone()));
+            }
+            NewsPageInput::HighlightSelection => {
+                if let Some((start, end)) = widgets.content_label.selection_bounds() {
+                    let passage: String = widgets
+                        .content_label
+                        .text()
+                        .chars()
+                        .skip(start.min(end) as usize)
+                        .take(start.abs_diff(end) as usize)
+                        .collect();
+                    let passage = passage.trim().to_string();
+
+                    if !passage.is_empty() && !self.note.highlights.contains(&passage) {
+                        self.note.highlights.push(passage);
+                        self.save_note();
+                        self.render_highlights(widgets, &sender);
+                    }
+                }
+            }
+            NewsPageInput::RemoveHighlight(index) => {
+                if index < self.note.highlights.len() {
+                    self.note.highlights.remove(index);
+                    self.save_note();
+                    self.render_highlights(widgets, &sender);
+                }
+            }
+            NewsPageInput::NoteEdited => self.note_dirty = true,
+            NewsPageInput::SaveNote => self.save_note(),
+            NewsPageInput::Shown => self.shown_at = Some(Instant::now()),
+            NewsPageInput::Hidden => {
+                if let Some(shown_at) = self.shown_at.take() {
+                    let secs = (shown_at.elapsed().as_secs() as i64).min(MAX_READ_SECS);
+                    if secs > 0 {
+                        self.history_worker.emit(HistoryWorkerInput::AddReadTime(
+                            self.note.article_id.clone(),
+                            secs,
+                        ));
+                    }
+                }
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
+
+impl NewsPage {
+    /// Saves the note text together with the highlights
+    fn save_note(&mut self) {
+        let (start, end) = self.note_buffer.bounds();
+        self.note.body = self.note_buffer.text(&start, &end, false).to_string();
+        self.notes.save(&self.note);
+        self.note_dirty = false;
+    }
+
+    fn render_highlights(&self, widgets: &NewsPageWidgets, sender: &ComponentSender<Self>) {
+        if let Some(content) = self.article.content() {
+            widgets
+                .content_label
+                .set_markup(&Self::highlighted_markup(&content, &self.note.highlights));
+        }
+
+        let list = &widgets.highlights_list;
+        while let Some(child) = list.first_child() {
+            list.remove(&child);
+        }
+
+        for (index, highlight) in self.note.highlights.iter().enumerate() {
+            let row_box = gtk::Box::builder()
+                .spacing(12)
+                .margin_top(8)
+                .margin_bottom(8)
+                .margin_start(12)
+                .margin_end(8)
+                .build();
+
+            let label = gtk::Label::builder()
+                .label(format!("“{}”", highlight))
+                .wrap(true)
+                .xalign(0.0)
+                .hexpand(true)
+                .css_classes(vec!["document"])
+                .build();
+
+            let remove = gtk::Button::builder()
+                .icon_name("edit-delete-symbolic")
+                .tooltip_text("Remove Highlight")
+                .valign(gtk::Align::Center)
+                .css_classes(vec!["flat", "circular"])
+                .build();
+            let sender = sender.clone();
+            remove.connect_clicked(move |_| {
+                sender.input(NewsPageInput::RemoveHighlight(index));
+            });
+
+            row_box.append(&label);
+            row_box.append(&remove);
+            list.append(&row_box);
+        }
+    }
+
+    /// Article text as Pango markup with every highlighted passage marked
+    fn highlighted_markup(content: &str, highlights: &[String]) -> String {
+        let mut ranges: Vec<(usize, usize)> = highlights
+            .iter()
+            .flat_map(|highlight| {
+                content
+                    .match_indices(highlight.as_str())
+                    .map(|(start, found)| (start, start + found.len()))
+            })
+            .collect();
+        ranges.sort();
+
+        let mut markup = String::new();
+        let mut cursor = 0;
+        for (start, end) in ranges {
+            if end <= cursor {
+                continue;
+            }
+            let start = start.max(cursor);
+            markup.push_str(&gtk::glib::markup_escape_text(&content[cursor..start]));
+            markup.push_str(HIGHLIGHT_SPAN);
+            markup.push_str(&gtk::glib::markup_escape_text(&content[start..end]));
+            markup.push_str("</span>");
+            cursor = end;
         }
+        markup.push_str(&gtk::glib::markup_escape_text(&content[cursor..]));
+        markup
     }
 }
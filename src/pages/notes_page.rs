@@ -0,0 +1,251 @@
+use adw::prelude::*;
+use relm4::{Component, ComponentParts, ComponentSender, prelude::*};
+
+use crate::services::note_service::notes::Notes;
+use crate::types::article_note::ArticleNote;
+
+#[derive(Debug)]
+pub struct NotesPage {
+    notes: Notes,
+    show_sidebar_toggle_btn: bool,
+    query: String,
+    shown: Vec<ArticleNote>,
+}
+
+#[derive(Debug)]
+pub enum NotesPageInput {
+    Refresh,
+    SetQuery(String),
+    Export,
+    ShowSidebarToggleBtn(bool),
+}
+
+#[derive(Debug)]
+pub enum NotesPageOutput {
+    ToggleSidebar,
+}
+
+impl NotesPage {
+    fn render(&mut self, widgets: &NotesPageWidgets) {
+        self.shown = self.notes.search(&self.query);
+
+        let list = &widgets.notes_list;
+        while let Some(child) = list.first_child() {
+            list.remove(&child);
+        }
+
+        for note in &self.shown {
+            list.append(&Self::note_row(note));
+        }
+    }
+
+    fn note_row(note: &ArticleNote) -> gtk::ListBoxRow {
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(8)
+            .margin_top(12)
+            .margin_bottom(12)
+            .margin_start(12)
+            .margin_end(12)
+            .build();
+
+        let title = gtk::LinkButton::builder()
+            .uri(note.url.as_str())
+            .label(note.title.as_str())
+            .halign(gtk::Align::Start)
+            .build();
+        if let Some(label) = title.child().and_downcast::<gtk::Label>() {
+            label.set_wrap(true);
+            label.set_xalign(0.0);
+            label.add_css_class("heading");
+        }
+        content.append(&title);
+
+        for highlight in &note.highlights {
+            let quote = gtk::Label::builder()
+                .label(format!("“{}”", highlight))
+                .wrap(true)
+                .xalign(0.0)
+                .selectable(true)
+                .css_classes(vec!["document", "dim-label"])
+                .build();
+            content.append(&quote);
+        }
+
+        if !note.body.trim().is_empty() {
+            let body = gtk::Label::builder()
+                .label(note.body.trim())
+                .wrap(true)
+                .xalign(0.0)
+                .selectable(true)
+                .css_classes(vec!["document"])
+                .build();
+            content.append(&body);
+        }
+
+        gtk::ListBoxRow::builder()
+            .child(&content)
+            .activatable(false)
+            .build()
+    }
+
+    fn empty_title(&self) -> &'static str {
+        if self.query.is_empty() {
+            "No Notes"
+        } else {
+            "No Matches"
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl Component for NotesPage {
+    type Init = (Notes, bool);
+    type Input = NotesPageInput;
+    type Output = NotesPageOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::NavigationPage {
+            set_title: "Notes",
+
+            #[wrap(Some)]
+            set_child = &adw::ToolbarView {
+                add_top_bar = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+
+                    adw::HeaderBar {
+                        set_show_title: false,
+                        pack_start = &gtk::Button {
+                            set_icon_name: "sidebar-show-symbolic",
+                            #[watch]
+                            set_visible: model.show_sidebar_toggle_btn,
+                            connect_clicked[sender] => move |_| {
+                                let _ = sender.output(NotesPageOutput::ToggleSidebar);
+                            },
+                        },
+                        pack_end = &gtk::Button {
+                            set_icon_name: "document-save-symbolic",
+                            set_tooltip: "Export as Markdown",
+                            #[watch]
+                            set_sensitive: !model.shown.is_empty(),
+                            connect_clicked[sender] => move |_| {
+                                sender.input(NotesPageInput::Export);
+                            },
+                        },
+                    },
+
+                    adw::Clamp {
+                        set_margin_bottom: 16,
+
+                        gtk::SearchEntry {
+                            set_placeholder_text: Some("Search notes and highlights"),
+                            set_margin_horizontal: 40,
+                            connect_search_changed[sender] => move |entry| {
+                                sender.input(NotesPageInput::SetQuery(entry.text().to_string()));
+                            }
+                        },
+                    },
+                },
+
+                #[wrap(Some)]
+                set_content = &gtk::ScrolledWindow {
+                    set_hscrollbar_policy: gtk::PolicyType::Never,
+
+                    adw::Clamp {
+                        set_margin_top: 20,
+                        set_margin_bottom: 40,
+
+                        gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 20,
+
+                            gtk::Label {
+                                set_label: "Notes",
+                                set_xalign: 0.0,
+                                add_css_class: "frost-brand-title",
+                            },
+
+                            adw::StatusPage {
+                                set_icon_name: Some("document-edit-symbolic"),
+                                #[watch]
+                                set_title: model.empty_title(),
+                                set_description: Some("Notes and highlights you make on articles show up here"),
+                                #[watch]
+                                set_visible: model.shown.is_empty(),
+                            },
+
+                            #[name = "notes_list"]
+                            gtk::ListBox {
+                                set_selection_mode: gtk::SelectionMode::None,
+                                add_css_class: "boxed-list",
+                                #[watch]
+                                set_visible: !model.shown.is_empty(),
+                            }
+                        }
+                    }
+                },
+            }
+        },
+    }
+
+    fn init(
+        init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let (notes, show_sidebar_toggle_btn) = init;
+
+        let mut model = NotesPage {
+            notes,
+            show_sidebar_toggle_btn,
+            query: String::new(),
+            shown: Vec::new(),
+        };
+
+        let widgets = view_output!();
+        model.render(&widgets);
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            NotesPageInput::Refresh => self.render(widgets),
+            NotesPageInput::SetQuery(query) => {
+                self.query = query;
+                self.render(widgets);
+            }
+            NotesPageInput::Export => {
+                let dialog = gtk::FileDialog::builder()
+                    .title("Export Notes")
+                    .modal(true)
+                    .initial_name("frostnews-notes.md")
+                    .build();
+                let window = root.root().and_downcast::<gtk::Window>();
+                let markdown = ArticleNote::to_markdown(&self.shown);
+
+                relm4::spawn_local(async move {
+                    if let Ok(file) = dialog.save_future(window.as_ref()).await {
+                        if let Some(path) = file.path() {
+                            if let Err(e) = std::fs::write(&path, markdown) {
+                                eprintln!("Failed to export notes: {}", e);
+                            }
+                        }
+                    }
+                });
+            }
+            NotesPageInput::ShowSidebarToggleBtn(visible) => {
+                self.show_sidebar_toggle_btn = visible;
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
@@ -0,0 +1,605 @@
+use adw::prelude::*;
+use chrono::{DateTime, Local, Utc};
+use relm4::{Component, ComponentParts, ComponentSender, Controller, prelude::*};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::components::saved_bucket::SavedBucket;
+use crate::components::saved_row::SavedRowOutput;
+use crate::pages::news_page::{NewsPage, NewsPageInput, NewsPageOutput};
+use crate::services::workers::bookmark_worker::{BookmarkWorker, BookmarkWorkerInput};
+use crate::services::note_service::notes::Notes;
+use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput};
+use crate::types::bookmark::Bookmark;
+use crate::types::cache::ImageCache;
+use crate::types::news_article::NewsArticle;
+use crate::types::persistent_articel::PersistentArticle;
+use crate::types::visit_origin::VisitOrigin;
+
+const SORT_ORDERS: [&str; 3] = ["Recently Saved", "Recently Published", "Title"];
+
+#[derive(Debug)]
+pub struct SavedPage {
+    bookmark_worker: Arc<Controller<BookmarkWorker>>,
+    history_worker: Arc<Controller<HistoryWorker>>,
+    cache: ImageCache,
+    notes: Notes,
+    show_sidebar_toggle_btn: bool,
+    navigation_view: adw::NavigationView,
+    news_page_controller: Option<Controller<NewsPage>>,
+    sections: FactoryVecDeque<SavedBucket>,
+    bookmarks: Vec<Bookmark>,
+    query: String,
+    sort: usize,
+    shown: usize,
+    folders: Vec<String>,
+    tags: Vec<String>,
+    folder_filter: Option<String>,
+    tag_filter: Option<String>,
+    // backing models of the folder and tag dropdowns, the first item means "any"
+    folder_list: gtk::StringList,
+    tag_list: gtk::StringList,
+}
+
+#[derive(Debug)]
+pub enum SavedPageInput {
+    UpdateBookmarks(Vec<Bookmark>),
+    SetQuery(String),
+    SetSort(u32),
+    SetFolderFilter(Option<String>),
+    SetTagFilter(Option<String>),
+    Open(Bookmark),
+    Edit(Bookmark),
+    Organise(String, Option<String>, Vec<String>),
+    ManageTags,
+    RenameTag(String, String),
+    Remove(String),
+    ShowSidebarToggleBtn(bool),
+}
+
+#[derive(Debug)]
+pub enum SavedPageOutput {
+    ToggleSidebar,
+    Bookmark(Arc<dyn NewsArticle>),
+}
+
+impl SavedPage {
+    /// Groups the bookmarks matching the search by the day they were saved, newest day first
+    fn render(&mut self) {
+        let query = self.query.to_lowercase();
+        let mut matching: Vec<Bookmark> = self
+            .bookmarks
+            .iter()
+            .filter(|bookmark| {
+                self.folder_filter
+                    .as_ref()
+                    .is_none_or(|folder| bookmark.folder.as_ref() == Some(folder))
+            })
+            .filter(|bookmark| {
+                self.tag_filter
+                    .as_ref()
+                    .is_none_or(|tag| bookmark.tags.contains(tag))
+            })
+            .filter(|bookmark| {
+                let article = &bookmark.article;
+                query.is_empty()
+                    || article.title.to_lowercase().contains(&query)
+                    || article.url.to_lowercase().contains(&query)
+                    || article
+                        .description
+                        .as_deref()
+                        .is_some_and(|d| d.to_lowercase().contains(&query))
+            })
+            .cloned()
+            .collect();
+
+        match self.sort {
+            1 => matching.sort_by(|a, b| b.article.published_at.cmp(&a.article.published_at)),
+            2 => matching.sort_by_key(|bookmark| bookmark.article.title.to_lowercase()),
+            _ => matching.sort_by(|a, b| b.saved_at.cmp(&a.saved_at)),
+        }
+        self.shown = matching.len();
+
+        let mut groups: BTreeMap<i64, (String, Vec<Bookmark>)> = BTreeMap::new();
+        let now = Local::now();
+        let today = now.date_naive();
+
+        for bookmark in matching {
+            let dt = DateTime::<Utc>::from_timestamp(bookmark.saved_at, 0)
+                .map(|u| u.with_timezone(&Local))
+                .unwrap_or(now);
+            let days_diff = (today - dt.date_naive()).num_days();
+
+            let (label, sort_key) = match days_diff {
+                0 => ("Today".to_string(), 0),
+                1 => ("Yesterday".to_string(), 1),
+                2..=6 => (dt.format("%A").to_string(), days_diff),
+                _ => (dt.format("%A, %B %d").to_string(), days_diff),
+            };
+
+            groups
+                .entry(sort_key)
+                .or_insert((label, Vec::new()))
+                .1
+                .push(bookmark);
+        }
+
+        let mut guard = self.sections.guard();
+        guard.clear();
+        for (_key, (label, items)) in groups {
+            guard.push_back((label, items));
+        }
+    }
+
+    /// Rebuilds the folder and tag dropdowns, dropping filters whose folder or tag is gone
+    fn refresh_filters(&mut self, widgets: &SavedPageWidgets) {
+        let mut folders: Vec<String> = self
+            .bookmarks
+            .iter()
+            .filter_map(|bookmark| bookmark.folder.clone())
+            .collect();
+        let mut tags: Vec<String> = self
+            .bookmarks
+            .iter()
+            .flat_map(|bookmark| bookmark.tags.clone())
+            .collect();
+        for list in [&mut folders, &mut tags] {
+            list.sort_by_key(|name| name.to_lowercase());
+            list.dedup();
+        }
+
+        if self.folder_filter.as_ref().is_some_and(|f| !folders.contains(f)) {
+            self.folder_filter = None;
+        }
+        if self.tag_filter.as_ref().is_some_and(|t| !tags.contains(t)) {
+            self.tag_filter = None;
+        }
+
+        Self::fill_list(&self.folder_list, "All Folders", &folders);
+        Self::fill_list(&self.tag_list, "All Tags", &tags);
+        widgets
+            .folder_dropdown
+            .set_selected(Self::position(&folders, &self.folder_filter));
+        widgets
+            .tag_dropdown
+            .set_selected(Self::position(&tags, &self.tag_filter));
+
+        self.folders = folders;
+        self.tags = tags;
+    }
+
+    fn fill_list(list: &gtk::StringList, any: &str, names: &[String]) {
+        let mut items = vec![any];
+        items.extend(names.iter().map(String::as_str));
+        list.splice(0, list.n_items(), &items);
+    }
+
+    fn position(names: &[String], selected: &Option<String>) -> u32 {
+        selected
+            .as_ref()
+            .and_then(|name| names.iter().position(|n| n == name))
+            .map_or(0, |index| index as u32 + 1)
+    }
+
+    /// Name picked in a folder or tag dropdown, `None` for the leading "any" item
+    fn selected_name(dropdown: &gtk::DropDown) -> Option<String> {
+        if dropdown.selected() == 0 {
+            return None;
+        }
+        dropdown
+            .selected_item()
+            .and_downcast::<gtk::StringObject>()
+            .map(|item| item.string().to_string())
+    }
+
+    fn show_organise_dialog(&self, root: &adw::NavigationPage, bookmark: Bookmark, sender: &ComponentSender<Self>) {
+        let dialog = adw::AlertDialog::new(
+            Some("Folder and Tags"),
+            Some(&bookmark.article.title),
+        );
+
+        let folder_row = adw::EntryRow::builder()
+            .title("Folder")
+            .text(bookmark.folder.as_deref().unwrap_or(""))
+            .build();
+        let tags_row = adw::EntryRow::builder()
+            .title("Tags (comma separated)")
+            .text(bookmark.tags.join(", "))
+            .build();
+
+        let fields = gtk::ListBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .css_classes(vec!["boxed-list"])
+            .build();
+        fields.append(&folder_row);
+        fields.append(&tags_row);
+
+        if !self.folders.is_empty() {
+            let hint = gtk::Label::builder()
+                .label(format!("Folders: {}", self.folders.join(", ")))
+                .wrap(true)
+                .xalign(0.0)
+                .margin_top(8)
+                .css_classes(vec!["caption", "dim-label"])
+                .build();
+            let content = gtk::Box::new(gtk::Orientation::Vertical, 0);
+            content.append(&fields);
+            content.append(&hint);
+            dialog.set_extra_child(Some(&content));
+        } else {
+            dialog.set_extra_child(Some(&fields));
+        }
+
+        dialog.add_responses(&[("cancel", "Cancel"), ("save", "Save")]);
+        dialog.set_response_appearance("save", adw::ResponseAppearance::Suggested);
+        dialog.set_default_response(Some("save"));
+        dialog.set_close_response("cancel");
+
+        let sender = sender.clone();
+        let id = bookmark.article.id.clone();
+        dialog.connect_response(None, move |_, response| {
+            if response != "save" {
+                return;
+            }
+
+            let folder = folder_row.text().trim().to_string();
+            sender.input(SavedPageInput::Organise(
+                id.clone(),
+                (!folder.is_empty()).then_some(folder),
+                Bookmark::parse_tags(&tags_row.text()),
+            ));
+        });
+
+        dialog.present(Some(root));
+    }
+
+    fn show_tags_dialog(&self, root: &adw::NavigationPage, sender: &ComponentSender<Self>) {
+        let dialog = adw::AlertDialog::new(
+            Some("Manage Tags"),
+            Some("Rename a tag to one that already exists to merge them, clear it to remove it from every article"),
+        );
+
+        let fields = gtk::ListBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .css_classes(vec!["boxed-list"])
+            .build();
+        let rows: Vec<(String, adw::EntryRow)> = self
+            .tags
+            .iter()
+            .map(|tag| {
+                let row = adw::EntryRow::builder().title(tag).text(tag).build();
+                fields.append(&row);
+                (tag.clone(), row)
+            })
+            .collect();
+
+        let scroller = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .propagate_natural_height(true)
+            .max_content_height(420)
+            .child(&fields)
+            .build();
+
+        dialog.set_extra_child(Some(&scroller));
+        dialog.add_responses(&[("cancel", "Cancel"), ("apply", "Apply")]);
+        dialog.set_response_appearance("apply", adw::ResponseAppearance::Suggested);
+        dialog.set_default_response(Some("apply"));
+        dialog.set_close_response("cancel");
+
+        let sender = sender.clone();
+        dialog.connect_response(None, move |_, response| {
+            if response != "apply" {
+                return;
+            }
+
+            for (tag, row) in &rows {
+                let renamed = row.text().trim().to_string();
+                if renamed != *tag {
+                    sender.input(SavedPageInput::RenameTag(tag.clone(), renamed));
+                }
+            }
+        });
+
+        dialog.present(Some(root));
+    }
+
+    fn empty_title(&self) -> &'static str {
+        if self.bookmarks.is_empty() {
+            "No Saved Articles"
+        } else {
+            "No Matches"
+        }
+    }
+
+    fn empty_description(&self) -> &'static str {
+        if self.bookmarks.is_empty() {
+            "Press the bookmark button on any article to keep it here"
+        } else {
+            "No saved article matches the search"
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl Component for SavedPage {
+    type Init = (
+        Arc<Controller<BookmarkWorker>>,
+        Arc<Controller<HistoryWorker>>,
+        ImageCache,
+        Notes,
+        bool,
+    );
+    type Input = SavedPageInput;
+    type Output = SavedPageOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::NavigationPage {
+            set_title: "Saved",
+
+            #[wrap(Some)]
+            set_child = &model.navigation_view.clone() {
+                push = &adw::NavigationPage {
+                    set_tag: Some("saved"),
+
+                    #[wrap(Some)]
+                    set_child = &adw::ToolbarView {
+                        add_top_bar = &gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+
+                            adw::HeaderBar {
+                                set_show_title: false,
+                                pack_start = &gtk::Button {
+                                    set_icon_name: "sidebar-show-symbolic",
+                                    #[watch]
+                                    set_visible: model.show_sidebar_toggle_btn,
+                                    connect_clicked[sender] => move |_| {
+                                        let _ = sender.output(SavedPageOutput::ToggleSidebar);
+                                    },
+                                },
+                            },
+
+                            adw::Clamp {
+                                set_margin_bottom: 16,
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Horizontal,
+                                    set_margin_horizontal: 40,
+                                    set_spacing: 10,
+
+                                    gtk::SearchEntry {
+                                        set_placeholder_text: Some("Search saved articles"),
+                                        set_hexpand: true,
+                                        connect_search_changed[sender] => move |entry| {
+                                            sender.input(SavedPageInput::SetQuery(entry.text().to_string()));
+                                        }
+                                    },
+
+                                    #[name = "folder_dropdown"]
+                                    gtk::DropDown {
+                                        set_model: Some(&model.folder_list),
+                                        set_tooltip_text: Some("Folder"),
+                                        connect_selected_notify[sender] => move |dropdown| {
+                                            sender.input(SavedPageInput::SetFolderFilter(SavedPage::selected_name(dropdown)));
+                                        }
+                                    },
+
+                                    #[name = "tag_dropdown"]
+                                    gtk::DropDown {
+                                        set_model: Some(&model.tag_list),
+                                        set_tooltip_text: Some("Tag"),
+                                        connect_selected_notify[sender] => move |dropdown| {
+                                            sender.input(SavedPageInput::SetTagFilter(SavedPage::selected_name(dropdown)));
+                                        }
+                                    },
+
+                                    gtk::DropDown::from_strings(&SORT_ORDERS) {
+                                        set_tooltip_text: Some("Sort Order"),
+                                        connect_selected_notify[sender] => move |dropdown| {
+                                            sender.input(SavedPageInput::SetSort(dropdown.selected()));
+                                        }
+                                    },
+
+                                    gtk::Button {
+                                        set_label: "Manage Tags",
+                                        add_css_class: "flat",
+                                        #[watch]
+                                        set_sensitive: !model.tags.is_empty(),
+                                        connect_clicked[sender] => move |_| {
+                                            sender.input(SavedPageInput::ManageTags);
+                                        }
+                                    },
+                                },
+                            },
+                        },
+
+                        #[wrap(Some)]
+                        set_content = &gtk::ScrolledWindow {
+                            set_hscrollbar_policy: gtk::PolicyType::Never,
+
+                            adw::Clamp {
+                                set_margin_top: 20,
+                                set_margin_bottom: 40,
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+                                    set_spacing: 20,
+
+                                    gtk::Label {
+                                        set_label: "Saved",
+                                        set_xalign: 0.0,
+                                        add_css_class: "frost-brand-title",
+                                    },
+
+                                    adw::StatusPage {
+                                        set_icon_name: Some("user-bookmarks-symbolic"),
+                                        #[watch]
+                                        set_title: model.empty_title(),
+                                        #[watch]
+                                        set_description: Some(model.empty_description()),
+                                        #[watch]
+                                        set_visible: model.shown == 0,
+                                    },
+
+                                    #[local_ref]
+                                    sections_widget -> gtk::Box {
+                                        set_orientation: gtk::Orientation::Vertical,
+                                        set_spacing: 24,
+                                        #[watch]
+                                        set_visible: model.shown > 0,
+                                    }
+                                }
+                            }
+                        },
+                    },
+                },
+            }
+        },
+    }
+
+    fn init(
+        init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let (bookmark_worker, history_worker, cache, notes, show_sidebar_toggle_btn) = init;
+
+        bookmark_worker.emit(BookmarkWorkerInput::Subscribe(sender.clone()));
+        bookmark_worker.emit(BookmarkWorkerInput::Fetch);
+
+        let sections = FactoryVecDeque::builder()
+            .launch(gtk::Box::new(gtk::Orientation::Vertical, 24))
+            .forward(sender.input_sender(), move |output| match output {
+                SavedRowOutput::Open(bookmark) => SavedPageInput::Open(bookmark),
+                SavedRowOutput::Edit(bookmark) => SavedPageInput::Edit(bookmark),
+                SavedRowOutput::Remove(id) => SavedPageInput::Remove(id),
+            });
+
+        let model = SavedPage {
+            bookmark_worker,
+            history_worker,
+            cache,
+            notes,
+            show_sidebar_toggle_btn,
+            navigation_view: adw::NavigationView::builder().build(),
+            news_page_controller: None,
+            sections,
+            bookmarks: Vec::new(),
+            query: String::new(),
+            sort: 0,
+            shown: 0,
+            folders: Vec::new(),
+            tags: Vec::new(),
+            folder_filter: None,
+            tag_filter: None,
+            folder_list: gtk::StringList::new(&["All Folders"]),
+            tag_list: gtk::StringList::new(&["All Tags"]),
+        };
+
+        let sections_widget = model.sections.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            SavedPageInput::UpdateBookmarks(bookmarks) => {
+                self.bookmarks = bookmarks;
+                self.refresh_filters(widgets);
+                self.render();
+            }
+            SavedPageInput::SetFolderFilter(folder) => {
+                if self.folder_filter != folder {
+                    self.folder_filter = folder;
+                    self.render();
+                }
+            }
+            SavedPageInput::SetTagFilter(tag) => {
+                if self.tag_filter != tag {
+                    self.tag_filter = tag;
+                    self.render();
+                }
+            }
+            SavedPageInput::Edit(bookmark) => {
+                self.show_organise_dialog(root, bookmark, &sender);
+            }
+            SavedPageInput::Organise(id, folder, tags) => {
+                self.bookmark_worker
+                    .emit(BookmarkWorkerInput::Organise(id, folder, tags));
+            }
+            SavedPageInput::ManageTags => {
+                self.show_tags_dialog(root, &sender);
+            }
+            SavedPageInput::RenameTag(from, to) => {
+                if self.tag_filter.as_ref() == Some(&from) {
+                    self.tag_filter = (!to.is_empty()).then(|| to.clone());
+                }
+                self.bookmark_worker
+                    .emit(BookmarkWorkerInput::RenameTag(from, to));
+            }
+            SavedPageInput::SetQuery(query) => {
+                self.query = query.trim().to_string();
+                self.render();
+            }
+            SavedPageInput::SetSort(index) => {
+                self.sort = (index as usize).min(SORT_ORDERS.len() - 1);
+                self.render();
+            }
+            SavedPageInput::Remove(id) => {
+                self.bookmark_worker.emit(BookmarkWorkerInput::Delete(id));
+            }
+            SavedPageInput::ShowSidebarToggleBtn(visible) => {
+                self.show_sidebar_toggle_btn = visible;
+                if let Some(controller) = &self.news_page_controller {
+                    controller.emit(NewsPageInput::ShowSidebarToggleBtn(visible));
+                }
+            }
+            SavedPageInput::Open(bookmark) => {
+                let article = bookmark.article;
+
+                let persistent_article = PersistentArticle::auto_create(
+                    article.title.clone(),
+                    article.url.clone(),
+                    article.description.clone(),
+                    article.content.clone(),
+                    article.published_at.clone(),
+                    article.image_url.clone(),
+                );
+                // the publisher and section stay as recorded on earlier visits
+                self.history_worker.emit(HistoryWorkerInput::Save(
+                    persistent_article,
+                    VisitOrigin::default(),
+                ));
+
+                self.news_page_controller = Some(
+                    NewsPage::builder()
+                        .launch((
+                            article as Arc<dyn NewsArticle>,
+                            Vec::new(),
+                            self.cache.clone(),
+                            self.notes.clone(),
+                            self.history_worker.clone(),
+                            self.show_sidebar_toggle_btn,
+                        ))
+                        .forward(sender.output_sender(), move |action| match action {
+                            NewsPageOutput::ToggleSidebar => SavedPageOutput::ToggleSidebar,
+                            NewsPageOutput::Bookmark(article) => SavedPageOutput::Bookmark(article),
+                        }),
+                );
+
+                if let Some(controller) = &self.news_page_controller {
+                    self.navigation_view.push(controller.widget());
+                }
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
@@ -0,0 +1,712 @@
+use crate::NewsFetchService;
+use crate::components::categorised_news::CategorisedNewsSection;
+use crate::components::news_tile::NewsTileOutput;
+use crate::pages::news_page::{NewsPage, NewsPageInput, NewsPageOutput};
+use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput};
+use crate::types::article_page::PageInfo;
+use crate::services::note_service::notes::Notes;
+use crate::types::cache::ImageCache;
+use crate::types::fetch_error::FetchError;
+use crate::types::news_article::NewsArticle;
+use crate::types::news_handler::NewsHandler;
+use crate::types::news_source::NewsSource;
+use crate::types::persistent_articel::PersistentArticle;
+use crate::types::request_parameters::RequestParameters;
+use crate::types::saved_topic::SavedTopic;
+use crate::types::visit_origin::VisitOrigin;
+use crate::utils::page_pignation::NewsPagination;
+use crate::utils::time_organizer;
+
+use adw::prelude::*;
+use relm4::factory::FactoryVecDeque;
+use relm4::prelude::*;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const SORT_ORDERS: [(&str, &str); 3] = [
+    ("Newest", "publishedAt"),
+    ("Relevance", "relevancy"),
+    ("Popularity", "popularity"),
+];
+
+const SOURCES: [(&str, Option<NewsSource>); 5] = [
+    ("All Sources", None),
+    ("NewsAPI", Some(NewsSource::NewsAPI)),
+    ("GNews", Some(NewsSource::GNews)),
+    ("NewsData", Some(NewsSource::NewsData)),
+    ("RSS", Some(NewsSource::Rss)),
+];
+
+pub struct SearchPage {
+    show_sidebar_toggle_btn: bool,
+    sections: FactoryVecDeque<CategorisedNewsSection>,
+    fetch_service: NewsFetchService,
+    cache: ImageCache,
+    notes: Notes,
+    news_page_controller: Option<Controller<NewsPage>>,
+    navigation_view: adw::NavigationView,
+    history_worker: Arc<Controller<HistoryWorker>>,
+    /// Set when the page shows a saved topic from the sidebar instead of the search field
+    topic: Option<SavedTopic>,
+    query: String,
+    from: String,
+    to: String,
+    language: String,
+    sort: usize,
+    source: usize,
+    title_only: bool,
+    is_searching: bool,
+    has_results: bool,
+    error_message: Option<String>,
+    pagination: NewsPagination,
+    reached_end: bool,
+}
+
+#[derive(Debug)]
+pub enum SearchPageInput {
+    Search(String),
+    SetFrom(String),
+    SetTo(String),
+    SetLanguage(String),
+    SetSort(u32),
+    SetSource(u32),
+    SetTitleOnly(bool),
+    ApplyFilters,
+    SaveTopic,
+    SaveTopicAs(String),
+    NextPage,
+    PreviousPage,
+    LoadPage(i32),
+    NewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>),
+    PageLoaded(PageInfo),
+    GotoNews((Arc<dyn NewsArticle>, Vec<Arc<dyn NewsArticle>>)),
+    Bookmark(Arc<dyn NewsArticle>),
+    ApiError(FetchError),
+    SourceFallback(String, String),
+    ShowSidebarToggleBtn(bool),
+}
+
+impl NewsHandler for SearchPageInput {
+    fn on_news_received(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>) -> Self {
+        SearchPageInput::NewsReceived(grouped)
+    }
+    fn on_error(err: FetchError) -> Self {
+        SearchPageInput::ApiError(err)
+    }
+    fn on_page_loaded(info: PageInfo) -> Self {
+        SearchPageInput::PageLoaded(info)
+    }
+    fn on_fallback(failed: String, served_by: String) -> Self {
+        SearchPageInput::SourceFallback(failed, served_by)
+    }
+    fn on_offline(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, _fetched_at: i64) -> Self {
+        SearchPageInput::NewsReceived(grouped)
+    }
+}
+
+#[derive(Debug)]
+pub enum SearchPageOutput {
+    ToggleSidebar,
+    Bookmark(Arc<dyn NewsArticle>),
+    SaveTopic(SavedTopic),
+    /// The topic's first page was shown, so its matches are no longer unread
+    TopicSeen(i64, Vec<String>),
+}
+
+impl SearchPage {
+    fn parameters(&self) -> RequestParameters {
+        let mut parameters = RequestParameters::new()
+            .query(self.query.clone())
+            .sort_by(SORT_ORDERS[self.sort].1);
+
+        if !self.from.is_empty() {
+            parameters = parameters.from(self.from.clone());
+        }
+        if !self.to.is_empty() {
+            parameters = parameters.to(self.to.clone());
+        }
+        if !self.language.is_empty() {
+            parameters = parameters.language(self.language.clone());
+        }
+        if self.title_only {
+            parameters = parameters.search_in("title");
+        }
+        parameters
+    }
+
+    /// Empty or a calendar date, the format every provider accepts
+    fn is_valid_date(date: &str) -> bool {
+        date.is_empty() || chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()
+    }
+
+    fn to_topic(&self, name: String) -> SavedTopic {
+        let optional = |value: &str| (!value.is_empty()).then(|| value.to_string());
+
+        SavedTopic {
+            from: optional(&self.from),
+            to: optional(&self.to),
+            language: optional(&self.language),
+            sort_by: SORT_ORDERS[self.sort].1.to_string(),
+            source: SOURCES[self.source].1.clone(),
+            title_only: self.title_only,
+            ..SavedTopic::new(name, self.query.clone())
+        }
+    }
+
+    fn results_title(&self) -> String {
+        match &self.topic {
+            Some(topic) => topic.name.clone(),
+            None => format!("Results for “{}”", self.query),
+        }
+    }
+
+    fn status_title(&self) -> String {
+        if self.query.is_empty() {
+            "Search News".to_string()
+        } else {
+            format!("No results for “{}”", self.query)
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl Component for SearchPage {
+    type Init = (
+        NewsFetchService,
+        Arc<Controller<HistoryWorker>>,
+        ImageCache,
+        Notes,
+        bool,
+        Option<SavedTopic>,
+    );
+    type Input = SearchPageInput;
+    type Output = SearchPageOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::NavigationPage {
+            set_title: "Search",
+
+            #[wrap(Some)]
+            set_child = &model.navigation_view.clone() {
+                push = &adw::NavigationPage {
+                    set_tag: Some("search"),
+
+                    #[wrap(Some)]
+                    set_child = &adw::ToolbarView {
+                        add_top_bar = &gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+
+                            adw::HeaderBar {
+                                set_margin_start: 20,
+                                set_show_title: false,
+                                pack_start = &gtk::Button {
+                                    set_icon_name: "sidebar-show-symbolic",
+                                    #[watch]
+                                    set_visible: model.show_sidebar_toggle_btn,
+                                    connect_clicked[sender] => move |_| {
+                                        let _ = sender.output(SearchPageOutput::ToggleSidebar);
+                                    }
+                                },
+                                pack_end = &gtk::Button {
+                                    set_icon_name: "view-refresh-symbolic",
+                                    set_tooltip: "Refresh",
+                                    #[watch]
+                                    set_sensitive: !model.query.is_empty() && !model.is_searching,
+                                    connect_clicked[sender] => move |_| {
+                                        sender.input(SearchPageInput::ApplyFilters);
+                                    }
+                                },
+                                pack_end = &gtk::Button {
+                                    set_icon_name: "starred-symbolic",
+                                    set_tooltip: "Save as Topic",
+                                    #[watch]
+                                    set_visible: model.topic.is_none(),
+                                    #[watch]
+                                    set_sensitive: !model.query.is_empty(),
+                                    connect_clicked[sender] => move |_| {
+                                        sender.input(SearchPageInput::SaveTopic);
+                                    }
+                                },
+                            },
+
+                            // --- FILTERS ---
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Horizontal,
+                                set_halign: gtk::Align::Center,
+                                set_spacing: 8,
+                                set_margin_all: 12,
+
+                                gtk::Entry {
+                                    set_placeholder_text: Some("From (YYYY-MM-DD)"),
+                                    set_text: &model.from,
+                                    set_width_chars: 16,
+                                    connect_changed[sender] => move |entry| {
+                                        sender.input(SearchPageInput::SetFrom(entry.text().to_string()));
+                                    },
+                                    connect_activate[sender] => move |_| {
+                                        sender.input(SearchPageInput::ApplyFilters);
+                                    }
+                                },
+
+                                gtk::Entry {
+                                    set_placeholder_text: Some("To (YYYY-MM-DD)"),
+                                    set_text: &model.to,
+                                    set_width_chars: 16,
+                                    connect_changed[sender] => move |entry| {
+                                        sender.input(SearchPageInput::SetTo(entry.text().to_string()));
+                                    },
+                                    connect_activate[sender] => move |_| {
+                                        sender.input(SearchPageInput::ApplyFilters);
+                                    }
+                                },
+
+                                gtk::Entry {
+                                    set_placeholder_text: Some("Language (en)"),
+                                    set_text: &model.language,
+                                    set_width_chars: 12,
+                                    connect_changed[sender] => move |entry| {
+                                        sender.input(SearchPageInput::SetLanguage(entry.text().to_string()));
+                                    },
+                                    connect_activate[sender] => move |_| {
+                                        sender.input(SearchPageInput::ApplyFilters);
+                                    }
+                                },
+
+                                gtk::DropDown::from_strings(&SORT_ORDERS.map(|(name, _)| name)) {
+                                    set_tooltip_text: Some("Sort Order"),
+                                    set_selected: model.sort as u32,
+                                    connect_selected_notify[sender] => move |dropdown| {
+                                        sender.input(SearchPageInput::SetSort(dropdown.selected()));
+                                    }
+                                },
+
+                                gtk::DropDown::from_strings(&SOURCES.map(|(name, _)| name)) {
+                                    set_tooltip_text: Some("Source"),
+                                    set_selected: model.source as u32,
+                                    connect_selected_notify[sender] => move |dropdown| {
+                                        sender.input(SearchPageInput::SetSource(dropdown.selected()));
+                                    }
+                                },
+
+                                gtk::CheckButton {
+                                    set_label: Some("Titles only"),
+                                    set_active: model.title_only,
+                                    connect_toggled[sender] => move |check| {
+                                        sender.input(SearchPageInput::SetTitleOnly(check.is_active()));
+                                    }
+                                },
+
+                                gtk::Button {
+                                    set_label: "Apply",
+                                    add_css_class: "suggested-action",
+                                    #[watch]
+                                    set_sensitive: !model.query.is_empty() && !model.is_searching,
+                                    connect_clicked[sender] => move |_| {
+                                        sender.input(SearchPageInput::ApplyFilters);
+                                    }
+                                },
+                            },
+
+                            adw::Banner {
+                                #[watch]
+                                set_title: model.error_message.as_deref().unwrap_or(""),
+                                #[watch]
+                                set_revealed: model.error_message.is_some(),
+                            },
+                        },
+
+                        #[wrap(Some)]
+                        set_content = &gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+
+                            #[name = "toast_overlay"]
+                            adw::ToastOverlay {
+                                set_vexpand: true,
+
+                                #[wrap(Some)]
+                                set_child = &gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+
+                                    adw::Spinner {
+                                        set_height_request: 50,
+                                        set_width_request: 50,
+                                        set_vexpand: true,
+                                        set_halign: gtk::Align::Center,
+                                        #[watch]
+                                        set_visible: model.is_searching && !model.has_results,
+                                    },
+
+                                    adw::StatusPage {
+                                        set_icon_name: Some("system-search-symbolic"),
+                                        set_vexpand: true,
+                                        #[watch]
+                                        set_title: &model.status_title(),
+                                        set_description: Some("Type in the sidebar search field and press Enter"),
+                                        #[watch]
+                                        set_visible: !model.is_searching && !model.has_results,
+                                    },
+
+                                    gtk::ScrolledWindow {
+                                        set_vexpand: true,
+                                        set_hscrollbar_policy: gtk::PolicyType::Never,
+                                        #[watch]
+                                        set_visible: model.has_results,
+
+                                        adw::Clamp {
+                                            set_maximum_size: 1400,
+                                            set_tightening_threshold: 1000,
+
+                                            gtk::Box {
+                                                set_orientation: gtk::Orientation::Vertical,
+                                                set_spacing: 24,
+                                                set_margin_all: 20,
+
+                                                gtk::Label {
+                                                    #[watch]
+                                                    set_label: &model.results_title(),
+                                                    add_css_class: "frost-brand-title",
+                                                    set_xalign: 0.0,
+                                                },
+
+                                                gtk::Label {
+                                                    #[watch]
+                                                    set_label: &format!("About {} matching articles", model.pagination.total_results),
+                                                    add_css_class: "dim-label",
+                                                    set_xalign: 0.0,
+                                                },
+
+                                                #[local_ref]
+                                                sections -> gtk::Box {
+                                                    set_orientation: gtk::Orientation::Vertical,
+                                                    set_spacing: 12,
+                                                },
+
+                                                // --- PAGINATION FOOTER ---
+                                                gtk::Box {
+                                                    set_orientation: gtk::Orientation::Horizontal,
+                                                    set_halign: gtk::Align::Center,
+                                                    set_spacing: 12,
+                                                    set_margin_bottom: 20,
+
+                                                    gtk::Button {
+                                                        set_icon_name: "go-previous-symbolic",
+                                                        add_css_class: "circular",
+                                                        #[watch]
+                                                        set_sensitive: model.pagination.current_page > 1 && !model.is_searching,
+                                                        connect_clicked[sender] => move |_| {
+                                                            sender.input(SearchPageInput::PreviousPage);
+                                                        }
+                                                    },
+
+                                                    gtk::Label {
+                                                        #[watch]
+                                                        set_label: &format!("Page {}", model.pagination.current_page),
+                                                        add_css_class: "dim-label",
+                                                    },
+
+                                                    gtk::Button {
+                                                        set_icon_name: "go-next-symbolic",
+                                                        add_css_class: "circular",
+                                                        #[watch]
+                                                        set_sensitive: !model.reached_end && !model.is_searching,
+                                                        connect_clicked[sender] => move |_| {
+                                                            sender.input(SearchPageInput::NextPage);
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let (fetch_service, history_worker, cache, notes, show_sidebar_toggle_btn, topic) = init;
+
+        let sections = FactoryVecDeque::builder()
+            .launch(gtk::Box::new(gtk::Orientation::Vertical, 12))
+            .forward(sender.input_sender(), move |output| match output {
+                NewsTileOutput::Open(data) => SearchPageInput::GotoNews(data),
+                NewsTileOutput::Bookmark(article) => SearchPageInput::Bookmark(article),
+            });
+
+        let model = SearchPage {
+            show_sidebar_toggle_btn,
+            sections,
+            fetch_service,
+            cache,
+            notes,
+            news_page_controller: None,
+            navigation_view: adw::NavigationView::builder().build(),
+            history_worker,
+            query: topic.as_ref().map(|t| t.query.clone()).unwrap_or_default(),
+            from: topic.as_ref().and_then(|t| t.from.clone()).unwrap_or_default(),
+            to: topic.as_ref().and_then(|t| t.to.clone()).unwrap_or_default(),
+            language: topic
+                .as_ref()
+                .and_then(|t| t.language.clone())
+                .unwrap_or_default(),
+            sort: topic
+                .as_ref()
+                .and_then(|t| SORT_ORDERS.iter().position(|(_, key)| *key == t.sort_by))
+                .unwrap_or(0),
+            source: topic
+                .as_ref()
+                .and_then(|t| SOURCES.iter().position(|(_, source)| *source == t.source))
+                .unwrap_or(0),
+            title_only: topic.as_ref().is_some_and(|t| t.title_only),
+            topic,
+            is_searching: false,
+            has_results: false,
+            error_message: None,
+            pagination: NewsPagination::new(),
+            reached_end: false,
+        };
+
+        let sections = model.sections.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            SearchPageInput::ShowSidebarToggleBtn(visible) => {
+                self.show_sidebar_toggle_btn = visible;
+                if let Some(controller) = &self.news_page_controller {
+                    let _ = controller
+                        .sender()
+                        .send(NewsPageInput::ShowSidebarToggleBtn(visible));
+                }
+            }
+            SearchPageInput::Search(query) => {
+                self.query = query.trim().to_string();
+                self.navigation_view.pop_to_tag("search");
+                sender.input(SearchPageInput::ApplyFilters);
+            }
+            SearchPageInput::SetFrom(from) => self.from = from.trim().to_string(),
+            SearchPageInput::SetTo(to) => self.to = to.trim().to_string(),
+            SearchPageInput::SetLanguage(language) => {
+                self.language = language.trim().to_lowercase();
+            }
+            SearchPageInput::SetSort(index) => {
+                self.sort = (index as usize).min(SORT_ORDERS.len() - 1);
+            }
+            SearchPageInput::SetSource(index) => {
+                self.source = (index as usize).min(SOURCES.len() - 1);
+            }
+            SearchPageInput::SetTitleOnly(active) => self.title_only = active,
+            SearchPageInput::ApplyFilters => {
+                if self.query.is_empty() {
+                    return;
+                }
+                if !Self::is_valid_date(&self.from) || !Self::is_valid_date(&self.to) {
+                    let toast = adw::Toast::new("Dates must look like 2026-01-31");
+                    toast.set_timeout(5);
+                    widgets.toast_overlay.add_toast(toast);
+                    return;
+                }
+                let dated = !self.from.is_empty() || !self.to.is_empty();
+                if dated && self.fetch_service.drops_date_range(SOURCES[self.source].1.as_ref()) {
+                    let toast = adw::Toast::new(
+                        "NewsData's free plan can't search by date, showing results from any date",
+                    );
+                    toast.set_timeout(5);
+                    widgets.toast_overlay.add_toast(toast);
+                }
+
+                self.pagination.reset();
+                self.has_results = false;
+                sender.input(SearchPageInput::LoadPage(1));
+            }
+            SearchPageInput::SaveTopic => {
+                let dialog = adw::AlertDialog::new(
+                    Some("Save as Topic"),
+                    Some("The topic is listed in the sidebar and checked for new articles"),
+                );
+                let name_row = adw::EntryRow::builder()
+                    .title("Name")
+                    .text(&self.query)
+                    .build();
+                let fields = gtk::ListBox::builder()
+                    .selection_mode(gtk::SelectionMode::None)
+                    .css_classes(vec!["boxed-list"])
+                    .build();
+                fields.append(&name_row);
+
+                dialog.set_extra_child(Some(&fields));
+                dialog.add_responses(&[("cancel", "Cancel"), ("save", "Save")]);
+                dialog.set_response_appearance("save", adw::ResponseAppearance::Suggested);
+                dialog.set_default_response(Some("save"));
+                dialog.set_close_response("cancel");
+
+                let sender = sender.clone();
+                dialog.connect_response(None, move |_, response| {
+                    if response == "save" {
+                        sender.input(SearchPageInput::SaveTopicAs(
+                            name_row.text().trim().to_string(),
+                        ));
+                    }
+                });
+                dialog.present(Some(root));
+            }
+            SearchPageInput::SaveTopicAs(name) => {
+                let name = if name.is_empty() { self.query.clone() } else { name };
+                let _ = sender.output(SearchPageOutput::SaveTopic(self.to_topic(name.clone())));
+
+                let toast = adw::Toast::new(&format!("Saved “{}” to Topics", name));
+                toast.set_timeout(3);
+                widgets.toast_overlay.add_toast(toast);
+            }
+            SearchPageInput::NextPage => {
+                let next = self.pagination.current_page + 1;
+                sender.input(SearchPageInput::LoadPage(next));
+            }
+            SearchPageInput::PreviousPage => {
+                if self.pagination.current_page > 1 {
+                    let prev = self.pagination.current_page - 1;
+                    sender.input(SearchPageInput::LoadPage(prev));
+                }
+            }
+            SearchPageInput::LoadPage(page_num) => {
+                self.pagination.current_page = page_num;
+                if let Some(existing_data) = self.pagination.pages.get(&page_num) {
+                    sender.input(SearchPageInput::NewsReceived(existing_data.clone()));
+                } else {
+                    self.is_searching = true;
+                    self.error_message = None;
+                    self.fetch_service.search(
+                        self.parameters(),
+                        SOURCES[self.source].1.clone(),
+                        page_num,
+                        self.pagination.cursors_for(page_num),
+                        sender.clone(),
+                    );
+                }
+            }
+            SearchPageInput::PageLoaded(info) => {
+                self.pagination.record(self.pagination.current_page, info);
+            }
+            SearchPageInput::NewsReceived(grouped_data) => {
+                self.is_searching = false;
+
+                if grouped_data.is_empty() {
+                    if self.pagination.current_page > 1 {
+                        let toast = adw::Toast::new("No more results");
+                        toast.set_timeout(5);
+                        widgets.toast_overlay.add_toast(toast);
+                        self.reached_end = true;
+                        self.pagination.current_page -= 1;
+                    } else {
+                        self.has_results = false;
+                        self.sections.guard().clear();
+                    }
+                } else {
+                    self.has_results = true;
+                    self.reached_end = !self.pagination.has_next(self.pagination.current_page);
+
+                    if let Some(topic) = &self.topic {
+                        let urls = grouped_data
+                            .values()
+                            .flatten()
+                            .map(|article| article.url())
+                            .collect();
+                        let _ = sender.output(SearchPageOutput::TopicSeen(topic.id, urls));
+                    }
+                    self.pagination
+                        .pages
+                        .insert(self.pagination.current_page, grouped_data.clone());
+
+                    let mut guard = self.sections.guard();
+                    guard.clear();
+                    let noted = Arc::new(self.notes.noted_ids());
+
+                    let mut buckets: Vec<_> = grouped_data.keys().cloned().collect();
+                    buckets.sort_by(|a, b| {
+                        time_organizer::UITimeOrganiser::comapre(a.clone(), b.clone())
+                    });
+
+                    for bucket in buckets {
+                        if let Some(articles) = grouped_data.get(&bucket) {
+                            guard.push_back((
+                                bucket,
+                                articles.to_vec(),
+                                self.cache.clone(),
+                                noted.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+            SearchPageInput::ApiError(e) => {
+                self.is_searching = false;
+                self.error_message = Some(e.to_string());
+            }
+            SearchPageInput::SourceFallback(failed, served_by) => {
+                let toast = adw::Toast::new(&format!(
+                    "{} is unavailable, showing results from {}",
+                    failed, served_by
+                ));
+                toast.set_timeout(5);
+                widgets.toast_overlay.add_toast(toast);
+            }
+            SearchPageInput::Bookmark(article) => {
+                let _ = sender.output(SearchPageOutput::Bookmark(article));
+            }
+            SearchPageInput::GotoNews(data) => {
+                let (article, related_articles) = data;
+
+                let persistent_arcticle = PersistentArticle::auto_create(
+                    article.title(),
+                    article.url(),
+                    article.description(),
+                    article.content(),
+                    article.published_at(),
+                    article.url_to_image().unwrap_or("".to_string()),
+                );
+                let origin = VisitOrigin::new(article.source(), None);
+                self.history_worker
+                    .emit(HistoryWorkerInput::Save(persistent_arcticle, origin));
+
+                self.news_page_controller = Some(
+                    NewsPage::builder()
+                        .launch((
+                            article.clone(),
+                            related_articles.clone(),
+                            self.cache.clone(),
+                            self.notes.clone(),
+                            self.history_worker.clone(),
+                            self.show_sidebar_toggle_btn,
+                        ))
+                        .forward(sender.output_sender(), move |action| match action {
+                            NewsPageOutput::ToggleSidebar => SearchPageOutput::ToggleSidebar,
+                            NewsPageOutput::Bookmark(article) => SearchPageOutput::Bookmark(article),
+                        }),
+                );
+
+                if let Some(controller) = &self.news_page_controller {
+                    self.navigation_view.push(controller.widget());
+                }
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
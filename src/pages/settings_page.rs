@@ -0,0 +1,514 @@
+use adw::prelude::*;
+use relm4::{Component, ComponentParts, ComponentSender, prelude::*};
+
+use crate::NewsFetchService;
+use crate::services::news_settings_service::settings::NewsServiceSettings;
+use crate::types::news_source::NewsSource;
+use crate::types::provider_usage::ProviderUsage;
+
+const SOURCES: [(&str, NewsSource); 4] = [
+    ("NewsAPI", NewsSource::NewsAPI),
+    ("GNews", NewsSource::GNews),
+    ("NewsData", NewsSource::NewsData),
+    ("RSS", NewsSource::Rss),
+];
+
+#[derive(Debug)]
+pub struct SettingsPage {
+    fetch_service: NewsFetchService,
+    settings: NewsServiceSettings,
+    usage: Vec<ProviderUsage>,
+    configured_sources: Vec<NewsSource>,
+    /// Every source in fallback order, with whether it is used as a fallback at all
+    fallbacks: Vec<(NewsSource, bool)>,
+    rss_feeds: Vec<String>,
+    show_sidebar_toggle_btn: bool,
+}
+
+#[derive(Debug)]
+pub enum SettingsPageInput {
+    ShowSidebarToggleBtn(bool),
+    RefreshUsage,
+    SelectSource(u32),
+    SetAggregate(bool),
+    MoveFallback(usize, isize),
+    SetFallbackEnabled(usize, bool),
+    AddRssFeed(String),
+    RemoveRssFeed(usize),
+    SetCountry(String),
+    SetLanguage(String),
+    SetPrivateMode(bool),
+    SetKeepDays(u32),
+    SetMaxEntries(u32),
+}
+
+#[derive(Debug)]
+pub enum SettingsPageOutput {
+    ToggleSidebar,
+}
+
+impl SettingsPage {
+    /// The user's fallback sources in order, followed by the sources left out of it
+    fn load_fallbacks(settings: &NewsServiceSettings) -> Vec<(NewsSource, bool)> {
+        let mut fallbacks: Vec<(NewsSource, bool)> = settings
+            .fallback_sources()
+            .into_iter()
+            .map(|source| (source, true))
+            .collect();
+        for (_, source) in SOURCES {
+            if !fallbacks.iter().any(|(fallback, _)| *fallback == source) {
+                fallbacks.push((source, false));
+            }
+        }
+        fallbacks
+    }
+
+    fn save_fallbacks(&self) {
+        let sources: Vec<NewsSource> = self
+            .fallbacks
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(source, _)| source.clone())
+            .collect();
+        self.settings.set_fallback_sources(&sources);
+    }
+
+    fn render_rss_feeds(&self, widgets: &SettingsPageWidgets, sender: &ComponentSender<Self>) {
+        let list = &widgets.rss_list;
+        while let Some(child) = list.first_child() {
+            list.remove(&child);
+        }
+        list.set_visible(!self.rss_feeds.is_empty());
+
+        for (index, feed) in self.rss_feeds.iter().enumerate() {
+            let row = adw::ActionRow::builder()
+                .title(feed.as_str())
+                .title_lines(1)
+                .build();
+
+            let remove = gtk::Button::builder()
+                .icon_name("user-trash-symbolic")
+                .tooltip_text("Remove Feed")
+                .valign(gtk::Align::Center)
+                .css_classes(vec!["flat"])
+                .build();
+            let sender = sender.clone();
+            remove.connect_clicked(move |_| {
+                sender.input(SettingsPageInput::RemoveRssFeed(index));
+            });
+
+            row.add_suffix(&remove);
+            list.append(&row);
+        }
+    }
+
+    /// Adding the first feed or removing the last one changes whether RSS is usable
+    fn rss_feeds_changed(&mut self, widgets: &SettingsPageWidgets, sender: &ComponentSender<Self>) {
+        self.settings.set_rss_feeds(&self.rss_feeds);
+        self.configured_sources = self.fetch_service.configured_sources();
+        self.render_rss_feeds(widgets, sender);
+        self.render_fallbacks(widgets, sender);
+    }
+
+    fn render_fallbacks(&self, widgets: &SettingsPageWidgets, sender: &ComponentSender<Self>) {
+        let list = &widgets.fallback_list;
+        while let Some(child) = list.first_child() {
+            list.remove(&child);
+        }
+
+        let last = self.fallbacks.len().saturating_sub(1);
+        for (index, (source, enabled)) in self.fallbacks.iter().enumerate() {
+            let name = SOURCES
+                .iter()
+                .find(|(_, known)| known == source)
+                .map_or("", |(name, _)| *name);
+            let row = adw::ActionRow::builder().title(name).build();
+            if !self.configured_sources.contains(source) {
+                row.set_subtitle("Not configured, skipped");
+            }
+
+            let up = gtk::Button::builder()
+                .icon_name("go-up-symbolic")
+                .tooltip_text("Try Earlier")
+                .valign(gtk::Align::Center)
+                .sensitive(index > 0)
+                .css_classes(vec!["flat"])
+                .build();
+            let up_sender = sender.clone();
+            up.connect_clicked(move |_| {
+                up_sender.input(SettingsPageInput::MoveFallback(index, -1));
+            });
+
+            let down = gtk::Button::builder()
+                .icon_name("go-down-symbolic")
+                .tooltip_text("Try Later")
+                .valign(gtk::Align::Center)
+                .sensitive(index < last)
+                .css_classes(vec!["flat"])
+                .build();
+            let down_sender = sender.clone();
+            down.connect_clicked(move |_| {
+                down_sender.input(SettingsPageInput::MoveFallback(index, 1));
+            });
+
+            let switch = gtk::Switch::builder()
+                .active(*enabled)
+                .valign(gtk::Align::Center)
+                .build();
+            let switch_sender = sender.clone();
+            switch.connect_active_notify(move |switch| {
+                switch_sender.input(SettingsPageInput::SetFallbackEnabled(
+                    index,
+                    switch.is_active(),
+                ));
+            });
+
+            row.add_suffix(&up);
+            row.add_suffix(&down);
+            row.add_suffix(&switch);
+            list.append(&row);
+        }
+    }
+
+    fn key_status(&self, source: NewsSource, env_var: &str) -> String {
+        if self.configured_sources.contains(&source) {
+            "Configured".to_string()
+        } else {
+            format!("Missing, set {} in your .env file", env_var)
+        }
+    }
+
+    fn find_usage(&self, provider: &str) -> Option<&ProviderUsage> {
+        self.usage.iter().find(|usage| usage.provider == provider)
+    }
+
+    fn usage_summary(&self, provider: &str) -> String {
+        match self.find_usage(provider) {
+            Some(usage) => {
+                let hours = (usage.resets_at - chrono::Utc::now()).num_hours();
+                format!(
+                    "{} of {} requests used, about {} left. Resets in {}h",
+                    usage.requests,
+                    usage.daily_limit,
+                    usage.remaining(),
+                    hours
+                )
+            }
+            None => "No usage recorded".to_string(),
+        }
+    }
+
+    fn usage_fraction(&self, provider: &str) -> f64 {
+        self.find_usage(provider)
+            .map(|usage| 1.0 - usage.remaining() as f64 / usage.daily_limit.max(1) as f64)
+            .unwrap_or(0.0)
+    }
+}
+
+#[relm4::component(pub)]
+impl Component for SettingsPage {
+    type Init = (NewsFetchService, bool);
+    type Input = SettingsPageInput;
+    type Output = SettingsPageOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::NavigationPage {
+            set_title: "Settings",
+
+            connect_map[sender] => move |_| {
+                sender.input(SettingsPageInput::RefreshUsage);
+            },
+
+            #[wrap(Some)]
+            set_child = &adw::ToolbarView {
+                add_top_bar = &adw::HeaderBar {
+                    pack_start = &gtk::Button {
+                        set_icon_name: "sidebar-show-symbolic",
+                        #[watch]
+                        set_visible: model.show_sidebar_toggle_btn,
+                        connect_clicked[sender] => move |_| {
+                            let _ = sender.output(SettingsPageOutput::ToggleSidebar);
+                        },
+                    },
+                },
+
+                #[wrap(Some)]
+                set_content = &adw::PreferencesPage {
+                    add = &adw::PreferencesGroup {
+                        set_title: "News Source",
+
+                        add = &adw::ComboRow {
+                            set_title: "Source",
+                            set_model: Some(&gtk::StringList::new(&SOURCES.map(|(name, _)| name))),
+                            set_selected: SOURCES
+                                .iter()
+                                .position(|(_, source)| *source == model.settings.news_source())
+                                .unwrap_or(0) as u32,
+                            connect_selected_notify[sender] => move |row| {
+                                sender.input(SettingsPageInput::SelectSource(row.selected()));
+                            },
+                        },
+
+                        add = &adw::SwitchRow {
+                            set_title: "Combine All Sources",
+                            set_subtitle: "Query every configured source at once and merge the results",
+                            set_active: model.settings.aggregate_sources(),
+                            connect_active_notify[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetAggregate(row.is_active()));
+                            },
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "Fallback Order",
+                        set_description: Some("Tried from the top when the selected source is out of quota or rejects the key. The selected source itself is skipped."),
+
+                        #[name = "fallback_list"]
+                        gtk::ListBox {
+                            set_selection_mode: gtk::SelectionMode::None,
+                            add_css_class: "boxed-list",
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "RSS Feeds",
+                        set_description: Some("RSS and Atom feeds read by the RSS source"),
+
+                        #[name = "rss_list"]
+                        gtk::ListBox {
+                            set_selection_mode: gtk::SelectionMode::None,
+                            set_margin_bottom: 12,
+                            add_css_class: "boxed-list",
+                        },
+
+                        gtk::ListBox {
+                            set_selection_mode: gtk::SelectionMode::None,
+                            add_css_class: "boxed-list",
+
+                            adw::EntryRow {
+                                set_title: "Add Feed URL",
+                                set_show_apply_button: true,
+                                connect_apply[sender] => move |row| {
+                                    sender.input(SettingsPageInput::AddRssFeed(row.text().to_string()));
+                                    row.set_text("");
+                                },
+                            },
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "Region",
+
+                        add = &adw::EntryRow {
+                            set_title: "Country",
+                            set_text: &model.settings.country(),
+                            set_show_apply_button: true,
+                            connect_apply[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetCountry(row.text().to_string()));
+                            },
+                        },
+
+                        add = &adw::EntryRow {
+                            set_title: "Language",
+                            set_text: &model.settings.language(),
+                            set_show_apply_button: true,
+                            connect_apply[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetLanguage(row.text().to_string()));
+                            },
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "History",
+                        set_description: Some("Older entries are removed when the app starts and every hour after"),
+
+                        add = &adw::SwitchRow {
+                            set_title: "Private Mode",
+                            set_subtitle: "Stop recording the articles you read",
+                            set_active: model.settings.history_retention().private_mode,
+                            connect_active_notify[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetPrivateMode(row.is_active()));
+                            },
+                        },
+
+                        add = &adw::SpinRow::with_range(0.0, 3650.0, 1.0) {
+                            set_title: "Keep History For",
+                            set_subtitle: "Days, 0 keeps history forever",
+                            set_value: model.settings.history_retention().keep_days as f64,
+                            connect_value_notify[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetKeepDays(row.value() as u32));
+                            },
+                        },
+
+                        add = &adw::SpinRow::with_range(0.0, 100000.0, 100.0) {
+                            set_title: "Maximum Entries",
+                            set_subtitle: "0 for no limit",
+                            set_value: model.settings.history_retention().max_entries as f64,
+                            connect_value_notify[sender] => move |row| {
+                                sender.input(SettingsPageInput::SetMaxEntries(row.value() as u32));
+                            },
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "API Keys",
+                        set_description: Some("Keys are read from the environment when the app starts"),
+
+                        add = &adw::ActionRow {
+                            set_title: "NewsAPI",
+                            set_subtitle: &model.key_status(NewsSource::NewsAPI, "NEWS_API_KEY"),
+                        },
+
+                        add = &adw::ActionRow {
+                            set_title: "GNews",
+                            set_subtitle: &model.key_status(NewsSource::GNews, "GNEWS_API_KEY"),
+                        },
+
+                        add = &adw::ActionRow {
+                            set_title: "NewsData",
+                            set_subtitle: &model.key_status(NewsSource::NewsData, "NEWSDATA_API_KEY"),
+                        },
+                    },
+
+                    add = &adw::PreferencesGroup {
+                        set_title: "Usage Today",
+                        set_description: Some("Estimated from the requests made on this computer and the limits providers report. Anyone sharing your keys also uses this allowance."),
+
+                        add = &adw::ActionRow {
+                            set_title: "NewsAPI",
+                            #[watch]
+                            set_subtitle: &model.usage_summary("NewsAPI"),
+                            add_suffix = &gtk::LevelBar {
+                                set_valign: gtk::Align::Center,
+                                set_width_request: 120,
+                                #[watch]
+                                set_value: model.usage_fraction("NewsAPI"),
+                            },
+                        },
+
+                        add = &adw::ActionRow {
+                            set_title: "GNews",
+                            #[watch]
+                            set_subtitle: &model.usage_summary("GNews"),
+                            add_suffix = &gtk::LevelBar {
+                                set_valign: gtk::Align::Center,
+                                set_width_request: 120,
+                                #[watch]
+                                set_value: model.usage_fraction("GNews"),
+                            },
+                        },
+
+                        add = &adw::ActionRow {
+                            set_title: "NewsData",
+                            #[watch]
+                            set_subtitle: &model.usage_summary("NewsData"),
+                            add_suffix = &gtk::LevelBar {
+                                set_valign: gtk::Align::Center,
+                                set_width_request: 120,
+                                #[watch]
+                                set_value: model.usage_fraction("NewsData"),
+                            },
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let (fetch_service, show_sidebar_toggle_btn) = init;
+
+        let settings = fetch_service.get_settings();
+        let model = SettingsPage {
+            fallbacks: Self::load_fallbacks(&settings),
+            rss_feeds: settings.rss_feeds(),
+            settings,
+            configured_sources: fetch_service.configured_sources(),
+            usage: fetch_service.usage_report(),
+            fetch_service,
+            show_sidebar_toggle_btn,
+        };
+
+        let widgets = view_output!();
+        model.render_fallbacks(&widgets, &sender);
+        model.render_rss_feeds(&widgets, &sender);
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match msg {
+            SettingsPageInput::ShowSidebarToggleBtn(visible) => {
+                self.show_sidebar_toggle_btn = visible;
+            }
+            SettingsPageInput::RefreshUsage => {
+                self.usage = self.fetch_service.usage_report();
+            }
+            SettingsPageInput::SelectSource(index) => {
+                if let Some((_, source)) = SOURCES.get(index as usize) {
+                    self.settings.set_news_source(source.clone());
+                }
+            }
+            SettingsPageInput::SetAggregate(active) => {
+                self.settings.set_aggregate_sources(active);
+            }
+            SettingsPageInput::MoveFallback(index, offset) => {
+                let target = index.checked_add_signed(offset);
+                if let Some(target) = target.filter(|target| *target < self.fallbacks.len()) {
+                    self.fallbacks.swap(index, target);
+                    self.save_fallbacks();
+                    self.render_fallbacks(widgets, &sender);
+                }
+            }
+            SettingsPageInput::SetFallbackEnabled(index, enabled) => {
+                if let Some(fallback) = self.fallbacks.get_mut(index) {
+                    fallback.1 = enabled;
+                    self.save_fallbacks();
+                }
+            }
+            SettingsPageInput::AddRssFeed(feed) => {
+                let feed = feed.trim();
+                let valid =
+                    url::Url::parse(feed).is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+                if valid && !self.rss_feeds.iter().any(|known| known == feed) {
+                    self.rss_feeds.push(feed.to_string());
+                    self.rss_feeds_changed(widgets, &sender);
+                }
+            }
+            SettingsPageInput::RemoveRssFeed(index) => {
+                if index < self.rss_feeds.len() {
+                    self.rss_feeds.remove(index);
+                    self.rss_feeds_changed(widgets, &sender);
+                }
+            }
+            SettingsPageInput::SetCountry(country) => {
+                self.settings.set_country(country.trim());
+            }
+            SettingsPageInput::SetLanguage(language) => {
+                self.settings.set_langauge(language.trim());
+            }
+            SettingsPageInput::SetPrivateMode(active) => {
+                self.settings.set_private_mode(active);
+            }
+            SettingsPageInput::SetKeepDays(days) => {
+                self.settings.set_history_keep_days(days);
+            }
+            SettingsPageInput::SetMaxEntries(entries) => {
+                self.settings.set_history_max_entries(entries);
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use adw::prelude::*;
+use chrono::{Local, NaiveDate};
+use relm4::{Component, ComponentParts, ComponentSender, Controller, prelude::*};
+
+use crate::services::workers::history_worker::{HistoryWorker, HistoryWorkerInput};
+use crate::types::reading_stats::ReadingStats;
+
+/// How many days the chart goes back
+const CHART_DAYS: u64 = 30;
+
+#[derive(Debug)]
+pub struct StatsPage {
+    history_worker: Arc<Controller<HistoryWorker>>,
+    show_sidebar_toggle_btn: bool,
+    stats: ReadingStats,
+    today: NaiveDate,
+}
+
+#[derive(Debug)]
+pub enum StatsPageInput {
+    Refresh,
+    Update(ReadingStats),
+    ShowSidebarToggleBtn(bool),
+}
+
+#[derive(Debug)]
+pub enum StatsPageOutput {
+    ToggleSidebar,
+}
+
+impl StatsPage {
+    fn render(&self, widgets: &StatsPageWidgets) {
+        let chart = &widgets.chart;
+        while let Some(child) = chart.first_child() {
+            chart.remove(&child);
+        }
+
+        let days = self.stats.last_days(self.today, CHART_DAYS);
+        let most = days.iter().fold(1, |most, (_, count)| most.max(*count));
+        for (day, count) in days {
+            chart.append(&Self::day_bar(day, count, most));
+        }
+
+        Self::fill_ranking(&widgets.sources_list, &self.stats.top_sources);
+        Self::fill_ranking(&widgets.sections_list, &self.stats.top_sections);
+    }
+
+    fn day_bar(day: NaiveDate, count: usize, most: usize) -> gtk::LevelBar {
+        let bar = gtk::LevelBar::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .inverted(true)
+            .min_value(0.0)
+            .max_value(most as f64)
+            .value(count as f64)
+            .hexpand(true)
+            .tooltip_text(format!(
+                "{}: {}",
+                day.format("%a %-d %b"),
+                Self::articles(count)
+            ))
+            .build();
+
+        // the default offsets color bars as if they were levels, here they are only counts
+        bar.remove_offset_value(Some(gtk::LEVEL_BAR_OFFSET_LOW));
+        bar.remove_offset_value(Some(gtk::LEVEL_BAR_OFFSET_HIGH));
+        bar.remove_offset_value(Some(gtk::LEVEL_BAR_OFFSET_FULL));
+        bar
+    }
+
+    fn fill_ranking(list: &gtk::ListBox, ranking: &[(String, usize)]) {
+        while let Some(child) = list.first_child() {
+            list.remove(&child);
+        }
+
+        if ranking.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("Nothing recorded yet")
+                .css_classes(vec!["dim-label"])
+                .build();
+            list.append(&row);
+        }
+
+        for (name, count) in ranking {
+            let row = adw::ActionRow::builder().title(name.as_str()).build();
+            row.add_suffix(
+                &gtk::Label::builder()
+                    .label(Self::articles(*count))
+                    .css_classes(vec!["dim-label"])
+                    .build(),
+            );
+            list.append(&row);
+        }
+    }
+
+    fn articles(count: usize) -> String {
+        if count == 1 {
+            "1 article".to_string()
+        } else {
+            format!("{} articles", count)
+        }
+    }
+
+    fn days(count: usize) -> String {
+        if count == 1 {
+            "1 day".to_string()
+        } else {
+            format!("{} days", count)
+        }
+    }
+
+    fn reading_time(&self) -> String {
+        let minutes = self.stats.read_secs / 60;
+        match (minutes / 60, minutes % 60) {
+            (0, 0) => "Under a minute".to_string(),
+            (0, minutes) => format!("{} min", minutes),
+            (hours, minutes) => format!("{} h {} min", hours, minutes),
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl Component for StatsPage {
+    type Init = (Arc<Controller<HistoryWorker>>, bool);
+    type Input = StatsPageInput;
+    type Output = StatsPageOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::NavigationPage {
+            set_title: "Statistics",
+
+            // history changes while other pages are open
+            connect_map[sender] => move |_| {
+                sender.input(StatsPageInput::Refresh);
+            },
+
+            #[wrap(Some)]
+            set_child = &adw::ToolbarView {
+                add_top_bar = &adw::HeaderBar {
+                    set_show_title: false,
+                    pack_start = &gtk::Button {
+                        set_icon_name: "sidebar-show-symbolic",
+                        #[watch]
+                        set_visible: model.show_sidebar_toggle_btn,
+                        connect_clicked[sender] => move |_| {
+                            let _ = sender.output(StatsPageOutput::ToggleSidebar);
+                        },
+                    },
+                },
+
+                #[wrap(Some)]
+                set_content = &gtk::ScrolledWindow {
+                    set_hscrollbar_policy: gtk::PolicyType::Never,
+
+                    adw::Clamp {
+                        set_margin_top: 20,
+                        set_margin_bottom: 40,
+
+                        gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 20,
+
+                            gtk::Label {
+                                set_label: "Statistics",
+                                set_xalign: 0.0,
+                                add_css_class: "frost-brand-title",
+                            },
+
+                            adw::StatusPage {
+                                set_icon_name: Some("document-open-recent-symbolic"),
+                                set_title: "Nothing Read Yet",
+                                set_description: Some("Statistics about the articles you open show up here"),
+                                #[watch]
+                                set_visible: model.stats.articles == 0,
+                            },
+
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 12,
+                                #[watch]
+                                set_visible: model.stats.articles > 0,
+
+                                gtk::ListBox {
+                                    set_selection_mode: gtk::SelectionMode::None,
+                                    add_css_class: "boxed-list",
+
+                                    adw::ActionRow {
+                                        set_title: "Articles Read",
+                                        add_suffix = &gtk::Label {
+                                            #[watch]
+                                            set_label: &model.stats.articles.to_string(),
+                                        },
+                                    },
+                                    adw::ActionRow {
+                                        set_title: "Visits",
+                                        add_suffix = &gtk::Label {
+                                            #[watch]
+                                            set_label: &model.stats.visits.to_string(),
+                                        },
+                                    },
+                                    adw::ActionRow {
+                                        set_title: "Time Reading",
+                                        add_suffix = &gtk::Label {
+                                            #[watch]
+                                            set_label: &model.reading_time(),
+                                        },
+                                    },
+                                    adw::ActionRow {
+                                        set_title: "Current Streak",
+                                        add_suffix = &gtk::Label {
+                                            #[watch]
+                                            set_label: &StatsPage::days(model.stats.current_streak(model.today)),
+                                        },
+                                    },
+                                    adw::ActionRow {
+                                        set_title: "Longest Streak",
+                                        add_suffix = &gtk::Label {
+                                            #[watch]
+                                            set_label: &StatsPage::days(model.stats.longest_streak()),
+                                        },
+                                    },
+                                },
+
+                                gtk::Label {
+                                    set_label: "Last 30 Days",
+                                    set_xalign: 0.0,
+                                    set_margin_top: 12,
+                                    add_css_class: "heading",
+                                },
+
+                                #[name = "chart"]
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Horizontal,
+                                    set_spacing: 4,
+                                    set_homogeneous: true,
+                                    set_height_request: 120,
+                                },
+
+                                gtk::Label {
+                                    set_label: "Top Sources",
+                                    set_xalign: 0.0,
+                                    set_margin_top: 12,
+                                    add_css_class: "heading",
+                                },
+
+                                #[name = "sources_list"]
+                                gtk::ListBox {
+                                    set_selection_mode: gtk::SelectionMode::None,
+                                    add_css_class: "boxed-list",
+                                },
+
+                                gtk::Label {
+                                    set_label: "Top Sections",
+                                    set_xalign: 0.0,
+                                    set_margin_top: 12,
+                                    add_css_class: "heading",
+                                },
+
+                                #[name = "sections_list"]
+                                gtk::ListBox {
+                                    set_selection_mode: gtk::SelectionMode::None,
+                                    add_css_class: "boxed-list",
+                                },
+                            }
+                        }
+                    }
+                },
+            }
+        },
+    }
+
+    fn init(
+        init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let (history_worker, show_sidebar_toggle_btn) = init;
+
+        let model = StatsPage {
+            history_worker,
+            show_sidebar_toggle_btn,
+            stats: ReadingStats::default(),
+            today: Local::now().date_naive(),
+        };
+
+        let widgets = view_output!();
+        model.render(&widgets);
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match msg {
+            StatsPageInput::Refresh => {
+                self.history_worker
+                    .emit(HistoryWorkerInput::Stats(sender.clone()));
+            }
+            StatsPageInput::Update(stats) => {
+                self.stats = stats;
+                self.today = Local::now().date_naive();
+                self.render(widgets);
+            }
+            StatsPageInput::ShowSidebarToggleBtn(visible) => {
+                self.show_sidebar_toggle_btn = visible;
+            }
+        }
+
+        self.update_view(widgets, sender);
+    }
+}
@@ -0,0 +1,146 @@
+use serde::Deserialize;
+
+// --- RSS 2.0 ---
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RssDocument {
+    pub channel: RssChannel,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RssChannel {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    #[serde(rename = "item", default)]
+    pub items: Vec<RssItem>,
+}
+
+// quick-xml matches on local names, so `dc:creator` is declared as `creator`,
+// `media:content` as `content` and so on.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RssItem {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    #[serde(rename = "creator")]
+    pub creator: Option<String>,
+    #[serde(rename = "pubDate")]
+    pub pub_date: Option<String>,
+    #[serde(rename = "date")]
+    pub dc_date: Option<String>,
+    pub guid: Option<RssText>,
+    pub enclosure: Option<RssEnclosure>,
+    #[serde(rename = "encoded")]
+    pub content_encoded: Option<String>,
+    #[serde(rename = "content", default)]
+    pub media_content: Vec<MediaContent>,
+    #[serde(rename = "thumbnail", default)]
+    pub media_thumbnail: Vec<MediaContent>,
+    #[serde(rename = "category", default)]
+    pub categories: Vec<RssText>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RssEnclosure {
+    #[serde(rename = "@url")]
+    pub url: String,
+    #[serde(rename = "@type")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MediaContent {
+    #[serde(rename = "@url")]
+    pub url: Option<String>,
+    #[serde(rename = "@medium")]
+    pub medium: Option<String>,
+    #[serde(rename = "@type")]
+    pub mime_type: Option<String>,
+}
+
+/// Text node that may carry attributes (`<guid isPermaLink="false">`, `<category domain="…">`).
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct RssText {
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+// --- Atom ---
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AtomFeed {
+    pub title: Option<RssText>,
+    #[serde(rename = "entry", default)]
+    pub entries: Vec<AtomEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AtomEntry {
+    pub id: Option<String>,
+    pub title: Option<RssText>,
+    #[serde(rename = "link", default)]
+    pub links: Vec<AtomLink>,
+    pub summary: Option<RssText>,
+    pub content: Option<RssText>,
+    pub published: Option<String>,
+    pub updated: Option<String>,
+    #[serde(rename = "author", default)]
+    pub authors: Vec<AtomPerson>,
+    #[serde(rename = "thumbnail", default)]
+    pub media_thumbnail: Vec<MediaContent>,
+    #[serde(rename = "category", default)]
+    pub categories: Vec<AtomCategory>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AtomLink {
+    #[serde(rename = "@href")]
+    pub href: String,
+    #[serde(rename = "@rel")]
+    pub rel: Option<String>,
+    #[serde(rename = "@type")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AtomPerson {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AtomCategory {
+    #[serde(rename = "@term")]
+    pub term: Option<String>,
+}
+
+// --- Normalised item handed to the UI ---
+
+#[derive(Debug, Clone)]
+pub struct FeedItem {
+    pub title: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub content: Option<String>,
+    pub image_url: Option<String>,
+    pub published_at: String,
+    pub author: Option<String>,
+    pub source: String,
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RssError {
+    #[error("Network Error: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("Feed Error: The server answered with status {0}.")]
+    Http(u16),
+    #[error("Parse Error: {0}")]
+    Parse(#[from] quick_xml::DeError),
+    #[error("Unsupported Feed: Only RSS 2.0 and Atom feeds are supported.")]
+    UnsupportedFormat,
+    #[error("No feeds configured. Add a feed URL in the settings.")]
+    NoFeeds,
+}
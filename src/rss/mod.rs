@@ -0,0 +1,3 @@
+pub mod data_structures;
+pub mod opml;
+pub mod rss_client;
\ No newline at end of file
@@ -0,0 +1,114 @@
+use quick_xml::escape::escape;
+use serde::Deserialize;
+
+use crate::types::feed_subscription::FeedSubscription;
+
+#[derive(Debug, Deserialize)]
+struct OpmlDocument {
+    body: OpmlBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpmlBody {
+    #[serde(rename = "outline", default)]
+    outlines: Vec<Outline>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Outline {
+    #[serde(rename = "@text")]
+    text: Option<String>,
+    #[serde(rename = "@title")]
+    title: Option<String>,
+    #[serde(rename = "@xmlUrl")]
+    xml_url: Option<String>,
+    #[serde(rename = "outline", default)]
+    children: Vec<Outline>,
+}
+
+pub struct Opml;
+
+impl Opml {
+    /// Read every feed out of an OPML document.
+    /// Outlines without `xmlUrl` are treated as folders for the feeds nested in them.
+    pub fn parse(xml: &str) -> Result<Vec<FeedSubscription>, quick_xml::DeError> {
+        let document: OpmlDocument = quick_xml::de::from_str(xml)?;
+        let mut subscriptions = Vec::new();
+
+        for outline in document.body.outlines {
+            Self::collect(outline, None, &mut subscriptions);
+        }
+
+        Ok(subscriptions)
+    }
+
+    fn collect(outline: Outline, folder: Option<String>, out: &mut Vec<FeedSubscription>) {
+        let name = outline
+            .title
+            .clone()
+            .or(outline.text.clone())
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+
+        match outline.xml_url {
+            Some(url) if !url.trim().is_empty() => {
+                let url = url.trim().to_string();
+                out.push(FeedSubscription::new(
+                    name.unwrap_or_else(|| url.clone()),
+                    url,
+                    folder,
+                ));
+            }
+            _ => {
+                let folder = name.or(folder);
+                for child in outline.children {
+                    Self::collect(child, folder.clone(), out);
+                }
+            }
+        }
+    }
+
+    /// Write subscriptions as an OPML 2.0 document, one outline per folder
+    pub fn write(subscriptions: &[FeedSubscription]) -> String {
+        let mut opml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n  <head>\n    <title>Frost News Subscriptions</title>\n  </head>\n  <body>\n",
+        );
+
+        for subscription in subscriptions.iter().filter(|s| s.folder.is_none()) {
+            opml.push_str(&Self::feed_outline(subscription, 4));
+        }
+
+        let mut folders: Vec<&str> = subscriptions
+            .iter()
+            .filter_map(|s| s.folder.as_deref())
+            .collect();
+        folders.sort();
+        folders.dedup();
+
+        for folder in folders {
+            opml.push_str(&format!(
+                "    <outline text=\"{0}\" title=\"{0}\">\n",
+                escape(folder)
+            ));
+            for subscription in subscriptions
+                .iter()
+                .filter(|s| s.folder.as_deref() == Some(folder))
+            {
+                opml.push_str(&Self::feed_outline(subscription, 6));
+            }
+            opml.push_str("    </outline>\n");
+        }
+
+        opml.push_str("  </body>\n</opml>\n");
+        opml
+    }
+
+    fn feed_outline(subscription: &FeedSubscription, indent: usize) -> String {
+        format!(
+            "{}<outline type=\"rss\" text=\"{1}\" title=\"{1}\" xmlUrl=\"{2}\"/>\n",
+            " ".repeat(indent),
+            escape(subscription.title.as_str()),
+            escape(subscription.url.as_str()),
+        )
+    }
+}
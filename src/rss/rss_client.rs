@@ -0,0 +1,294 @@
+use chrono::DateTime;
+use quick_xml::{Reader, events::Event};
+use reqwest::Client;
+use url::Url;
+
+use crate::rss::data_structures::{
+    AtomEntry, AtomFeed, FeedItem, MediaContent, RssDocument, RssError, RssItem,
+};
+use crate::utils::time_organizer::UITimeOrganiser;
+
+#[derive(Debug, Clone)]
+pub struct RssClient {
+    feeds: Vec<String>,
+    client: Client,
+}
+
+impl RssClient {
+    pub fn new(feeds: Vec<String>) -> Self {
+        Self {
+            feeds,
+            client: Client::new(),
+        }
+    }
+
+    async fn perform_request(&self, url: Url) -> Result<Vec<FeedItem>, RssError> {
+        let response = self
+            .client
+            .get(url)
+            .header("User-Agent", "FrostNews/1.0")
+            .header(
+                "Accept",
+                "application/rss+xml, application/atom+xml, application/xml, text/xml",
+            )
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(RssError::Http(status.as_u16()));
+        }
+
+        let body = response.text().await?;
+        Self::parse_feed(&body)
+    }
+
+    /// Fetch and parse a single RSS 2.0 or Atom feed
+    pub async fn fetch_feed(&self, feed_url: &str) -> Result<Vec<FeedItem>, RssError> {
+        let url = Url::parse(feed_url.trim()).map_err(|_| RssError::UnsupportedFormat)?;
+        self.perform_request(url).await
+    }
+
+    /// Fetch every configured feed, newest items first.
+    /// A broken feed is skipped; an error is only returned when all of them fail.
+    pub async fn fetch_all(&self) -> Result<Vec<FeedItem>, RssError> {
+        if self.feeds.is_empty() {
+            return Err(RssError::NoFeeds);
+        }
+
+        let requests = self.feeds.iter().map(|feed| self.fetch_feed(feed));
+        let results = futures::future::join_all(requests).await;
+
+        let mut items = Vec::new();
+        let mut last_error = None;
+        let mut any_succeeded = false;
+
+        for (feed, result) in self.feeds.iter().zip(results) {
+            match result {
+                Ok(mut feed_items) => {
+                    any_succeeded = true;
+                    items.append(&mut feed_items);
+                }
+                Err(e) => {
+                    eprintln!("Feed {} failed: {}", feed, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        if !any_succeeded {
+            return Err(last_error.unwrap_or(RssError::NoFeeds));
+        }
+
+        let time_organiser = UITimeOrganiser::new();
+        items.sort_by_key(|item| {
+            std::cmp::Reverse(time_organiser.parse_datetime(Some(item.published_at.clone())))
+        });
+        Ok(items)
+    }
+
+    pub fn parse_feed(xml: &str) -> Result<Vec<FeedItem>, RssError> {
+        match Self::root_element(xml).as_deref() {
+            Some("rss") => {
+                let document: RssDocument = quick_xml::de::from_str(xml)?;
+                let source = document
+                    .channel
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| document.channel.link.clone().unwrap_or_default());
+
+                Ok(document
+                    .channel
+                    .items
+                    .into_iter()
+                    .filter_map(|item| Self::from_rss_item(item, &source))
+                    .collect())
+            }
+            Some("feed") => {
+                let feed: AtomFeed = quick_xml::de::from_str(xml)?;
+                let source = feed
+                    .title
+                    .as_ref()
+                    .map(|t| t.value.trim().to_string())
+                    .unwrap_or_default();
+
+                Ok(feed
+                    .entries
+                    .into_iter()
+                    .filter_map(|entry| Self::from_atom_entry(entry, &source))
+                    .collect())
+            }
+            _ => Err(RssError::UnsupportedFormat),
+        }
+    }
+
+    fn root_element(xml: &str) -> Option<String> {
+        let mut reader = Reader::from_str(xml);
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                    return Some(String::from_utf8_lossy(e.local_name().as_ref()).to_string());
+                }
+                Ok(Event::Eof) | Err(_) => return None,
+                _ => {}
+            }
+        }
+    }
+
+    fn from_rss_item(item: RssItem, source: &str) -> Option<FeedItem> {
+        let url = item
+            .link
+            .clone()
+            .or_else(|| item.guid.clone().map(|g| g.value))
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())?;
+
+        let image_url = item
+            .enclosure
+            .as_ref()
+            .filter(|e| e.mime_type.as_deref().unwrap_or("image/").starts_with("image/"))
+            .map(|e| e.url.clone())
+            .or_else(|| Self::media_image(&item.media_content))
+            .or_else(|| Self::media_image(&item.media_thumbnail))
+            .or_else(|| item.description.as_deref().and_then(Self::first_image))
+            .or_else(|| item.content_encoded.as_deref().and_then(Self::first_image));
+
+        let published_at = item
+            .pub_date
+            .as_deref()
+            .or(item.dc_date.as_deref())
+            .map(Self::normalise_date)
+            .unwrap_or_default();
+
+        Some(FeedItem {
+            title: item
+                .title
+                .as_deref()
+                .map(Self::strip_html)
+                .unwrap_or_default(),
+            url,
+            description: item.description.as_deref().map(Self::strip_html),
+            content: item.content_encoded.as_deref().map(Self::strip_html),
+            image_url,
+            published_at,
+            author: item.creator.or(item.author),
+            source: source.to_string(),
+            categories: item
+                .categories
+                .into_iter()
+                .map(|c| c.value.trim().to_string())
+                .collect(),
+        })
+    }
+
+    fn from_atom_entry(entry: AtomEntry, source: &str) -> Option<FeedItem> {
+        let url = entry
+            .links
+            .iter()
+            .find(|l| matches!(l.rel.as_deref(), None | Some("alternate")))
+            .or_else(|| entry.links.first())
+            .map(|l| l.href.trim().to_string())
+            .filter(|l| !l.is_empty())?;
+
+        let image_url = entry
+            .links
+            .iter()
+            .find(|l| {
+                l.rel.as_deref() == Some("enclosure")
+                    && l.mime_type.as_deref().unwrap_or("").starts_with("image/")
+            })
+            .map(|l| l.href.clone())
+            .or_else(|| Self::media_image(&entry.media_thumbnail))
+            .or_else(|| {
+                entry
+                    .content
+                    .as_ref()
+                    .and_then(|c| Self::first_image(&c.value))
+            });
+
+        let published_at = entry
+            .published
+            .as_deref()
+            .or(entry.updated.as_deref())
+            .map(Self::normalise_date)
+            .unwrap_or_default();
+
+        Some(FeedItem {
+            title: entry
+                .title
+                .as_ref()
+                .map(|t| Self::strip_html(&t.value))
+                .unwrap_or_default(),
+            url,
+            description: entry.summary.as_ref().map(|s| Self::strip_html(&s.value)),
+            content: entry.content.as_ref().map(|c| Self::strip_html(&c.value)),
+            image_url,
+            published_at,
+            author: entry.authors.into_iter().find_map(|a| a.name),
+            source: source.to_string(),
+            categories: entry.categories.into_iter().filter_map(|c| c.term).collect(),
+        })
+    }
+
+    fn media_image(media: &[MediaContent]) -> Option<String> {
+        media
+            .iter()
+            .find(|m| {
+                m.medium.as_deref().map_or(true, |medium| medium == "image")
+                    && m.mime_type.as_deref().map_or(true, |t| t.starts_with("image/"))
+            })
+            .and_then(|m| m.url.clone())
+    }
+
+    /// RSS uses RFC 2822 dates; convert them to RFC 3339 like the other providers
+    fn normalise_date(raw: &str) -> String {
+        let raw = raw.trim();
+        DateTime::parse_from_rfc2822(raw)
+            .or_else(|_| DateTime::parse_from_rfc3339(raw))
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|_| raw.to_string())
+    }
+
+    /// Pull the first `<img src="…">` out of an HTML fragment
+    fn first_image(html: &str) -> Option<String> {
+        let img = html.find("<img")?;
+        let rest = &html[img..];
+        let src = rest.find("src=")? + 4;
+        let quote = rest[src..].chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value = &rest[src + 1..];
+        let end = value.find(quote)?;
+        Some(value[..end].to_string())
+    }
+
+    /// Feed descriptions are usually HTML; labels want plain text
+    fn strip_html(html: &str) -> String {
+        let mut text = String::with_capacity(html.len());
+        let mut in_tag = false;
+
+        for c in html.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' if in_tag => {
+                    in_tag = false;
+                    text.push(' ');
+                }
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+
+        text.replace("&nbsp;", " ")
+            .replace("&amp;", "&")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&#8217;", "’")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+use rusqlite::{Connection, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::stored_article::StoredArticle;
+
+/// Articles kept per section, as many as an offline page shows
+const SECTION_LIMIT: usize = 100;
+
+#[derive(Debug)]
+pub struct ArticleStoreService {
+    conn: Connection,
+}
+
+impl ArticleStoreService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Stores a batch of fetched articles, refreshing the ones already known for the section.
+    /// Sections it touches are trimmed back to their newest [`SECTION_LIMIT`] articles.
+    pub fn save_articles(&mut self, articles: Vec<StoredArticle>) -> rusqlite::Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut count = 0;
+        let sections: BTreeSet<String> = articles
+            .iter()
+            .map(|article| article.section.clone())
+            .collect();
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO article_store
+                (section, url, provider, title, description, content, image_url, published_at, source, author, fetched_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            )?;
+            for article in articles {
+                count += stmt.execute(params![
+                    article.section,
+                    article.url,
+                    article.provider,
+                    article.title,
+                    article.description,
+                    article.content,
+                    article.image_url,
+                    article.published_at,
+                    article.source,
+                    article.author,
+                    article.fetched_at
+                ])?;
+            }
+
+            let mut prune = tx.prepare(
+                "DELETE FROM article_store
+                WHERE section = ?1 AND rowid NOT IN (
+                    SELECT rowid FROM article_store
+                    WHERE section = ?1
+                    ORDER BY fetched_at DESC, published_at DESC
+                    LIMIT ?2
+                )",
+            )?;
+            for section in sections {
+                prune.execute(params![section, SECTION_LIMIT as i64])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// The most recently fetched articles of a section, newest fetch first
+    pub fn get_section_articles(
+        &self,
+        section: &str,
+        limit: usize,
+    ) -> rusqlite::Result<Vec<StoredArticle>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT section, url, provider, title, description, content, image_url, published_at, source, author, fetched_at
+             FROM article_store
+             WHERE section = ?1
+             ORDER BY fetched_at DESC, published_at DESC
+             LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![section, limit as i64], |row| {
+            Ok(StoredArticle {
+                section: row.get(0)?,
+                url: row.get(1)?,
+                provider: row.get(2)?,
+                title: row.get(3)?,
+                description: row.get(4)?,
+                content: row.get(5)?,
+                image_url: row.get(6)?,
+                published_at: row.get(7)?,
+                source: row.get(8)?,
+                author: row.get(9)?,
+                fetched_at: row.get(10)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            if let Ok(article) = row {
+                results.push(article);
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// Shared handle the fetch service uses to fill the store and read it back when offline
+#[derive(Debug, Clone)]
+pub struct ArticleStore {
+    service: Arc<Mutex<ArticleStoreService>>,
+}
+
+impl ArticleStore {
+    pub fn new(service: ArticleStoreService) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(service)),
+        }
+    }
+
+    pub fn save(&self, articles: Vec<StoredArticle>) {
+        if let Ok(mut service) = self.service.lock() {
+            if let Err(e) = service.save_articles(articles) {
+                eprintln!("Failed to store articles: {}", e);
+            }
+        }
+    }
+
+    /// Latest stored articles for a section, with the time they were fetched
+    pub fn latest(&self, section: &str) -> Option<(Vec<StoredArticle>, i64)> {
+        let service = self.service.lock().ok()?;
+        let articles = service.get_section_articles(section, SECTION_LIMIT).ok()?;
+        let fetched_at = articles.iter().map(|article| article.fetched_at).max()?;
+        Some((articles, fetched_at))
+    }
+}
@@ -0,0 +1 @@
+pub mod article_store;
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rusqlite::{Connection, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::{bookmark::Bookmark, persistent_articel::PersistentArticle};
+
+/// Saving an article twice keeps the first save date
+const INSERT_BOOKMARK: &str = "INSERT OR IGNORE INTO bookmarks
+    (id, title, url, description, content, published_at, image_url, visit_time, saved_at)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+
+#[derive(Debug)]
+pub struct BookmarkService {
+    conn: Connection,
+}
+
+impl BookmarkService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    pub fn save_bookmark(&self, article: PersistentArticle) -> rusqlite::Result<()> {
+        let now = chrono::Utc::now().timestamp();
+
+        self.conn.execute(
+            INSERT_BOOKMARK,
+            params![
+                article.id,
+                article.title,
+                article.url,
+                article.description,
+                article.content,
+                article.published_at,
+                article.image_url,
+                article.visit_time,
+                now
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Saves several articles at once, all or none. Returns how many were not saved before.
+    pub fn save_bookmarks(
+        &mut self,
+        articles: &[Arc<PersistentArticle>],
+    ) -> rusqlite::Result<usize> {
+        let now = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+        {
+            let mut stmt = tx.prepare(INSERT_BOOKMARK)?;
+            for article in articles {
+                added += stmt.execute(params![
+                    article.id,
+                    article.title,
+                    article.url,
+                    article.description,
+                    article.content,
+                    article.published_at,
+                    article.image_url,
+                    article.visit_time,
+                    now
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+
+    pub fn get_all_bookmarks(&self) -> rusqlite::Result<Vec<Bookmark>> {
+        let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT bookmark_id, tag FROM bookmark_tags ORDER BY tag COLLATE NOCASE")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            for (id, tag) in rows.flatten() {
+                tags.entry(id).or_default().push(tag);
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, url, description, content, published_at, image_url, visit_time, saved_at, folder
+             FROM bookmarks
+             ORDER BY saved_at DESC",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            let id: String = row.get(0)?;
+            Ok(Bookmark {
+                tags: tags.remove(&id).unwrap_or_default(),
+                article: Arc::new(PersistentArticle {
+                    id,
+                    title: row.get(1)?,
+                    url: row.get(2)?,
+                    description: row.get(3)?,
+                    content: row.get(4)?,
+                    published_at: row.get(5)?,
+                    image_url: row.get(6)?,
+                    visit_time: row.get(7)?,
+                }),
+                saved_at: row.get(8)?,
+                folder: row.get(9)?,
+            })
+        })?;
+
+        rows.collect::<Result<Vec<_>>>()
+    }
+
+    pub fn delete_bookmark(&mut self, id: String) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM bookmark_tags WHERE bookmark_id = ?1", params![id])?;
+        tx.execute("DELETE FROM bookmarks WHERE id = ?1", params![id])?;
+        tx.commit()
+    }
+
+    /// Moves a bookmark to `folder` (`None` takes it out of any folder) and replaces its tags
+    pub fn organise_bookmark(
+        &mut self,
+        id: String,
+        folder: Option<String>,
+        tags: Vec<String>,
+    ) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "UPDATE bookmarks SET folder = ?1 WHERE id = ?2",
+            params![folder, id],
+        )?;
+        tx.execute("DELETE FROM bookmark_tags WHERE bookmark_id = ?1", params![id])?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag) VALUES (?1, ?2)",
+            )?;
+            for tag in tags {
+                stmt.execute(params![id, tag])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// Merges imported bookmarks by id. A bookmark already here keeps its save date and
+    /// folder, tags from both sides are kept. Returns how many bookmarks were new.
+    pub fn import_bookmarks(&mut self, bookmarks: Vec<Bookmark>) -> rusqlite::Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+        for bookmark in bookmarks {
+            let article = &bookmark.article;
+            added += tx.execute(
+                "INSERT OR IGNORE INTO bookmarks
+                (id, title, url, description, content, published_at, image_url, visit_time, saved_at, folder)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    article.id,
+                    article.title,
+                    article.url,
+                    article.description,
+                    article.content,
+                    article.published_at,
+                    article.image_url,
+                    article.visit_time,
+                    bookmark.saved_at,
+                    bookmark.folder
+                ],
+            )?;
+            tx.execute(
+                "UPDATE bookmarks SET folder = ?2 WHERE id = ?1 AND folder IS NULL",
+                params![article.id, bookmark.folder],
+            )?;
+            for tag in &bookmark.tags {
+                tx.execute(
+                    "INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag) VALUES (?1, ?2)",
+                    params![article.id, tag],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+
+    /// Renames a tag on every bookmark. Renaming to a tag that is already in use merges the
+    /// two, and an empty name removes the tag.
+    pub fn rename_tag(&mut self, from: String, to: String) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        if !to.is_empty() {
+            tx.execute(
+                "INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag)
+                SELECT bookmark_id, ?2 FROM bookmark_tags WHERE tag = ?1",
+                params![from, to],
+            )?;
+        }
+        tx.execute("DELETE FROM bookmark_tags WHERE tag = ?1 AND tag != ?2", params![from, to])?;
+        tx.commit()
+    }
+}
@@ -0,0 +1,68 @@
+use rusqlite::{Connection, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::custom_endpoint::CustomEndpoint;
+
+#[derive(Debug)]
+pub struct EndpointService {
+    conn: Connection,
+}
+
+impl EndpointService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Inserts a new endpoint (id 0) or replaces the stored definition of an existing one
+    pub fn save_endpoint(&self, endpoint: CustomEndpoint) -> rusqlite::Result<()> {
+        let definition = serde_json::to_string(&endpoint)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+        if endpoint.id == 0 {
+            self.conn.execute(
+                "INSERT INTO custom_endpoints (name, definition, added_at) VALUES (?1, ?2, ?3)",
+                params![endpoint.name, definition, chrono::Utc::now().timestamp()],
+            )?;
+        } else {
+            self.conn.execute(
+                "UPDATE custom_endpoints SET name = ?1, definition = ?2 WHERE id = ?3",
+                params![endpoint.name, definition, endpoint.id],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn get_all_endpoints(&self) -> rusqlite::Result<Vec<CustomEndpoint>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, definition FROM custom_endpoints ORDER BY name COLLATE NOCASE",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            let id: i64 = row.get(0)?;
+            let definition: String = row.get(1)?;
+            Ok((id, definition))
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            if let Ok((id, definition)) = row {
+                match serde_json::from_str::<CustomEndpoint>(&definition) {
+                    Ok(mut endpoint) => {
+                        endpoint.id = id;
+                        results.push(endpoint);
+                    }
+                    Err(e) => eprintln!("Skipping unreadable endpoint {}: {}", id, e),
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn delete_endpoint(&self, id: i64) -> rusqlite::Result<()> {
+        self.conn
+            .execute("DELETE FROM custom_endpoints WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+}
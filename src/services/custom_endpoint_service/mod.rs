@@ -0,0 +1 @@
+pub mod endpoints;
\ No newline at end of file
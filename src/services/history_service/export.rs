@@ -0,0 +1,361 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    bookmark::Bookmark, history_record::HistoryRecord, persistent_articel::PersistentArticle,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// A saved article as written to an archive
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedEntry {
+    #[serde(flatten)]
+    article: PersistentArticle,
+    saved_at: i64,
+    #[serde(default)]
+    folder: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// The JSON archive, the only format that can be imported again
+#[derive(Debug, Serialize, Deserialize)]
+struct Archive {
+    exported_at: i64,
+    #[serde(default)]
+    history: Vec<HistoryRecord>,
+    #[serde(default)]
+    saved: Vec<SavedEntry>,
+}
+
+/// Writes the reading history and saved articles in `format`
+pub fn write(format: ExportFormat, history: &[HistoryRecord], saved: &[Bookmark]) -> String {
+    match format {
+        ExportFormat::Json => write_json(history, saved),
+        ExportFormat::Csv => write_csv(history, saved),
+        ExportFormat::Html => write_html(history, saved),
+    }
+}
+
+/// Reads a JSON archive. Ids are worked out again from the urls so entries from another
+/// machine line up with the ones already stored here.
+pub fn read_json(json: &str) -> Result<(Vec<HistoryRecord>, Vec<Bookmark>), serde_json::Error> {
+    let archive: Archive = serde_json::from_str(json)?;
+
+    let history = archive
+        .history
+        .into_iter()
+        .map(|mut record| {
+            record.article.id = PersistentArticle::id_for(&record.article.url);
+            record
+        })
+        .collect();
+
+    let saved = archive
+        .saved
+        .into_iter()
+        .map(|mut entry| {
+            entry.article.id = PersistentArticle::id_for(&entry.article.url);
+            Bookmark {
+                article: Arc::new(entry.article),
+                saved_at: entry.saved_at,
+                folder: entry.folder,
+                tags: entry.tags,
+            }
+        })
+        .collect();
+
+    Ok((history, saved))
+}
+
+fn write_json(history: &[HistoryRecord], saved: &[Bookmark]) -> String {
+    let archive = Archive {
+        exported_at: Utc::now().timestamp(),
+        history: history.to_vec(),
+        saved: saved
+            .iter()
+            .map(|bookmark| SavedEntry {
+                article: (*bookmark.article).clone(),
+                saved_at: bookmark.saved_at,
+                folder: bookmark.folder.clone(),
+                tags: bookmark.tags.clone(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&archive).unwrap_or_default()
+}
+
+fn write_csv(history: &[HistoryRecord], saved: &[Bookmark]) -> String {
+    let mut csv =
+        String::from("kind,title,url,description,published_at,visited_at,saved_at,folder,tags\n");
+
+    for record in history {
+        let article = &record.article;
+        let row = [
+            "history",
+            &article.title,
+            &article.url,
+            article.description.as_deref().unwrap_or(""),
+            &article.published_at,
+            &rfc3339(article.visit_time),
+            "",
+            "",
+            "",
+        ];
+        push_csv_row(&mut csv, &row);
+    }
+
+    for bookmark in saved {
+        let article = &bookmark.article;
+        let row = [
+            "saved",
+            &article.title,
+            &article.url,
+            article.description.as_deref().unwrap_or(""),
+            &article.published_at,
+            &rfc3339(article.visit_time),
+            &rfc3339(bookmark.saved_at),
+            bookmark.folder.as_deref().unwrap_or(""),
+            &bookmark.tags.join(", "),
+        ];
+        push_csv_row(&mut csv, &row);
+    }
+
+    csv
+}
+
+fn push_csv_row(csv: &mut String, fields: &[&str]) {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect();
+
+    csv.push_str(&fields.join(","));
+    csv.push('\n');
+}
+
+/// Netscape bookmark file, which every browser can import. Saved articles keep their
+/// folders, the history goes into a folder of its own.
+fn write_html(history: &[HistoryRecord], saved: &[Bookmark]) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n\
+         <META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">\n\
+         <TITLE>FrostNews</TITLE>\n\
+         <H1>FrostNews</H1>\n\
+         <DL><p>\n",
+    );
+
+    let mut folders: BTreeMap<Option<&str>, Vec<&Bookmark>> = BTreeMap::new();
+    for bookmark in saved {
+        folders
+            .entry(bookmark.folder.as_deref())
+            .or_default()
+            .push(bookmark);
+    }
+
+    if !saved.is_empty() {
+        html.push_str("    <DT><H3>Saved</H3>\n    <DL><p>\n");
+        for (folder, bookmarks) in &folders {
+            let indent = match folder {
+                Some(folder) => {
+                    html.push_str(&format!(
+                        "        <DT><H3>{}</H3>\n        <DL><p>\n",
+                        escape_html(folder)
+                    ));
+                    "            "
+                }
+                None => "        ",
+            };
+            for bookmark in bookmarks {
+                html.push_str(&format!(
+                    "{}<DT><A HREF=\"{}\" ADD_DATE=\"{}\" TAGS=\"{}\">{}</A>\n",
+                    indent,
+                    escape_html(&bookmark.article.url),
+                    bookmark.saved_at,
+                    escape_html(&bookmark.tags.join(",")),
+                    escape_html(&bookmark.article.title),
+                ));
+            }
+            if folder.is_some() {
+                html.push_str("        </DL><p>\n");
+            }
+        }
+        html.push_str("    </DL><p>\n");
+    }
+
+    if !history.is_empty() {
+        html.push_str("    <DT><H3>History</H3>\n    <DL><p>\n");
+        for record in history {
+            html.push_str(&format!(
+                "        <DT><A HREF=\"{}\" ADD_DATE=\"{}\" LAST_VISIT=\"{}\">{}</A>\n",
+                escape_html(&record.article.url),
+                record.first_visit(),
+                record.article.visit_time,
+                escape_html(&record.article.title),
+            ));
+        }
+        html.push_str("    </DL><p>\n");
+    }
+
+    html.push_str("</DL><p>\n");
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn rfc3339(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .map(|time| time.to_rfc3339())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(title: &str, url: &str, visit_time: i64) -> PersistentArticle {
+        PersistentArticle {
+            id: "stale id".to_string(),
+            title: title.to_string(),
+            url: url.to_string(),
+            description: None,
+            content: None,
+            published_at: "2024-01-01T00:00:00Z".to_string(),
+            image_url: String::new(),
+            visit_time,
+        }
+    }
+
+    fn record(title: &str, url: &str) -> HistoryRecord {
+        HistoryRecord {
+            article: article(title, url, 1_700_000_000),
+            first_visit: Some(1_600_000_000),
+            visit_count: 3,
+            read_secs: 240,
+        }
+    }
+
+    #[test]
+    fn quotes_csv_fields_that_need_it() {
+        let csv = write(
+            ExportFormat::Csv,
+            &[record(
+                "Rust, \"fast\" and\nsafe",
+                "https://example.com/rust",
+            )],
+            &[],
+        );
+
+        let row = csv.split_once('\n').unwrap().1;
+        assert!(
+            row.starts_with("history,\"Rust, \"\"fast\"\" and\nsafe\",https://example.com/rust,"),
+            "{}",
+            row
+        );
+    }
+
+    #[test]
+    fn escapes_html_in_bookmark_files() {
+        let saved = Bookmark {
+            article: Arc::new(article(
+                "<b>Tom & \"Jerry\"</b>",
+                "https://example.com/?a=1&b=2",
+                0,
+            )),
+            saved_at: 1_700_000_000,
+            folder: Some("Cats & Mice".to_string()),
+            tags: vec!["<script>".to_string()],
+        };
+
+        let html = write(ExportFormat::Html, &[], &[saved]);
+
+        assert!(html.contains("<H3>Cats &amp; Mice</H3>"));
+        assert!(html.contains("HREF=\"https://example.com/?a=1&amp;b=2\""));
+        assert!(html.contains("TAGS=\"&lt;script&gt;\""));
+        assert!(html.contains(">&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;</A>"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn json_round_trips() {
+        let saved = Bookmark {
+            article: Arc::new(article("Saved", "https://example.com/saved", 1_650_000_000)),
+            saved_at: 1_700_000_500,
+            folder: Some("Later".to_string()),
+            tags: vec!["rust".to_string(), "news".to_string()],
+        };
+
+        let json = write(
+            ExportFormat::Json,
+            &[record("Read", "https://example.com/read")],
+            &[saved],
+        );
+        let (history, saved) = read_json(&json).unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].article.id,
+            PersistentArticle::id_for("https://example.com/read")
+        );
+        assert_eq!(history[0].article.title, "Read");
+        assert_eq!(history[0].article.visit_time, 1_700_000_000);
+        assert_eq!(history[0].first_visit, Some(1_600_000_000));
+        assert_eq!(history[0].visit_count, 3);
+        assert_eq!(history[0].read_secs, 240);
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(
+            saved[0].article.id,
+            PersistentArticle::id_for("https://example.com/saved")
+        );
+        assert_eq!(saved[0].saved_at, 1_700_000_500);
+        assert_eq!(saved[0].folder.as_deref(), Some("Later"));
+        assert_eq!(saved[0].tags, ["rust", "news"]);
+    }
+
+    #[test]
+    fn reads_archives_without_visit_statistics() {
+        let json = r#"{"exported_at": 1, "history": [{
+            "id": "x", "title": "Old", "url": "https://example.com/old", "description": null,
+            "content": null, "published_at": "", "image_url": "", "visit_time": 1500000000
+        }]}"#;
+
+        let (history, saved) = read_json(json).unwrap();
+
+        assert!(saved.is_empty());
+        assert_eq!(history[0].first_visit(), 1_500_000_000);
+        assert_eq!(history[0].visit_count, 1);
+        assert_eq!(history[0].read_secs, 0);
+    }
+}
@@ -1,41 +1,60 @@
 use std::sync::Arc;
 
-use rusqlite::{Connection, Result, params};
+use chrono::NaiveDate;
+use rusqlite::{Connection, Result, params, params_from_iter, types::Value};
 use sha2::{Digest, Sha256};
 
-use crate::types::{news_article::NewsArticle, persistent_articel::PersistentArticle};
+use crate::services::history_service::migrations;
+use crate::types::{
+    history_filter::HistoryFilter, history_record::HistoryRecord,
+    history_retention::HistoryRetention, news_article::NewsArticle,
+    persistent_articel::PersistentArticle, reading_stats::ReadingStats, visit_origin::VisitOrigin,
+};
 
 #[derive(Debug)]
 pub struct HistoryService {
     conn: Connection,
+    last_batch: i64,
 }
 
 impl HistoryService {
     pub fn new() -> Result<Self> {
-        let conn = Connection::open("history.db")?;
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS article_history (
-                    id TEXT PRIMARY KEY,
-                    title TEXT NOT NULL,
-                    url TEXT NOT NULL,
-                    description TEXT,     
-                    content TEXT,       
-                    published_at TEXT,   
-                    image_url TEXT,
-                    visit_time INTEGER   
-                )",
-            [],
-        )?;
-        Ok(Self { conn })
-    }
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
 
-    pub fn save_to_history(&self, article: PersistentArticle) -> rusqlite::Result<()> {
-        let now = chrono::Utc::now().timestamp();
+        Ok(Self {
+            conn,
+            last_batch: 0,
+        })
+    }
 
+    /// Records a visit. Visiting an entry again counts the visit and moves the last one, the
+    /// first visit and reading time so far are kept.
+    pub fn save_to_history(
+        &self,
+        article: PersistentArticle,
+        origin: &VisitOrigin,
+    ) -> rusqlite::Result<()> {
+        // an entry still waiting to be purged starts over, it was deleted after all
         self.conn.execute(
-            "INSERT OR REPLACE INTO article_history 
-            (id, title, url, description, content, published_at, image_url, visit_time) 
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            "INSERT INTO article_history
+            (id, title, url, description, content, published_at, image_url, visit_time,
+             first_visit, source, section)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8, ?9, ?10)
+            ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                content = excluded.content,
+                published_at = excluded.published_at,
+                image_url = excluded.image_url,
+                visit_time = excluded.visit_time,
+                visit_count = CASE WHEN deleted_at IS NULL THEN visit_count + 1 ELSE 1 END,
+                first_visit = CASE WHEN deleted_at IS NULL
+                    THEN first_visit ELSE excluded.first_visit END,
+                read_secs = CASE WHEN deleted_at IS NULL THEN read_secs ELSE 0 END,
+                source = COALESCE(excluded.source, source),
+                section = COALESCE(excluded.section, section),
+                deleted_at = NULL",
             params![
                 article.id,
                 article.title,
@@ -44,21 +63,121 @@ impl HistoryService {
                 article.content,
                 article.published_at,
                 article.image_url,
-                article.visit_time
+                article.visit_time,
+                origin.source,
+                origin.section
             ],
         )?;
         Ok(())
     }
 
-    pub fn get_all_history(&self) -> rusqlite::Result<Vec<Arc<PersistentArticle>>> {
+    /// Adds time spent reading an entry, in seconds
+    pub fn add_read_time(&self, id: &str, secs: i64) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "UPDATE article_history SET read_secs = read_secs + ?2
+            WHERE id = ?1 AND deleted_at IS NULL",
+            params![id, secs],
+        )?;
+        Ok(())
+    }
+
+    /// Totals, the days with reading and the `top` most read sources and sections
+    pub fn reading_stats(&self, top: usize) -> rusqlite::Result<ReadingStats> {
+        let (articles, visits, read_secs) = self.conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(visit_count), 0), COALESCE(SUM(read_secs), 0)
+             FROM article_history WHERE deleted_at IS NULL",
+            [],
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get(1)?, row.get(2)?)),
+        )?;
+
+        // only the first and last visit of an entry are kept, so rereads in between are missing
         let mut stmt = self.conn.prepare(
-            "SELECT id, title, url, description, content, published_at, image_url, visit_time
-             FROM article_history 
-             ORDER BY visit_time DESC 
-             LIMIT 10", // Limit for sidebar performance
+            "SELECT day, COUNT(DISTINCT id) FROM (
+                SELECT id, date(first_visit, 'unixepoch', 'localtime') AS day
+                FROM article_history WHERE deleted_at IS NULL
+                UNION
+                SELECT id, date(visit_time, 'unixepoch', 'localtime')
+                FROM article_history WHERE deleted_at IS NULL
+             )
+             WHERE day IS NOT NULL
+             GROUP BY day
+             ORDER BY day",
         )?;
+        let per_day = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+            })?
+            .filter_map(|row| row.ok())
+            .filter_map(|(day, count)| {
+                NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+                    .ok()
+                    .map(|day| (day, count))
+            })
+            .collect();
+
+        Ok(ReadingStats {
+            articles,
+            visits,
+            read_secs,
+            per_day,
+            top_sources: self.most_read("source", top)?,
+            top_sections: self.most_read("section", top)?,
+        })
+    }
+
+    /// Values of `column` with the most entries, most first
+    fn most_read(&self, column: &str, limit: usize) -> rusqlite::Result<Vec<(String, usize)>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {column}, COUNT(*) AS read FROM article_history
+             WHERE deleted_at IS NULL AND {column} IS NOT NULL AND {column} != ''
+             GROUP BY {column}
+             ORDER BY read DESC, {column}
+             LIMIT ?1"
+        ))?;
 
-        let rows = stmt.query_map([], |row| {
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            Ok((row.get(0)?, row.get::<_, i64>(1)? as usize))
+        })?;
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+
+    /// One page of the history matching `filter`, most recent visit first
+    pub fn search_history(
+        &self,
+        filter: &HistoryFilter,
+        limit: usize,
+        offset: usize,
+    ) -> rusqlite::Result<Vec<Arc<PersistentArticle>>> {
+        let mut conditions = vec!["deleted_at IS NULL"];
+        let mut values: Vec<Value> = Vec::new();
+
+        if let Some(expression) = filter.match_expression() {
+            conditions.push(
+                "id IN (SELECT id FROM article_history_fts WHERE article_history_fts MATCH ?)",
+            );
+            values.push(Value::Text(expression));
+        }
+        if let Some(since) = filter.since {
+            conditions.push("visit_time >= ?");
+            values.push(Value::Integer(since));
+        }
+        if let Some(until) = filter.until {
+            conditions.push("visit_time < ?");
+            values.push(Value::Integer(until));
+        }
+        values.push(Value::Integer(limit as i64));
+        values.push(Value::Integer(offset as i64));
+
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, title, url, description, content, published_at, image_url, visit_time
+             FROM article_history
+             WHERE {}
+             ORDER BY visit_time DESC
+             LIMIT ? OFFSET ?",
+            conditions.join(" AND ")
+        ))?;
+
+        let rows = stmt.query_map(params_from_iter(values), |row| {
             Ok(PersistentArticle {
                 id: row.get(0)?,
                 title: row.get(1)?,
@@ -80,14 +199,491 @@ impl HistoryService {
         Ok(results)
     }
 
-    pub fn delete_entry(&self, id: String) -> rusqlite::Result<()> {
-        self.conn
-            .execute("DELETE FROM article_history WHERE id = ?1", params![id])?;
-        Ok(())
+    /// Every entry with its visit statistics, most recent visit first
+    pub fn get_all_records(&self) -> rusqlite::Result<Vec<HistoryRecord>> {
+        self.records("1 = 1", Vec::new())
     }
 
-    pub fn clear_all_history(&self) -> rusqlite::Result<()> {
-        self.conn.execute("DELETE FROM article_history", [])?;
-        Ok(())
+    /// The entries with the given ids and their visit statistics, most recent visit first
+    pub fn get_records(&self, ids: &[String]) -> rusqlite::Result<Vec<HistoryRecord>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let values = ids.iter().map(|id| Value::Text(id.clone())).collect();
+        self.records(&format!("id IN ({})", placeholders), values)
+    }
+
+    fn records(&self, condition: &str, values: Vec<Value>) -> rusqlite::Result<Vec<HistoryRecord>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, title, url, description, content, published_at, image_url, visit_time,
+                first_visit, visit_count, read_secs
+             FROM article_history
+             WHERE deleted_at IS NULL AND {}
+             ORDER BY visit_time DESC",
+            condition
+        ))?;
+
+        let rows = stmt.query_map(params_from_iter(values), |row| {
+            Ok(HistoryRecord {
+                article: PersistentArticle {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    url: row.get(2)?,
+                    description: row.get(3)?,
+                    content: row.get(4)?,
+                    published_at: row.get(5)?,
+                    image_url: row.get(6)?,
+                    visit_time: row.get(7)?,
+                },
+                first_visit: row.get(8)?,
+                visit_count: row.get(9)?,
+                read_secs: row.get(10)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    /// Merges imported entries by id. An entry already here keeps its visit unless the
+    /// imported one is newer, one waiting to be purged is replaced so the purge cannot take
+    /// the import with it. Either way the earlier first visit and the larger visit count and
+    /// reading time are kept, so moving between machines does not change the statistics.
+    /// Returns how many entries were added or updated.
+    pub fn import_history(&mut self, records: Vec<HistoryRecord>) -> rusqlite::Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut changed = 0;
+        {
+            let mut upsert = tx.prepare(
+                "INSERT INTO article_history
+                (id, title, url, description, content, published_at, image_url, visit_time,
+                 first_visit, visit_count, read_secs)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+                ON CONFLICT(id) DO UPDATE SET
+                    title = excluded.title,
+                    description = excluded.description,
+                    content = excluded.content,
+                    published_at = excluded.published_at,
+                    image_url = excluded.image_url,
+                    visit_time = excluded.visit_time,
+                    visit_count = CASE WHEN deleted_at IS NULL THEN visit_count ELSE 1 END,
+                    first_visit = CASE WHEN deleted_at IS NULL
+                        THEN first_visit ELSE excluded.first_visit END,
+                    read_secs = CASE WHEN deleted_at IS NULL THEN read_secs ELSE 0 END,
+                    deleted_at = NULL
+                WHERE article_history.deleted_at IS NOT NULL
+                    OR excluded.visit_time > article_history.visit_time",
+            )?;
+            let mut merge = tx.prepare(
+                "UPDATE article_history SET
+                    first_visit = MIN(COALESCE(first_visit, ?2), ?2),
+                    visit_count = MAX(visit_count, ?3),
+                    read_secs = MAX(read_secs, ?4)
+                WHERE id = ?1 AND (first_visit IS NULL OR first_visit > ?2
+                    OR visit_count < ?3 OR read_secs < ?4)",
+            )?;
+            for record in records {
+                let article = &record.article;
+                let upserted = upsert.execute(params![
+                    article.id,
+                    article.title,
+                    article.url,
+                    article.description,
+                    article.content,
+                    article.published_at,
+                    article.image_url,
+                    article.visit_time,
+                    record.first_visit(),
+                    record.visit_count,
+                    record.read_secs
+                ])?;
+                let merged = merge.execute(params![
+                    article.id,
+                    record.first_visit(),
+                    record.visit_count,
+                    record.read_secs
+                ])?;
+                changed += (upserted + merged).min(1);
+            }
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// The entries with the given ids, most recent visit first
+    pub fn get_entries(&self, ids: &[String]) -> rusqlite::Result<Vec<Arc<PersistentArticle>>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT id, title, url, description, content, published_at, image_url, visit_time
+             FROM article_history
+             WHERE id IN ({}) AND deleted_at IS NULL
+             ORDER BY visit_time DESC",
+            placeholders
+        ))?;
+
+        let rows = stmt.query_map(params_from_iter(ids), |row| {
+            Ok(Arc::new(PersistentArticle {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                url: row.get(2)?,
+                description: row.get(3)?,
+                content: row.get(4)?,
+                published_at: row.get(5)?,
+                image_url: row.get(6)?,
+                visit_time: row.get(7)?,
+            }))
+        })?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+
+    /// Marks the given entries deleted, all or none. They stay in the table until purged so
+    /// the deletion can be undone. Returns the batch they were marked with and their count.
+    pub fn trash_entries(&mut self, ids: &[String]) -> rusqlite::Result<(i64, usize)> {
+        let batch = self.next_batch();
+        let tx = self.conn.transaction()?;
+        let mut trashed = 0;
+        {
+            let mut stmt = tx.prepare(
+                "UPDATE article_history SET deleted_at = ?1 WHERE id = ?2 AND deleted_at IS NULL",
+            )?;
+            for id in ids {
+                trashed += stmt.execute(params![batch, id])?;
+            }
+        }
+        tx.commit()?;
+        Ok((batch, trashed))
+    }
+
+    /// Marks every visit made at or after `since` deleted, for "clear the last hour" and the like
+    pub fn trash_since(&mut self, since: i64) -> rusqlite::Result<(i64, usize)> {
+        let batch = self.next_batch();
+        let trashed = self.conn.execute(
+            "UPDATE article_history SET deleted_at = ?1
+            WHERE visit_time >= ?2 AND deleted_at IS NULL",
+            params![batch, since],
+        )?;
+        Ok((batch, trashed))
+    }
+
+    pub fn trash_all(&mut self) -> rusqlite::Result<(i64, usize)> {
+        let batch = self.next_batch();
+        let trashed = self.conn.execute(
+            "UPDATE article_history SET deleted_at = ?1 WHERE deleted_at IS NULL",
+            params![batch],
+        )?;
+        Ok((batch, trashed))
+    }
+
+    /// Brings back the entries of a deletion that has not been purged yet
+    pub fn restore(&self, batch: i64) -> rusqlite::Result<usize> {
+        self.conn.execute(
+            "UPDATE article_history SET deleted_at = NULL WHERE deleted_at = ?1",
+            params![batch],
+        )
+    }
+
+    /// Removes the entries of a deletion for good
+    pub fn purge(&self, batch: i64) -> rusqlite::Result<usize> {
+        self.conn.execute(
+            "DELETE FROM article_history WHERE deleted_at = ?1",
+            params![batch],
+        )
+    }
+
+    /// Removes every deleted entry for good, including ones left over from a previous run
+    pub fn purge_all(&self) -> rusqlite::Result<usize> {
+        self.conn.execute(
+            "DELETE FROM article_history WHERE deleted_at IS NOT NULL",
+            [],
+        )
+    }
+
+    /// Batches are deletion times in milliseconds, bumped when two deletions share one
+    fn next_batch(&mut self) -> i64 {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.last_batch = now.max(self.last_batch + 1);
+        self.last_batch
+    }
+
+    /// Drops whatever the retention limits no longer allow. Returns how many entries went.
+    pub fn prune(&mut self, retention: &HistoryRetention) -> rusqlite::Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut removed = 0;
+
+        if retention.keep_days > 0 {
+            let cutoff = chrono::Utc::now().timestamp() - retention.keep_days as i64 * 86_400;
+            removed += tx.execute(
+                "DELETE FROM article_history WHERE visit_time < ?1 AND deleted_at IS NULL",
+                params![cutoff],
+            )?;
+        }
+        if retention.max_entries > 0 {
+            removed += tx.execute(
+                "DELETE FROM article_history WHERE deleted_at IS NULL AND id NOT IN
+                (SELECT id FROM article_history WHERE deleted_at IS NULL
+                 ORDER BY visit_time DESC LIMIT ?1)",
+                params![retention.max_entries],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> HistoryService {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::migrate(&mut conn).unwrap();
+        HistoryService {
+            conn,
+            last_batch: 0,
+        }
+    }
+
+    fn article(url: &str, title: &str, visit_time: i64) -> PersistentArticle {
+        PersistentArticle {
+            id: PersistentArticle::id_for(url),
+            title: title.to_string(),
+            url: url.to_string(),
+            description: None,
+            content: None,
+            published_at: String::new(),
+            image_url: String::new(),
+            visit_time,
+        }
+    }
+
+    fn record(url: &str, visit_time: i64, first_visit: i64, visit_count: i64) -> HistoryRecord {
+        HistoryRecord {
+            article: article(url, "Imported", visit_time),
+            first_visit: Some(first_visit),
+            visit_count,
+            read_secs: 10,
+        }
+    }
+
+    fn visit(service: &HistoryService, url: &str, visit_time: i64) {
+        service
+            .save_to_history(article(url, "Local", visit_time), &VisitOrigin::default())
+            .unwrap();
+    }
+
+    fn stored(service: &HistoryService, url: &str) -> Option<HistoryRecord> {
+        service
+            .get_records(&[PersistentArticle::id_for(url)])
+            .unwrap()
+            .pop()
+    }
+
+    const URL: &str = "https://example.com/story";
+
+    #[test]
+    fn import_keeps_the_newer_visit() {
+        let mut service = service();
+        visit(&service, URL, 2_000);
+
+        service
+            .import_history(vec![record(URL, 1_000, 1_000, 1)])
+            .unwrap();
+        let kept = stored(&service, URL).unwrap();
+        assert_eq!(kept.article.visit_time, 2_000);
+        assert_eq!(kept.article.title, "Local");
+
+        service
+            .import_history(vec![record(URL, 3_000, 3_000, 1)])
+            .unwrap();
+        let replaced = stored(&service, URL).unwrap();
+        assert_eq!(replaced.article.visit_time, 3_000);
+        assert_eq!(replaced.article.title, "Imported");
+    }
+
+    #[test]
+    fn import_keeps_the_earliest_first_visit_and_the_larger_statistics() {
+        let mut service = service();
+        visit(&service, URL, 1_000);
+        visit(&service, URL, 2_000);
+        service
+            .add_read_time(&PersistentArticle::id_for(URL), 30)
+            .unwrap();
+
+        let changed = service
+            .import_history(vec![record(URL, 1_500, 500, 5)])
+            .unwrap();
+        assert_eq!(changed, 1);
+
+        let merged = stored(&service, URL).unwrap();
+        assert_eq!(merged.article.visit_time, 2_000);
+        assert_eq!(merged.first_visit, Some(500));
+        assert_eq!(merged.visit_count, 5);
+        assert_eq!(merged.read_secs, 30);
+
+        // nothing in an older, smaller record improves on what is stored
+        let changed = service
+            .import_history(vec![record(URL, 1_500, 800, 1)])
+            .unwrap();
+        assert_eq!(changed, 0);
+        assert_eq!(stored(&service, URL).unwrap().first_visit, Some(500));
+    }
+
+    #[test]
+    fn importing_the_same_archive_twice_changes_nothing() {
+        let mut service = service();
+        let archive = vec![
+            record(URL, 1_000, 900, 2),
+            record("https://example.com/other", 50, 50, 1),
+        ];
+
+        assert_eq!(service.import_history(archive.clone()).unwrap(), 2);
+        assert_eq!(service.import_history(archive).unwrap(), 0);
+        assert_eq!(service.get_all_records().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn import_revives_entries_waiting_to_be_purged() {
+        let mut service = service();
+        visit(&service, URL, 2_000);
+        let (batch, _) = service
+            .trash_entries(&[PersistentArticle::id_for(URL)])
+            .unwrap();
+        assert!(stored(&service, URL).is_none());
+
+        // even an older visit comes back, the local one was deleted
+        service
+            .import_history(vec![record(URL, 1_000, 700, 3)])
+            .unwrap();
+        service.purge(batch).unwrap();
+
+        let revived = stored(&service, URL).unwrap();
+        assert_eq!(revived.article.visit_time, 1_000);
+        assert_eq!(revived.first_visit, Some(700));
+        assert_eq!(revived.visit_count, 3);
+        assert_eq!(revived.read_secs, 10);
+    }
+
+    fn found(service: &HistoryService, filter: &HistoryFilter) -> Vec<String> {
+        service
+            .search_history(filter, 10, 0)
+            .unwrap()
+            .iter()
+            .map(|article| article.title.clone())
+            .collect()
+    }
+
+    fn query(query: &str) -> HistoryFilter {
+        HistoryFilter {
+            query: query.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn searches_titles_dates_and_pages() {
+        let service = service();
+        let origin = VisitOrigin::default();
+        for (url, title, visit_time) in [
+            ("https://example.com/a", "Rust compiler release", 1_000),
+            ("https://example.com/b", "Python release notes", 2_000),
+            (
+                "https://example.com/c",
+                "Gardening: tomato-growing tips",
+                3_000,
+            ),
+        ] {
+            service
+                .save_to_history(article(url, title, visit_time), &origin)
+                .unwrap();
+        }
+        // visiting again with a new title has to update the index too
+        service
+            .save_to_history(
+                article("https://example.com/a", "Rust 2024 edition", 4_000),
+                &origin,
+            )
+            .unwrap();
+
+        assert!(found(&service, &query("compiler")).is_empty());
+        assert_eq!(found(&service, &query("edition")), ["Rust 2024 edition"]);
+        assert_eq!(found(&service, &query("rel")), ["Python release notes"]);
+        assert_eq!(
+            found(&service, &query("tomato-growing gard")),
+            ["Gardening: tomato-growing tips"]
+        );
+        assert!(found(&service, &query("say \"hi")).is_empty());
+
+        let window = HistoryFilter {
+            since: Some(2_000),
+            until: Some(4_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            found(&service, &window),
+            ["Gardening: tomato-growing tips", "Python release notes"]
+        );
+
+        let second_page = service
+            .search_history(&HistoryFilter::default(), 1, 1)
+            .unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].title, "Gardening: tomato-growing tips");
+    }
+
+    fn indexed(service: &HistoryService, term: &str) -> i64 {
+        service
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM article_history_fts WHERE article_history_fts MATCH ?1",
+                [term],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn restores_and_purges_deleted_batches() {
+        let mut service = service();
+        let origin = VisitOrigin::default();
+        let ids: Vec<String> = ["alpha", "beta", "gamma"]
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                let url = format!("https://example.com/{}", word);
+                service
+                    .save_to_history(article(&url, word, index as i64), &origin)
+                    .unwrap();
+                PersistentArticle::id_for(&url)
+            })
+            .collect();
+
+        let (batch, trashed) = service.trash_entries(&ids[..2]).unwrap();
+        assert_eq!(trashed, 2);
+        assert_eq!(found(&service, &HistoryFilter::default()), ["gamma"]);
+        assert!(found(&service, &query("alpha")).is_empty());
+
+        assert_eq!(service.restore(batch).unwrap(), 2);
+        assert_eq!(
+            found(&service, &HistoryFilter::default()),
+            ["gamma", "beta", "alpha"]
+        );
+        assert_eq!(found(&service, &query("alpha")), ["alpha"]);
+
+        let (batch, _) = service.trash_entries(&ids[..1]).unwrap();
+        assert_eq!(service.purge(batch).unwrap(), 1);
+        assert_eq!(service.restore(batch).unwrap(), 0);
+        assert_eq!(
+            found(&service, &HistoryFilter::default()),
+            ["gamma", "beta"]
+        );
+        assert_eq!(indexed(&service, "alpha"), 0);
+        assert_eq!(indexed(&service, "beta"), 1);
     }
 }
@@ -0,0 +1,490 @@
+use rusqlite::{Connection, Result, Transaction};
+
+/// One step of the history.db schema. Steps run in order, each in its own transaction, and
+/// `PRAGMA user_version` records the last one applied.
+struct Migration {
+    version: i64,
+    description: &'static str,
+    up: fn(&Transaction) -> Result<()>,
+}
+
+/// Never edit or reorder a step once released, add a new one at the end instead
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "reading history",
+        up: create_article_history,
+    },
+    Migration {
+        version: 2,
+        description: "full-text index over the reading history",
+        up: index_article_history,
+    },
+    Migration {
+        version: 3,
+        description: "undoable history deletion",
+        up: add_deleted_at,
+    },
+    Migration {
+        version: 4,
+        description: "visit counts and reading time",
+        up: add_visit_stats,
+    },
+    Migration {
+        version: 5,
+        description: "saved articles and their tags",
+        up: create_bookmarks,
+    },
+    Migration {
+        version: 6,
+        description: "folders for saved articles",
+        up: add_bookmark_folder,
+    },
+    Migration {
+        version: 7,
+        description: "notes and highlights",
+        up: create_notes,
+    },
+    Migration {
+        version: 8,
+        description: "feed subscriptions",
+        up: create_feed_subscriptions,
+    },
+    Migration {
+        version: 9,
+        description: "custom endpoints",
+        up: create_custom_endpoints,
+    },
+    Migration {
+        version: 10,
+        description: "saved topics and their matches",
+        up: create_saved_topics,
+    },
+    Migration {
+        version: 11,
+        description: "provider usage",
+        up: create_provider_usage,
+    },
+    Migration {
+        version: 12,
+        description: "response cache",
+        up: create_http_cache,
+    },
+    Migration {
+        version: 13,
+        description: "stored articles for offline reading",
+        up: create_article_store,
+    },
+];
+
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+pub fn current_version(conn: &Connection) -> Result<i64> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+/// Brings the database up to the latest schema. Every service sharing history.db calls this
+/// when it opens the file, so whichever opens it first does the upgrade. A database that
+/// already holds history is copied next to itself first, so a failed upgrade never costs the
+/// user their history.
+pub fn migrate(conn: &mut Connection) -> Result<()> {
+    let version = current_version(conn)?;
+    if version >= latest_version() {
+        return Ok(());
+    }
+
+    // a fresh database has nothing to lose
+    let has_history: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = 'article_history')",
+        [],
+        |row| row.get(0),
+    )?;
+    if has_history {
+        backup(conn, version)?;
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        let tx = conn.transaction()?;
+        if let Err(e) = (migration.up)(&tx) {
+            eprintln!(
+                "Failed to migrate history.db to version {} ({}): {}",
+                migration.version, migration.description, e
+            );
+            return Err(e);
+        }
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+    }
+
+    Ok(())
+}
+
+fn backup(conn: &Connection, version: i64) -> Result<()> {
+    // in-memory databases have nothing worth keeping
+    let Some(path) = conn.path().filter(|path| !path.is_empty()) else {
+        return Ok(());
+    };
+
+    let backup_path = format!("{}.v{}.bak", path, version);
+    // VACUUM INTO refuses to overwrite, an older backup of the same version is replaced
+    let _ = std::fs::remove_file(&backup_path);
+    conn.execute("VACUUM INTO ?1", [&backup_path])?;
+    Ok(())
+}
+
+/// Databases from before versioning already have this table, so it is only created when missing
+fn create_article_history(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS article_history (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                url TEXT NOT NULL,
+                description TEXT,
+                content TEXT,
+                published_at TEXT,
+                image_url TEXT,
+                visit_time INTEGER
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+/// The index keeps its own copy of the text keyed by article id, so it stays right through
+/// INSERT OR REPLACE (which skips delete triggers) and rowid changes on VACUUM.
+fn index_article_history(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS article_history_fts USING fts5(
+                id UNINDEXED,
+                title,
+                description,
+                content
+            );
+        CREATE TRIGGER IF NOT EXISTS article_history_fts_insert AFTER INSERT ON article_history
+        BEGIN
+            DELETE FROM article_history_fts WHERE id = new.id;
+            INSERT INTO article_history_fts (id, title, description, content)
+            VALUES (new.id, new.title, new.description, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS article_history_fts_update AFTER UPDATE ON article_history
+        BEGIN
+            DELETE FROM article_history_fts WHERE id = old.id;
+            INSERT INTO article_history_fts (id, title, description, content)
+            VALUES (new.id, new.title, new.description, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS article_history_fts_delete AFTER DELETE ON article_history
+        BEGIN
+            DELETE FROM article_history_fts WHERE id = old.id;
+        END;
+
+        -- history read before the index existed
+        DELETE FROM article_history_fts;
+        INSERT INTO article_history_fts (id, title, description, content)
+        SELECT id, title, description, content FROM article_history;",
+    )
+}
+
+/// Deleted entries are kept, marked with the batch they were deleted in, until the chance to
+/// undo has passed
+fn add_deleted_at(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "ALTER TABLE article_history ADD COLUMN deleted_at INTEGER;
+        CREATE INDEX IF NOT EXISTS article_history_deleted_at ON article_history (deleted_at);",
+    )
+}
+
+/// `visit_time` stays the last visit. Entries from before this step count as a single visit.
+fn add_visit_stats(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "ALTER TABLE article_history ADD COLUMN visit_count INTEGER NOT NULL DEFAULT 1;
+        ALTER TABLE article_history ADD COLUMN first_visit INTEGER;
+        ALTER TABLE article_history ADD COLUMN read_secs INTEGER NOT NULL DEFAULT 0;
+        ALTER TABLE article_history ADD COLUMN source TEXT;
+        ALTER TABLE article_history ADD COLUMN section TEXT;
+
+        -- reading time is added to entries all the time, only text changes need re-indexing
+        DROP TRIGGER IF EXISTS article_history_fts_update;
+        CREATE TRIGGER article_history_fts_update
+        AFTER UPDATE OF title, description, content ON article_history
+        BEGIN
+            DELETE FROM article_history_fts WHERE id = old.id;
+            INSERT INTO article_history_fts (id, title, description, content)
+            VALUES (new.id, new.title, new.description, new.content);
+        END;
+
+        UPDATE article_history SET first_visit = visit_time;",
+    )
+}
+
+// The services sharing history.db created their own tables before the schema was versioned,
+// so the steps below only create what is missing.
+
+fn create_bookmarks(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS bookmarks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                url TEXT NOT NULL,
+                description TEXT,
+                content TEXT,
+                published_at TEXT,
+                image_url TEXT,
+                visit_time INTEGER,
+                saved_at INTEGER NOT NULL
+            );
+        CREATE TABLE IF NOT EXISTS bookmark_tags (
+                bookmark_id TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (bookmark_id, tag)
+            );",
+    )
+}
+
+/// Unversioned databases created after folders were added already have the column
+fn add_bookmark_folder(tx: &Transaction) -> Result<()> {
+    let has_folder: bool = tx.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('bookmarks') WHERE name = 'folder'",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_folder {
+        tx.execute("ALTER TABLE bookmarks ADD COLUMN folder TEXT", [])?;
+    }
+    Ok(())
+}
+
+fn create_notes(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS article_notes (
+                article_id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                url TEXT NOT NULL,
+                body TEXT NOT NULL,
+                updated_at INTEGER
+            );
+        CREATE TABLE IF NOT EXISTS article_highlights (
+                article_id TEXT NOT NULL,
+                passage TEXT NOT NULL,
+                created_at INTEGER,
+                PRIMARY KEY (article_id, passage)
+            );",
+    )
+}
+
+fn create_feed_subscriptions(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS feed_subscriptions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                title TEXT NOT NULL,
+                url TEXT NOT NULL UNIQUE,
+                folder TEXT,
+                added_at INTEGER
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn create_custom_endpoints(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS custom_endpoints (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                definition TEXT NOT NULL,
+                added_at INTEGER
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn create_saved_topics(tx: &Transaction) -> Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS saved_topics (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                query TEXT NOT NULL,
+                from_date TEXT,
+                to_date TEXT,
+                language TEXT,
+                sort_by TEXT NOT NULL,
+                source TEXT,
+                title_only INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL,
+                added_at INTEGER
+            );
+        -- every article a topic has matched, `seen` is cleared for matches found in the background
+        CREATE TABLE IF NOT EXISTS topic_matches (
+                topic_id INTEGER NOT NULL,
+                url TEXT NOT NULL,
+                seen INTEGER NOT NULL DEFAULT 0,
+                found_at INTEGER,
+                PRIMARY KEY (topic_id, url)
+            );",
+    )
+}
+
+fn create_provider_usage(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS provider_usage (
+                provider TEXT NOT NULL,
+                window_start INTEGER NOT NULL,
+                requests INTEGER NOT NULL DEFAULT 0,
+                reported_limit INTEGER,
+                reported_remaining INTEGER,
+                updated_at INTEGER,
+                PRIMARY KEY (provider, window_start)
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn create_http_cache(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS http_cache (
+                key TEXT PRIMARY KEY,
+                status INTEGER NOT NULL,
+                content_type TEXT,
+                body BLOB NOT NULL,
+                etag TEXT,
+                last_modified TEXT,
+                fetched_at INTEGER NOT NULL
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn create_article_store(tx: &Transaction) -> Result<()> {
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS article_store (
+                section TEXT NOT NULL,
+                url TEXT NOT NULL,
+                provider TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                content TEXT,
+                image_url TEXT,
+                published_at TEXT,
+                source TEXT,
+                author TEXT,
+                fetched_at INTEGER NOT NULL,
+                PRIMARY KEY (section, url)
+            )",
+        [],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A database from before the schema was versioned, with one visit in it
+    fn v0_database() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE article_history (
+                    id TEXT PRIMARY KEY,
+                    title TEXT NOT NULL,
+                    url TEXT NOT NULL,
+                    description TEXT,
+                    content TEXT,
+                    published_at TEXT,
+                    image_url TEXT,
+                    visit_time INTEGER
+                );
+            INSERT INTO article_history VALUES
+                ('abc', 'Rust 2024 released', 'https://example.com/rust', NULL, NULL,
+                 '2024-01-01T00:00:00Z', '', 1700000000);",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn has_column(conn: &Connection, table: &str, column: &str) -> bool {
+        conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+            [table, column],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    fn schema(conn: &Connection) -> Vec<String> {
+        let mut stmt = conn
+            .prepare("SELECT sql FROM sqlite_master WHERE sql IS NOT NULL ORDER BY name")
+            .unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn upgrades_v0_database_to_latest() {
+        let mut conn = v0_database();
+        migrate(&mut conn).unwrap();
+
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+        for column in [
+            "deleted_at",
+            "visit_count",
+            "first_visit",
+            "read_secs",
+            "source",
+            "section",
+        ] {
+            assert!(has_column(&conn, "article_history", column), "{column}");
+        }
+        assert!(has_column(&conn, "bookmarks", "folder"));
+
+        let fts: String = conn
+            .query_row(
+                "SELECT sql FROM sqlite_master WHERE name = 'article_history_fts'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(fts.contains("fts5"));
+
+        let (title, visits, first_visit): (String, i64, i64) = conn
+            .query_row(
+                "SELECT title, visit_count, first_visit FROM article_history WHERE id = 'abc'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(title, "Rust 2024 released");
+        assert_eq!(visits, 1);
+        assert_eq!(first_visit, 1700000000);
+
+        let matched: String = conn
+            .query_row(
+                "SELECT id FROM article_history_fts WHERE article_history_fts MATCH 'rust'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(matched, "abc");
+    }
+
+    #[test]
+    fn migrating_twice_changes_nothing() {
+        let mut conn = v0_database();
+        migrate(&mut conn).unwrap();
+        let before = schema(&conn);
+
+        migrate(&mut conn).unwrap();
+
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+        assert_eq!(schema(&conn), before);
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM article_history", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(rows, 1);
+    }
+}
@@ -1 +1,3 @@
-pub mod history;
\ No newline at end of file
+pub mod export;
+pub mod history;
+pub mod migrations;
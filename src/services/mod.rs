@@ -1,4 +1,12 @@
+pub mod article_store_service;
+pub mod bookmark_service;
 pub mod  news_service;
 pub mod news_settings_service;
+pub mod note_service;
+pub mod response_cache_service;
+pub mod custom_endpoint_service;
 pub mod history_service;
+pub mod subscription_service;
+pub mod topic_service;
+pub mod usage_service;
 pub mod workers;
\ No newline at end of file
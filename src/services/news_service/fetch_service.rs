@@ -1,18 +1,33 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
 use relm4::{Component, ComponentSender};
 
 use crate::{
+    custom_api::custom_api_client::CustomApiClient,
     gnews::gnews_client::GNewsClient,
     news_api::news_api_client::NewsAPIClient,
     newsdata::newsdata_client::NewsdataClient,
-    services::news_settings_service::settings::NewsServiceSettings,
+    rss::rss_client::RssClient,
+    services::{
+        article_store_service::article_store::ArticleStore,
+        news_settings_service::settings::NewsServiceSettings,
+        response_cache_service::response_cache::ResponseCache, usage_service::usage::UsageTracker,
+    },
     types::{
-        news_article::NewsArticle, news_category::NewsSection, news_client::NewsClient,
-        news_handler::NewsHandler, news_source::NewsSource, request_parameters::RequestParameters,
+        article_page::{ArticlePage, PageCursor, PageInfo},
+        fetch_error::FetchError,
+        news_article::{NewsArticle, TaggedArticle},
+        news_category::NewsSection,
+        news_client::NewsClient,
+        news_handler::NewsHandler, news_source::NewsSource, provider_usage::ProviderUsage,
+        request_parameters::RequestParameters, stored_article::StoredArticle,
     },
+    utils::{dedup::StoryDeduplicator, http::RetryPolicy},
 };
 
+/// Share of a provider's daily quota past which refreshing asks first
+const QUOTA_HIGH_WATER: f64 = 0.8;
+
 #[derive(Debug, Clone)]
 pub struct NewsFetchService {
     newsapi_api_key: String,
@@ -20,6 +35,9 @@ pub struct NewsFetchService {
     newsdata_api_key: String,
     settings: NewsServiceSettings,
     request_parameters: RequestParameters,
+    usage: UsageTracker,
+    cache: ResponseCache,
+    store: ArticleStore,
 }
 
 impl NewsFetchService {
@@ -28,14 +46,22 @@ impl NewsFetchService {
         gnews_api_key: String,
         newsdata_api_key: String,
         settings: NewsServiceSettings,
+        usage: UsageTracker,
+        cache: ResponseCache,
+        store: ArticleStore,
     ) -> Self {
-        Self {
+        let service = Self {
             newsapi_api_key,
             gnews_api_key,
             newsdata_api_key,
             settings,
             request_parameters: RequestParameters::new(),
-        }
+            usage,
+            cache,
+            store,
+        };
+        service.response_cache().evict_stale();
+        service
     }
 
     pub fn get_settings(&self) -> NewsServiceSettings {
@@ -44,12 +70,109 @@ impl NewsFetchService {
 
     fn get_client(&self, source: NewsSource) -> Box<dyn NewsClient> {
         match source {
-            NewsSource::GNews => Box::new(GNewsClient::new(self.gnews_api_key.clone())),
-            NewsSource::NewsAPI => Box::new(NewsAPIClient::new(self.newsapi_api_key.clone())),
-            NewsSource::NewsData => Box::new(NewsdataClient::new(self.newsdata_api_key.clone())),
+            NewsSource::GNews => Box::new(
+                GNewsClient::new(self.gnews_api_key.clone())
+                    .with_retry_policy(self.retry_policy("GNews")),
+            ),
+            NewsSource::NewsAPI => Box::new(
+                NewsAPIClient::new(self.newsapi_api_key.clone())
+                    .with_retry_policy(self.retry_policy("NewsAPI")),
+            ),
+            NewsSource::NewsData => Box::new(
+                NewsdataClient::new(self.newsdata_api_key.clone())
+                    .with_retry_policy(self.retry_policy("NewsData")),
+            ),
+            NewsSource::Rss => Box::new(RssClient::new(self.settings.rss_feeds())),
+        }
+    }
+
+    fn retry_policy(&self, provider: &'static str) -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(self.settings.max_request_attempts())
+            .timeout(Duration::from_secs(self.settings.request_timeout() as u64))
+            .track_usage(self.usage.clone(), provider)
+            .cache(self.response_cache())
+    }
+
+    /// The shared cache with the lifetime the user picked
+    fn response_cache(&self) -> ResponseCache {
+        self.cache
+            .clone()
+            .max_age(Duration::from_secs(self.settings.cache_lifetime() as u64 * 60))
+    }
+
+    /// Requests used and left today for every provider with a quota
+    pub fn usage_report(&self) -> Vec<ProviderUsage> {
+        self.usage.all_usage()
+    }
+
+    /// A warning when refreshing this section would go over a provider's free tier,
+    /// or past most of it
+    pub fn quota_warning(&self, category: &NewsSection) -> Option<String> {
+        let sources = match category {
+            NewsSection::Feed(_) | NewsSection::CustomEndpoint(_) => return None,
+            _ if self.settings.aggregate_sources() => self.configured_sources(),
+            _ => vec![self.settings.news_source()],
+        };
+        let cost = sources.len() as u32;
+
+        let warnings: Vec<String> = sources
+            .iter()
+            .filter_map(|source| self.usage.usage(Self::source_name(source)))
+            .filter(|usage| Self::near_quota(usage, cost))
+            .map(|usage| {
+                format!(
+                    "{} has {} of {} requests left, the quota resets at {} UTC.",
+                    usage.provider,
+                    usage.remaining(),
+                    usage.daily_limit,
+                    usage.resets_at.format("%H:%M")
+                )
+            })
+            .collect();
+
+        if warnings.is_empty() {
+            None
+        } else {
+            Some(warnings.join("\n"))
         }
     }
 
+    /// Whether spending `cost` more requests goes over the quota or past its high-water mark
+    fn near_quota(usage: &ProviderUsage, cost: u32) -> bool {
+        let used = usage.daily_limit.saturating_sub(usage.remaining()) + cost;
+        used > usage.daily_limit || used as f64 > usage.daily_limit as f64 * QUOTA_HIGH_WATER
+    }
+
+    fn source_name(source: &NewsSource) -> &'static str {
+        match source {
+            NewsSource::NewsAPI => "NewsAPI",
+            NewsSource::GNews => "GNews",
+            NewsSource::NewsData => "NewsData",
+            NewsSource::Rss => "RSS",
+        }
+    }
+
+    /// Every source that has an API key (or feeds) to work with
+    pub fn configured_sources(&self) -> Vec<NewsSource> {
+        let mut sources = Vec::new();
+
+        if !self.newsapi_api_key.is_empty() {
+            sources.push(NewsSource::NewsAPI);
+        }
+        if !self.gnews_api_key.is_empty() {
+            sources.push(NewsSource::GNews);
+        }
+        if !self.newsdata_api_key.is_empty() {
+            sources.push(NewsSource::NewsData);
+        }
+        if !self.settings.rss_feeds().is_empty() {
+            sources.push(NewsSource::Rss);
+        }
+
+        sources
+    }
+
     pub fn sync_parameters(&mut self) {
         let settings = self.settings.clone();
 
@@ -60,81 +183,380 @@ impl NewsFetchService {
             .country(settings.country());
     }
 
+    /// Fetches `page` of a section, `cursors` are the ones recorded with the previous page
     pub fn fetch_news<T: Component>(
         &mut self,
         category: NewsSection,
         page: i32,
+        cursors: BTreeMap<String, PageCursor>,
         sender: ComponentSender<T>,
     ) where
         T::Input: NewsHandler,
     {
-        let client = self.get_client(self.get_settings().news_source().clone());
         self.request_parameters = self.request_parameters.clone().page(page);
 
-        match category {
-            NewsSection::General => self.fetch_general_news(client, sender),
-            NewsSection::Business => self.fetch_business_news(client, sender),
-            NewsSection::Health => return,
-            NewsSection::Entertainment => return,
-            NewsSection::Technology => return,
-            NewsSection::Science => return,
-            NewsSection::Sports => return,
-        }
+        let clients: Vec<Box<dyn NewsClient>> = match &category {
+            NewsSection::Feed(subscription) => {
+                vec![Box::new(RssClient::new(vec![subscription.url.clone()]))]
+            }
+            NewsSection::CustomEndpoint(endpoint) => {
+                vec![Box::new(CustomApiClient::new(endpoint.clone()))]
+            }
+            _ if self.settings.aggregate_sources() => {
+                return self.fetch_aggregated_news(category, page, cursors, sender);
+            }
+            _ => self
+                .failover_chain()
+                .into_iter()
+                .map(|source| self.get_client(source))
+                .collect(),
+        };
+
+        self.fetch_with_failover(clients, category, page, cursors, sender);
+    }
+
+    /// The selected source followed by the user's fallback sources that are configured
+    fn failover_chain(&self) -> Vec<NewsSource> {
+        let primary = self.settings.news_source();
+        let configured = self.configured_sources();
+
+        let mut chain = vec![primary.clone()];
+        chain.extend(
+            self.settings
+                .fallback_sources()
+                .into_iter()
+                .filter(|source| *source != primary && configured.contains(source)),
+        );
+        chain
     }
 
-    fn fetch_general_news<T: Component>(
+    /// Queries every configured source concurrently and merges the results,
+    /// tagging each article with the provider that supplied it
+    fn fetch_aggregated_news<T: Component>(
         &mut self,
-        client: Box<dyn NewsClient>,
+        category: NewsSection,
+        page: i32,
+        cursors: BTreeMap<String, PageCursor>,
         sender: ComponentSender<T>,
     ) where
         T::Input: NewsHandler,
     {
         self.sync_parameters();
         let params = self.request_parameters.clone();
-        gtk::glib::spawn_future_local(async move {
-            match client.fetch_general(params).await {
-                Ok(articles) => {
-                    let mut grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>> = BTreeMap::new();
-                    let time_organiser = crate::utils::time_organizer::UITimeOrganiser::new();
-
-                    for article in articles {
-                        let bucket = time_organiser.time_bucket_key(&article.published_at());
-                        let bucket_key = time_organiser.categorize_by_relative_time(bucket);
-                        grouped.entry(bucket_key).or_default().push(article);
-                    }
+        let clients: Vec<Box<dyn NewsClient>> = self
+            .configured_sources()
+            .into_iter()
+            .map(|source| self.get_client(source))
+            // past the first page only the providers with more results are asked again
+            .filter(|client| page <= 1 || cursors.contains_key(client.name()))
+            .collect();
 
-                    sender.input(T::Input::on_news_received(grouped));
-                }
-                Err(e) => {
-                    sender.input(T::Input::on_error(e.to_string()));
+        if clients.is_empty() && page > 1 {
+            sender.input(T::Input::on_page_loaded(PageInfo::default()));
+            sender.input(T::Input::on_news_received(BTreeMap::new()));
+            return;
+        }
+
+        if clients.is_empty() {
+            sender.input(T::Input::on_error(FetchError::Auth(
+                "No news sources are configured. Add an API key or a feed first.".to_string(),
+            )));
+            return;
+        }
+
+        let store = self.store.clone();
+        gtk::glib::spawn_future_local(async move {
+            let requests = clients.iter().map(|client| {
+                let params = Self::page_parameters(&params, client.name(), page, &cursors);
+                let category = category.clone();
+                async move {
+                    let result = client.fetch_section(&category, params).await;
+                    (client.name(), result)
                 }
+            });
+
+            let (mut articles, info, errors) =
+                Self::merge_pages(futures::future::join_all(requests).await);
+
+            if articles.is_empty() && !errors.is_empty() {
+                Self::report_failure(&store, &category, Self::combine_errors(errors), &sender);
+                return;
             }
+
+            Self::store_articles(&store, &category, "", &articles);
+
+            let time_organiser = crate::utils::time_organizer::UITimeOrganiser::new();
+            articles.sort_by_key(|article| {
+                std::cmp::Reverse(time_organiser.parse_datetime(Some(article.published_at())))
+            });
+
+            let articles = StoryDeduplicator::new().dedupe(articles);
+            sender.input(T::Input::on_page_loaded(info));
+            sender.input(T::Input::on_news_received(Self::group_by_time(articles)));
         });
     }
 
-    fn fetch_business_news<T: Component>(
+    /// Runs a search on one source, or on every configured source when `source` is `None`,
+    /// and sends the merged results grouped by day
+    pub fn search<T: Component>(
         &self,
-        client: Box<dyn NewsClient>,
+        parameters: RequestParameters,
+        source: Option<NewsSource>,
+        page: i32,
+        cursors: BTreeMap<String, PageCursor>,
         sender: ComponentSender<T>,
     ) where
         T::Input: NewsHandler,
     {
+        let parameters = if self.drops_date_range(source.as_ref()) {
+            parameters.without_dates()
+        } else {
+            parameters
+        };
+        let dated = parameters.get_from().is_some() || parameters.get_to().is_some();
+
+        let configured = self.configured_sources();
+        let sources = match source {
+            Some(source) if configured.contains(&source) => vec![source],
+            Some(source) => {
+                sender.input(T::Input::on_error(FetchError::Auth(format!(
+                    "{} is not configured. Add its API key first.",
+                    Self::source_name(&source)
+                ))));
+                return;
+            }
+            // NewsData would answer a dated search from its paid archive, leave it to the others
+            None if dated => configured
+                .into_iter()
+                .filter(|source| *source != NewsSource::NewsData)
+                .collect(),
+            None => configured,
+        };
+
+        let clients: Vec<Box<dyn NewsClient>> = sources
+            .into_iter()
+            .map(|source| self.get_client(source))
+            .filter(|client| page <= 1 || cursors.contains_key(client.name()))
+            .collect();
+
+        if clients.is_empty() {
+            if page > 1 {
+                sender.input(T::Input::on_page_loaded(PageInfo::default()));
+                sender.input(T::Input::on_news_received(BTreeMap::new()));
+            } else {
+                sender.input(T::Input::on_error(FetchError::Auth(
+                    "No news sources are configured. Add an API key or a feed first.".to_string(),
+                )));
+            }
+            return;
+        }
+
         gtk::glib::spawn_future_local(async move {
-            match client.fetch_business().await {
-                Ok(articles) => {
-                    let mut grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>> = BTreeMap::new();
-                    let time_organiser = crate::utils::time_organizer::UITimeOrganiser::new();
-
-                    for article in articles {
-                        let bucket = time_organiser.time_bucket_key(&article.published_at());
-                        let bucket_key = time_organiser.categorize_by_relative_time(bucket);
-                        grouped.entry(bucket_key).or_default().push(article);
-                    }
+            let requests = clients.iter().map(|client| {
+                let params = Self::page_parameters(&parameters, client.name(), page, &cursors);
+                async move { (client.name(), client.search(params).await) }
+            });
 
-                    sender.input(T::Input::on_news_received(grouped));
+            let (mut articles, info, errors) =
+                Self::merge_pages(futures::future::join_all(requests).await);
+
+            if articles.is_empty() && !errors.is_empty() {
+                sender.input(T::Input::on_error(Self::combine_errors(errors)));
+                return;
+            }
+
+            // relevance order comes from the providers, everything else reads newest first
+            if parameters.get_sort_by().as_deref() != Some("relevancy") {
+                let time_organiser = crate::utils::time_organizer::UITimeOrganiser::new();
+                articles.sort_by_key(|article| {
+                    std::cmp::Reverse(time_organiser.parse_datetime(Some(article.published_at())))
+                });
+            }
+
+            let articles = StoryDeduplicator::new().dedupe(articles);
+            sender.input(T::Input::on_page_loaded(info));
+            sender.input(T::Input::on_news_received(Self::group_by_time(articles)));
+        });
+    }
+
+    /// Whether a search on `source` (every configured one when `None`) runs without its date
+    /// range. Only NewsData filters by date on its archive endpoint, which the free plan
+    /// does not include, so it is searched without one when nothing else can answer.
+    pub fn drops_date_range(&self, source: Option<&NewsSource>) -> bool {
+        match source {
+            Some(source) => *source == NewsSource::NewsData,
+            None => self.configured_sources() == [NewsSource::NewsData],
+        }
+    }
+
+    /// Collects the pages several providers returned, tagging each article with its provider
+    fn merge_pages(
+        results: Vec<(&'static str, Result<ArticlePage, FetchError>)>,
+    ) -> (
+        Vec<Arc<dyn NewsArticle>>,
+        PageInfo,
+        Vec<(&'static str, FetchError)>,
+    ) {
+        let mut articles = Vec::new();
+        let mut errors = Vec::new();
+        let mut info = PageInfo::default();
+
+        for (provider, result) in results {
+            match result {
+                Ok(fetched) => {
+                    info.add(provider, &fetched);
+                    articles.extend(
+                        fetched
+                            .articles
+                            .into_iter()
+                            .map(|article| TaggedArticle::tag(provider, article)),
+                    )
                 }
                 Err(e) => {
-                    sender.input(T::Input::on_error(e.to_string()));
+                    eprintln!("{} failed: {}", provider, e);
+                    errors.push((provider, e));
+                }
+            }
+        }
+
+        (articles, info, errors)
+    }
+
+    /// Points a provider's request at the page it reported last time, or at `page` by number
+    fn page_parameters(
+        params: &RequestParameters,
+        provider: &str,
+        page: i32,
+        cursors: &BTreeMap<String, PageCursor>,
+    ) -> RequestParameters {
+        match cursors.get(provider) {
+            Some(PageCursor::Number(number)) => params.clone().page(*number),
+            Some(PageCursor::Token(token)) => params.clone().page(page).cursor(token.clone()),
+            None => params.clone().page(page),
+        }
+    }
+
+    /// Keeps every fetched article in the local store so the section can be read offline
+    fn store_articles(
+        store: &ArticleStore,
+        category: &NewsSection,
+        provider: &str,
+        articles: &[Arc<dyn NewsArticle>],
+    ) {
+        let section = category.to_key();
+        let fetched_at = chrono::Utc::now().timestamp();
+        store.save(
+            articles
+                .iter()
+                .map(|article| {
+                    StoredArticle::from_article(article.as_ref(), &section, provider, fetched_at)
+                })
+                .collect(),
+        );
+    }
+
+    /// When the network is unreachable, falls back to the last stored results for the
+    /// section instead of an error, otherwise forwards the error to the page
+    fn report_failure<T: Component>(
+        store: &ArticleStore,
+        category: &NewsSection,
+        error: FetchError,
+        sender: &ComponentSender<T>,
+    ) where
+        T::Input: NewsHandler,
+    {
+        if let FetchError::Network(_) = error {
+            if let Some((stored, fetched_at)) = store.latest(&category.to_key()) {
+                let articles: Vec<Arc<dyn NewsArticle>> = stored
+                    .into_iter()
+                    .map(|article| Arc::new(article) as Arc<dyn NewsArticle>)
+                    .collect();
+                let articles = StoryDeduplicator::new().dedupe(articles);
+                sender.input(T::Input::on_offline(Self::group_by_time(articles), fetched_at));
+                return;
+            }
+        }
+
+        sender.input(T::Input::on_error(error));
+    }
+
+    /// Reports several provider failures as one, keeping the category of the last failure
+    fn combine_errors(mut errors: Vec<(&'static str, FetchError)>) -> FetchError {
+        let message = errors
+            .iter()
+            .map(|(provider, e)| format!("{}: {}", provider, e))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (_, last) = errors.pop().expect("at least one error to combine");
+        last.with_message(message)
+    }
+
+    /// Buckets articles by relative day ("Today", "Yesterday", …)
+    fn group_by_time(
+        articles: Vec<Arc<dyn NewsArticle>>,
+    ) -> BTreeMap<String, Vec<Arc<dyn NewsArticle>>> {
+        let mut grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>> = BTreeMap::new();
+        let time_organiser = crate::utils::time_organizer::UITimeOrganiser::new();
+
+        for article in articles {
+            let bucket = time_organiser.time_bucket_key(&article.published_at());
+            let bucket_key = time_organiser.categorize_by_relative_time(bucket);
+            grouped.entry(bucket_key).or_default().push(article);
+        }
+
+        grouped
+    }
+
+    /// Tries each client in turn, moving on only when a provider is out of quota
+    /// or rejects the key. Any other error is reported straight away.
+    fn fetch_with_failover<T: Component>(
+        &mut self,
+        clients: Vec<Box<dyn NewsClient>>,
+        category: NewsSection,
+        page: i32,
+        cursors: BTreeMap<String, PageCursor>,
+        sender: ComponentSender<T>,
+    ) where
+        T::Input: NewsHandler,
+    {
+        self.sync_parameters();
+        let params = self.request_parameters.clone();
+        let store = self.store.clone();
+        gtk::glib::spawn_future_local(async move {
+            let mut errors: Vec<(&'static str, FetchError)> = Vec::new();
+
+            for (index, client) in clients.iter().enumerate() {
+                let page_params = Self::page_parameters(&params, client.name(), page, &cursors);
+                match client.fetch_section(&category, page_params).await {
+                    Ok(fetched) => {
+                        if index > 0 {
+                            sender.input(T::Input::on_fallback(
+                                clients[0].name().to_string(),
+                                client.name().to_string(),
+                            ));
+                        }
+                        let mut info = PageInfo::default();
+                        info.add(client.name(), &fetched);
+                        Self::store_articles(&store, &category, client.name(), &fetched.articles);
+                        let articles = StoryDeduplicator::new().dedupe(fetched.articles);
+                        sender.input(T::Input::on_page_loaded(info));
+                        sender.input(T::Input::on_news_received(Self::group_by_time(articles)));
+                        return;
+                    }
+                    Err(e) if e.should_fail_over() && index + 1 < clients.len() => {
+                        eprintln!("{} unavailable, falling back: {}", client.name(), e);
+                        errors.push((client.name(), e));
+                    }
+                    Err(e) => {
+                        let error = if errors.is_empty() {
+                            e
+                        } else {
+                            errors.push((client.name(), e));
+                            Self::combine_errors(errors)
+                        };
+                        Self::report_failure(&store, &category, error, &sender);
+                        return;
+                    }
                 }
             }
         });
@@ -1,6 +1,6 @@
 use gtk::gio::{Settings, prelude::SettingsExt};
 
-use crate::types::news_source::NewsSource;
+use crate::types::{history_retention::HistoryRetention, news_source::NewsSource};
 
 #[derive(Debug, Clone)]
 pub struct NewsServiceSettings {
@@ -25,14 +25,51 @@ impl NewsServiceSettings {
     pub fn news_source(&self) -> NewsSource {
         let source_str = self.settings.string("news-source");
 
-        match source_str.as_str() {
-            "NewsAPI" => NewsSource::NewsAPI,
-            "GNews" => NewsSource::GNews,
-            "NewsData" => NewsSource::NewsData,
-            _ => NewsSource::NewsAPI, // This is your "Default" fallback
+        Self::parse_source(source_str.as_str()).unwrap_or(NewsSource::NewsAPI) // This is your "Default" fallback
+    }
+
+    /// Sources to try, in order, when the selected one is out of quota or rejects the key
+    pub fn fallback_sources(&self) -> Vec<NewsSource> {
+        self.settings
+            .strv("fallback-sources")
+            .iter()
+            .filter_map(|source| Self::parse_source(source.as_str()))
+            .collect()
+    }
+
+    pub fn aggregate_sources(&self) -> bool {
+        self.settings.boolean("aggregate-sources")
+    }
+
+    pub fn max_request_attempts(&self) -> u32 {
+        self.settings.int("max-request-attempts").max(1) as u32
+    }
+
+    pub fn request_timeout(&self) -> u32 {
+        self.settings.int("request-timeout").max(1) as u32
+    }
+
+    /// Minutes a provider response is reused before asking the server again
+    pub fn cache_lifetime(&self) -> u32 {
+        self.settings.int("cache-lifetime").max(0) as u32
+    }
+
+    pub fn history_retention(&self) -> HistoryRetention {
+        HistoryRetention {
+            keep_days: self.settings.int("history-keep-days").max(0) as u32,
+            max_entries: self.settings.int("history-max-entries").max(0) as u32,
+            private_mode: self.settings.boolean("private-mode"),
         }
     }
 
+    pub fn rss_feeds(&self) -> Vec<String> {
+        self.settings
+            .strv("rss-feeds")
+            .iter()
+            .map(|feed| feed.to_string())
+            .collect()
+    }
+
     // --- SETTERS (Overriding Defaults) ---
 
     pub fn set_country(&self, value: &str) {
@@ -48,14 +85,84 @@ impl NewsServiceSettings {
     }
 
     pub fn set_news_source(&self, source: NewsSource) {
-        let value = match source {
+        self.settings
+            .set_string("news-source", Self::source_key(&source))
+            .expect("Failed to save news source to GSettings");
+    }
+
+    pub fn set_fallback_sources(&self, sources: &[NewsSource]) {
+        let sources: Vec<&str> = sources.iter().map(Self::source_key).collect();
+        self.settings
+            .set_strv("fallback-sources", sources.as_slice())
+            .expect("Failed to save fallback sources to GSettings");
+    }
+
+    pub fn set_aggregate_sources(&self, value: bool) {
+        self.settings
+            .set_boolean("aggregate-sources", value)
+            .expect("Failed to save aggregation mode to GSettings");
+    }
+
+    pub fn set_max_request_attempts(&self, attempts: u32) {
+        self.settings
+            .set_int("max-request-attempts", attempts as i32)
+            .expect("Failed to save request attempts to GSettings");
+    }
+
+    pub fn set_request_timeout(&self, seconds: u32) {
+        self.settings
+            .set_int("request-timeout", seconds as i32)
+            .expect("Failed to save request timeout to GSettings");
+    }
+
+    pub fn set_cache_lifetime(&self, minutes: u32) {
+        self.settings
+            .set_int("cache-lifetime", minutes as i32)
+            .expect("Failed to save cache lifetime to GSettings");
+    }
+
+    pub fn set_history_keep_days(&self, days: u32) {
+        self.settings
+            .set_int("history-keep-days", days as i32)
+            .expect("Failed to save history retention to GSettings");
+    }
+
+    pub fn set_history_max_entries(&self, entries: u32) {
+        self.settings
+            .set_int("history-max-entries", entries as i32)
+            .expect("Failed to save history size limit to GSettings");
+    }
+
+    pub fn set_private_mode(&self, value: bool) {
+        self.settings
+            .set_boolean("private-mode", value)
+            .expect("Failed to save private mode to GSettings");
+    }
+
+    pub fn set_rss_feeds(&self, feeds: &[String]) {
+        let feeds: Vec<&str> = feeds.iter().map(|f| f.as_str()).collect();
+        self.settings
+            .set_strv("rss-feeds", feeds.as_slice())
+            .expect("Failed to save RSS feeds to GSettings");
+    }
+
+    pub fn parse_source(value: &str) -> Option<NewsSource> {
+        match value {
+            "NewsAPI" => Some(NewsSource::NewsAPI),
+            "GNews" => Some(NewsSource::GNews),
+            "NewsData" => Some(NewsSource::NewsData),
+            "RSS" => Some(NewsSource::Rss),
+            _ => None,
+        }
+    }
+
+    pub fn source_key(source: &NewsSource) -> &'static str {
+        match source {
             NewsSource::NewsAPI => "NewsAPI",
             NewsSource::GNews => "GNews",
             NewsSource::NewsData => "NewsData",
-        };
-        self.settings
-            .set_string("news-source", value)
-            .expect("Failed to save news source to GSettings");
+            NewsSource::Rss => "RSS",
+        }
     }
 
     // --- RESETTING (Back to Defaults) ---
@@ -72,6 +179,15 @@ impl NewsServiceSettings {
         self.settings.reset("country");
         self.settings.reset("news-source");
         self.settings.reset("language");
+        self.settings.reset("rss-feeds");
+        self.settings.reset("aggregate-sources");
+        self.settings.reset("fallback-sources");
+        self.settings.reset("max-request-attempts");
+        self.settings.reset("request-timeout");
+        self.settings.reset("cache-lifetime");
+        self.settings.reset("history-keep-days");
+        self.settings.reset("history-max-entries");
+        self.settings.reset("private-mode");
         // Or loop through keys if you have many
     }
 }
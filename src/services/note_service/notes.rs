@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use rusqlite::{Connection, OptionalExtension, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::article_note::ArticleNote;
+
+#[derive(Debug)]
+pub struct NoteService {
+    conn: Connection,
+}
+
+impl NoteService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    pub fn get_note(&self, article_id: &str) -> rusqlite::Result<Option<ArticleNote>> {
+        let note = self
+            .conn
+            .query_row(
+                "SELECT article_id, title, url, body, updated_at FROM article_notes WHERE article_id = ?1",
+                params![article_id],
+                |row| {
+                    Ok(ArticleNote {
+                        article_id: row.get(0)?,
+                        title: row.get(1)?,
+                        url: row.get(2)?,
+                        body: row.get(3)?,
+                        highlights: Vec::new(),
+                        updated_at: row.get(4)?,
+                    })
+                },
+            )
+            .optional()?;
+
+        match note {
+            Some(mut note) => {
+                note.highlights = self.get_highlights(article_id)?;
+                Ok(Some(note))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn get_highlights(&self, article_id: &str) -> rusqlite::Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT passage FROM article_highlights WHERE article_id = ?1 ORDER BY created_at",
+        )?;
+        let passages = stmt
+            .query_map(params![article_id], |row| row.get(0))?
+            .filter_map(|passage| passage.ok())
+            .collect();
+        Ok(passages)
+    }
+
+    /// Stores the note with its highlights, an empty note is removed instead
+    pub fn save_note(&mut self, note: &ArticleNote) -> rusqlite::Result<()> {
+        let now = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+
+        tx.execute(
+            "DELETE FROM article_highlights WHERE article_id = ?1",
+            params![note.article_id],
+        )?;
+        if note.is_empty() {
+            tx.execute(
+                "DELETE FROM article_notes WHERE article_id = ?1",
+                params![note.article_id],
+            )?;
+            return tx.commit();
+        }
+
+        tx.execute(
+            "INSERT OR REPLACE INTO article_notes (article_id, title, url, body, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![note.article_id, note.title, note.url, note.body, now],
+        )?;
+        {
+            // highlights keep the order they were made in
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO article_highlights (article_id, passage, created_at)
+                VALUES (?1, ?2, ?3)",
+            )?;
+            for (index, passage) in note.highlights.iter().enumerate() {
+                stmt.execute(params![note.article_id, passage, now + index as i64])?;
+            }
+        }
+
+        tx.commit()
+    }
+
+    /// Ids of every article with a note, so a page of tiles needs a single query
+    pub fn noted_ids(&self) -> rusqlite::Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare("SELECT article_id FROM article_notes")?;
+        stmt.query_map([], |row| row.get(0))?.collect()
+    }
+
+    /// Notes whose title, text or highlights contain `query`, most recently edited first
+    pub fn search_notes(&self, query: &str) -> rusqlite::Result<Vec<ArticleNote>> {
+        let pattern = format!("%{}%", query.trim());
+        let ids: Vec<String> = {
+            let mut stmt = self.conn.prepare(
+                "SELECT n.article_id FROM article_notes n
+                 WHERE n.title LIKE ?1 OR n.body LIKE ?1
+                    OR EXISTS (SELECT 1 FROM article_highlights h
+                               WHERE h.article_id = n.article_id AND h.passage LIKE ?1)
+                 ORDER BY n.updated_at DESC",
+            )?;
+            stmt.query_map(params![pattern], |row| row.get(0))?
+                .filter_map(|id| id.ok())
+                .collect()
+        };
+
+        let mut results = Vec::new();
+        for id in ids {
+            if let Some(note) = self.get_note(&id)? {
+                results.push(note);
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// Shared handle for pages and tiles that read or edit notes
+#[derive(Debug, Clone)]
+pub struct Notes {
+    service: Arc<Mutex<NoteService>>,
+}
+
+impl Notes {
+    pub fn new(service: NoteService) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(service)),
+        }
+    }
+
+    pub fn get(&self, article_id: &str) -> Option<ArticleNote> {
+        let service = self.service.lock().ok()?;
+        service.get_note(article_id).ok().flatten()
+    }
+
+    pub fn save(&self, note: &ArticleNote) {
+        if let Ok(mut service) = self.service.lock() {
+            if let Err(e) = service.save_note(note) {
+                eprintln!("Failed to save note: {}", e);
+            }
+        }
+    }
+
+    pub fn noted_ids(&self) -> HashSet<String> {
+        self.service
+            .lock()
+            .ok()
+            .and_then(|service| service.noted_ids().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn search(&self, query: &str) -> Vec<ArticleNote> {
+        self.service
+            .lock()
+            .ok()
+            .and_then(|service| service.search_notes(query).ok())
+            .unwrap_or_default()
+    }
+}
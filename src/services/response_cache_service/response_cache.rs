@@ -0,0 +1,162 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rusqlite::{Connection, OptionalExtension, Result, params};
+use url::Url;
+
+use crate::services::history_service::migrations;
+use crate::types::cached_response::CachedResponse;
+
+/// Query parameters that carry credentials and must never end up in a cache key
+const SECRET_PARAMS: [&str; 4] = ["apikey", "api_key", "token", "access_token"];
+
+/// Stale bodies are kept this many lifetimes so they can still be revalidated with a 304
+const STALE_LIFETIMES: u32 = 4;
+
+/// Shortest time a stale body is kept, so a lifetime of 0 does not empty the cache
+const MIN_STALE_KEEP: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug)]
+pub struct ResponseCacheService {
+    conn: Connection,
+}
+
+impl ResponseCacheService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    pub fn get_response(&self, key: &str) -> rusqlite::Result<Option<CachedResponse>> {
+        self.conn
+            .query_row(
+                "SELECT status, content_type, body, etag, last_modified, fetched_at
+                FROM http_cache WHERE key = ?1",
+                params![key],
+                |row| {
+                    Ok(CachedResponse {
+                        status: row.get(0)?,
+                        content_type: row.get(1)?,
+                        body: row.get(2)?,
+                        etag: row.get(3)?,
+                        last_modified: row.get(4)?,
+                        fetched_at: row.get(5)?,
+                    })
+                },
+            )
+            .optional()
+    }
+
+    pub fn save_response(&self, key: &str, response: &CachedResponse) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO http_cache
+                (key, status, content_type, body, etag, last_modified, fetched_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                key,
+                response.status,
+                response.content_type,
+                response.body,
+                response.etag,
+                response.last_modified,
+                response.fetched_at
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Marks a stored body as confirmed by the server (a 304 answer)
+    pub fn touch_response(&self, key: &str, fetched_at: i64) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "UPDATE http_cache SET fetched_at = ?1 WHERE key = ?2",
+            params![fetched_at, key],
+        )?;
+        Ok(())
+    }
+
+    /// Drops responses last fetched or confirmed before `cutoff`, returns how many went
+    pub fn delete_older_than(&self, cutoff: i64) -> rusqlite::Result<usize> {
+        self.conn.execute(
+            "DELETE FROM http_cache WHERE fetched_at < ?1",
+            params![cutoff],
+        )
+    }
+}
+
+/// Shared handle the request layer uses to read and fill the on-disk cache
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    service: Arc<Mutex<ResponseCacheService>>,
+    max_age: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(service: ResponseCacheService) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(service)),
+            max_age: Duration::from_secs(15 * 60),
+        }
+    }
+
+    /// How long a stored response is served without asking the server again
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// The request URL without credentials, sorted so parameter order doesn't matter
+    pub fn key(url: &Url) -> String {
+        let mut query: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| !SECRET_PARAMS.contains(&key.to_lowercase().as_str()))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        query.sort();
+
+        let mut key = url.clone();
+        key.set_query(None);
+        if !query.is_empty() {
+            key.query_pairs_mut().extend_pairs(query);
+        }
+        key.to_string()
+    }
+
+    pub fn get(&self, key: &str) -> Option<CachedResponse> {
+        let service = self.service.lock().ok()?;
+        service.get_response(key).ok().flatten()
+    }
+
+    pub fn is_fresh(&self, response: &CachedResponse) -> bool {
+        let age = chrono::Utc::now().timestamp() - response.fetched_at;
+        age >= 0 && (age as u64) < self.max_age.as_secs()
+    }
+
+    pub fn store(&self, key: &str, response: &CachedResponse) {
+        if let Ok(service) = self.service.lock() {
+            if let Err(e) = service.save_response(key, response) {
+                eprintln!("Failed to cache response: {}", e);
+            }
+        }
+    }
+
+    /// Removes responses too old to be worth revalidating; every search query and page
+    /// number gets its own entry, so without this the cache only ever grows
+    pub fn evict_stale(&self) {
+        let keep = (self.max_age * STALE_LIFETIMES).max(MIN_STALE_KEEP);
+        let cutoff = chrono::Utc::now().timestamp() - keep.as_secs() as i64;
+        if let Ok(service) = self.service.lock() {
+            if let Err(e) = service.delete_older_than(cutoff) {
+                eprintln!("Failed to clean up cached responses: {}", e);
+            }
+        }
+    }
+
+    pub fn touch(&self, key: &str) {
+        if let Ok(service) = self.service.lock() {
+            if let Err(e) = service.touch_response(key, chrono::Utc::now().timestamp()) {
+                eprintln!("Failed to refresh cached response: {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod subscriptions;
\ No newline at end of file
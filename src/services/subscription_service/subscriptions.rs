@@ -0,0 +1,92 @@
+use rusqlite::{Connection, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::feed_subscription::FeedSubscription;
+
+#[derive(Debug)]
+pub struct SubscriptionService {
+    conn: Connection,
+}
+
+impl SubscriptionService {
+    pub fn new() -> Result<Self> {
+        // Lives next to `article_history` in the same database
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Adds a subscription, or updates the title and folder if the url is already stored
+    pub fn add_subscription(&self, subscription: FeedSubscription) -> rusqlite::Result<()> {
+        let now = chrono::Utc::now().timestamp();
+
+        self.conn.execute(
+            "INSERT INTO feed_subscriptions (title, url, folder, added_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(url) DO UPDATE SET title = excluded.title, folder = excluded.folder",
+            params![
+                subscription.title,
+                subscription.url,
+                subscription.folder,
+                now
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn add_subscriptions(&mut self, subscriptions: Vec<FeedSubscription>) -> rusqlite::Result<usize> {
+        let now = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+        let mut count = 0;
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO feed_subscriptions (title, url, folder, added_at)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT(url) DO UPDATE SET title = excluded.title, folder = excluded.folder",
+            )?;
+            for subscription in subscriptions {
+                count += stmt.execute(params![
+                    subscription.title,
+                    subscription.url,
+                    subscription.folder,
+                    now
+                ])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+
+    pub fn get_all_subscriptions(&self) -> rusqlite::Result<Vec<FeedSubscription>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, url, folder
+             FROM feed_subscriptions
+             ORDER BY folder IS NOT NULL, folder COLLATE NOCASE, title COLLATE NOCASE",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok(FeedSubscription {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                url: row.get(2)?,
+                folder: row.get(3)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            if let Ok(subscription) = row {
+                results.push(subscription);
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn delete_subscription(&self, id: i64) -> rusqlite::Result<()> {
+        self.conn
+            .execute("DELETE FROM feed_subscriptions WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use rusqlite::{Connection, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::services::news_settings_service::settings::NewsServiceSettings;
+use crate::types::saved_topic::SavedTopic;
+
+#[derive(Debug)]
+pub struct TopicService {
+    conn: Connection,
+}
+
+impl TopicService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Adds a topic at the bottom of the list
+    pub fn add_topic(&self, topic: SavedTopic) -> rusqlite::Result<()> {
+        let now = chrono::Utc::now().timestamp();
+
+        self.conn.execute(
+            "INSERT INTO saved_topics
+            (name, query, from_date, to_date, language, sort_by, source, title_only, position, added_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8,
+                (SELECT COALESCE(MAX(position), -1) + 1 FROM saved_topics), ?9)",
+            params![
+                topic.name,
+                topic.query,
+                topic.from,
+                topic.to,
+                topic.language,
+                topic.sort_by,
+                topic.source.as_ref().map(NewsServiceSettings::source_key),
+                topic.title_only,
+                now
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_all_topics(&self) -> rusqlite::Result<Vec<SavedTopic>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT t.id, t.name, t.query, t.from_date, t.to_date, t.language, t.sort_by, t.source, t.title_only,
+                (SELECT COUNT(*) FROM topic_matches m WHERE m.topic_id = t.id AND m.seen = 0)
+             FROM saved_topics t
+             ORDER BY t.position",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            let source: Option<String> = row.get(7)?;
+            Ok(SavedTopic {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                query: row.get(2)?,
+                from: row.get(3)?,
+                to: row.get(4)?,
+                language: row.get(5)?,
+                sort_by: row.get(6)?,
+                source: source.and_then(|s| NewsServiceSettings::parse_source(&s)),
+                title_only: row.get(8)?,
+                unread: row.get(9)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for row in rows {
+            if let Ok(topic) = row {
+                results.push(topic);
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn delete_topic(&mut self, id: i64) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM topic_matches WHERE topic_id = ?1", params![id])?;
+        tx.execute("DELETE FROM saved_topics WHERE id = ?1", params![id])?;
+        tx.commit()
+    }
+
+    /// Moves a topic `offset` places up (negative) or down (positive) the list
+    pub fn move_topic(&mut self, id: i64, offset: i32) -> rusqlite::Result<()> {
+        let mut ids: Vec<i64> = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id FROM saved_topics ORDER BY position")?;
+            stmt.query_map([], |row| row.get(0))?
+                .filter_map(|id| id.ok())
+                .collect()
+        };
+
+        let Some(index) = ids.iter().position(|topic_id| *topic_id == id) else {
+            return Ok(());
+        };
+        let target = (index as i64 + offset as i64).clamp(0, ids.len() as i64 - 1) as usize;
+        let moved = ids.remove(index);
+        ids.insert(target, moved);
+
+        let tx = self.conn.transaction()?;
+        for (position, topic_id) in ids.iter().enumerate() {
+            tx.execute(
+                "UPDATE saved_topics SET position = ?1 WHERE id = ?2",
+                params![position as i64, topic_id],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Stores what a background check found. The first check of a topic only sets the
+    /// baseline, so a new topic does not start with every existing article unread.
+    pub fn record_matches(&mut self, topic_id: i64, urls: Vec<String>) -> rusqlite::Result<usize> {
+        let now = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+        let known: i64 = tx.query_row(
+            "SELECT COUNT(*) FROM topic_matches WHERE topic_id = ?1",
+            params![topic_id],
+            |row| row.get(0),
+        )?;
+        let seen = known == 0;
+        let mut count = 0;
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO topic_matches (topic_id, url, seen, found_at)
+                VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for url in urls {
+                count += stmt.execute(params![topic_id, url, seen, now])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// Marks everything the topic matched as read, including the articles just shown
+    pub fn mark_seen(&mut self, topic_id: i64, urls: Vec<String>) -> rusqlite::Result<()> {
+        let now = chrono::Utc::now().timestamp();
+        let tx = self.conn.transaction()?;
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO topic_matches (topic_id, url, seen, found_at)
+                VALUES (?1, ?2, 1, ?3)",
+            )?;
+            for url in urls {
+                stmt.execute(params![topic_id, url, now])?;
+            }
+        }
+        tx.execute(
+            "UPDATE topic_matches SET seen = 1 WHERE topic_id = ?1",
+            params![topic_id],
+        )?;
+
+        tx.commit()
+    }
+}
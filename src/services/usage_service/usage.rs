@@ -0,0 +1,143 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Timelike, Utc};
+use rusqlite::{Connection, OptionalExtension, Result, params};
+
+use crate::services::history_service::migrations;
+use crate::types::provider_usage::ProviderUsage;
+
+/// Free tier allowance per provider: (name, requests per day, hour of the UTC reset)
+const FREE_TIERS: [(&str, u32, u32); 3] = [
+    ("NewsAPI", 100, 0),
+    ("GNews", 100, 0),
+    ("NewsData", 200, 0),
+];
+
+#[derive(Debug)]
+pub struct UsageService {
+    conn: Connection,
+}
+
+impl UsageService {
+    pub fn new() -> Result<Self> {
+        let mut conn = Connection::open("history.db")?;
+        migrations::migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Counts one request, keeping the last rate-limit numbers the provider reported
+    pub fn record_request(
+        &self,
+        provider: &str,
+        window_start: i64,
+        reported_limit: Option<u32>,
+        reported_remaining: Option<u32>,
+    ) -> rusqlite::Result<()> {
+        let now = Utc::now().timestamp();
+
+        self.conn.execute(
+            "INSERT INTO provider_usage
+                (provider, window_start, requests, reported_limit, reported_remaining, updated_at)
+            VALUES (?1, ?2, 1, ?3, ?4, ?5)
+            ON CONFLICT(provider, window_start) DO UPDATE SET
+                requests = requests + 1,
+                reported_limit = COALESCE(excluded.reported_limit, reported_limit),
+                reported_remaining = COALESCE(excluded.reported_remaining, reported_remaining),
+                updated_at = excluded.updated_at",
+            params![provider, window_start, reported_limit, reported_remaining, now],
+        )?;
+        Ok(())
+    }
+
+    /// (requests, reported_limit, reported_remaining) for a provider's window
+    pub fn get_usage(
+        &self,
+        provider: &str,
+        window_start: i64,
+    ) -> rusqlite::Result<(u32, Option<u32>, Option<u32>)> {
+        let usage = self
+            .conn
+            .query_row(
+                "SELECT requests, reported_limit, reported_remaining
+                FROM provider_usage WHERE provider = ?1 AND window_start = ?2",
+                params![provider, window_start],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        Ok(usage.unwrap_or((0, None, None)))
+    }
+}
+
+/// Shared handle the provider clients use to count their requests
+#[derive(Debug, Clone)]
+pub struct UsageTracker {
+    service: Arc<Mutex<UsageService>>,
+}
+
+impl UsageTracker {
+    pub fn new(service: UsageService) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(service)),
+        }
+    }
+
+    pub fn record(&self, provider: &str, limit: Option<u32>, remaining: Option<u32>) {
+        let Some((_, _, reset_hour)) = Self::free_tier(provider) else {
+            return;
+        };
+        let window_start = Self::window_start(Utc::now(), reset_hour);
+
+        if let Ok(service) = self.service.lock() {
+            if let Err(e) = service.record_request(provider, window_start.timestamp(), limit, remaining) {
+                eprintln!("Failed to record {} usage: {}", provider, e);
+            }
+        }
+    }
+
+    /// Usage in the current quota window, `None` for sources without a quota (RSS, custom endpoints)
+    pub fn usage(&self, provider: &str) -> Option<ProviderUsage> {
+        let (_, free_limit, reset_hour) = Self::free_tier(provider)?;
+        let window_start = Self::window_start(Utc::now(), reset_hour);
+
+        let service = self.service.lock().ok()?;
+        let (requests, reported_limit, reported_remaining) = service
+            .get_usage(provider, window_start.timestamp())
+            .ok()?;
+
+        Some(ProviderUsage {
+            provider: provider.to_string(),
+            requests,
+            daily_limit: reported_limit.unwrap_or(free_limit),
+            reported_remaining,
+            resets_at: window_start + Duration::days(1),
+        })
+    }
+
+    pub fn all_usage(&self) -> Vec<ProviderUsage> {
+        FREE_TIERS
+            .iter()
+            .filter_map(|(provider, _, _)| self.usage(provider))
+            .collect()
+    }
+
+    fn free_tier(provider: &str) -> Option<(&'static str, u32, u32)> {
+        FREE_TIERS.iter().find(|(name, _, _)| *name == provider).copied()
+    }
+
+    /// Start of the quota window containing `now`, for a quota that resets daily at `reset_hour` UTC
+    fn window_start(now: DateTime<Utc>, reset_hour: u32) -> DateTime<Utc> {
+        let reset_today = now
+            .with_hour(reset_hour)
+            .and_then(|t| t.with_minute(0))
+            .and_then(|t| t.with_second(0))
+            .and_then(|t| t.with_nanosecond(0))
+            .unwrap_or(now);
+
+        if reset_today > now {
+            reset_today - Duration::days(1)
+        } else {
+            reset_today
+        }
+    }
+}
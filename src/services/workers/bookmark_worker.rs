@@ -0,0 +1,80 @@
+use relm4::{ComponentSender, Worker};
+
+use crate::{
+    pages::saved_page::{SavedPage, SavedPageInput},
+    services::bookmark_service::bookmarks::BookmarkService,
+    types::persistent_articel::PersistentArticle,
+};
+
+#[derive(Debug)]
+pub struct BookmarkWorker {
+    service: BookmarkService,
+    subscribers: Vec<relm4::ComponentSender<SavedPage>>,
+}
+
+#[derive(Debug)]
+pub enum BookmarkWorkerInput {
+    Fetch,
+    Save(PersistentArticle),
+    Delete(String),
+    /// Folder and tags for a bookmark
+    Organise(String, Option<String>, Vec<String>),
+    RenameTag(String, String),
+    Subscribe(relm4::ComponentSender<SavedPage>),
+}
+
+impl Worker for BookmarkWorker {
+    type Init = BookmarkService;
+    type Input = BookmarkWorkerInput;
+    type Output = ();
+
+    fn init(service: Self::Init, _sender: ComponentSender<Self>) -> Self {
+        Self {
+            service,
+            subscribers: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            BookmarkWorkerInput::Fetch => self.notify(),
+            BookmarkWorkerInput::Save(article) => {
+                if let Err(e) = self.service.save_bookmark(article) {
+                    eprintln!("Failed to save bookmark: {}", e);
+                }
+                self.notify();
+            }
+            BookmarkWorkerInput::Delete(id) => {
+                if let Err(e) = self.service.delete_bookmark(id) {
+                    eprintln!("Failed to delete bookmark: {}", e);
+                }
+                self.notify();
+            }
+            BookmarkWorkerInput::Organise(id, folder, tags) => {
+                if let Err(e) = self.service.organise_bookmark(id, folder, tags) {
+                    eprintln!("Failed to organise bookmark: {}", e);
+                }
+                self.notify();
+            }
+            BookmarkWorkerInput::RenameTag(from, to) => {
+                if let Err(e) = self.service.rename_tag(from, to) {
+                    eprintln!("Failed to rename tag: {}", e);
+                }
+                self.notify();
+            }
+            BookmarkWorkerInput::Subscribe(sender) => {
+                self.subscribers.push(sender);
+            }
+        }
+    }
+}
+
+impl BookmarkWorker {
+    fn notify(&self) {
+        if let Ok(bookmarks) = self.service.get_all_bookmarks() {
+            for subscriber in &self.subscribers {
+                subscriber.input(SavedPageInput::UpdateBookmarks(bookmarks.clone()));
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use relm4::{ComponentSender, Worker};
+
+use crate::{
+    components::sidebar::{SideBar, SidebarInput},
+    services::custom_endpoint_service::endpoints::EndpointService,
+    types::custom_endpoint::CustomEndpoint,
+};
+
+#[derive(Debug)]
+pub struct EndpointWorker {
+    service: EndpointService,
+    subscribers: Vec<relm4::ComponentSender<SideBar>>,
+}
+
+#[derive(Debug)]
+pub enum EndpointWorkerInput {
+    Fetch,
+    Save(CustomEndpoint),
+    Delete(i64),
+    Subscribe(relm4::ComponentSender<SideBar>),
+}
+
+impl Worker for EndpointWorker {
+    type Init = EndpointService;
+    type Input = EndpointWorkerInput;
+    type Output = ();
+
+    fn init(service: Self::Init, _sender: ComponentSender<Self>) -> Self {
+        Self {
+            service,
+            subscribers: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            EndpointWorkerInput::Fetch => self.notify(),
+            EndpointWorkerInput::Save(endpoint) => {
+                if let Err(e) = self.service.save_endpoint(endpoint) {
+                    eprintln!("Failed to save endpoint: {}", e);
+                }
+                self.notify();
+            }
+            EndpointWorkerInput::Delete(id) => {
+                if let Err(e) = self.service.delete_endpoint(id) {
+                    eprintln!("Failed to delete endpoint: {}", e);
+                }
+                self.notify();
+            }
+            EndpointWorkerInput::Subscribe(sender) => {
+                self.subscribers.push(sender);
+            }
+        }
+    }
+}
+
+impl EndpointWorker {
+    fn notify(&self) {
+        if let Ok(endpoints) = self.service.get_all_endpoints() {
+            for subscriber in &self.subscribers {
+                subscriber.input(SidebarInput::UpdateEndpoints(endpoints.clone()));
+            }
+        }
+    }
+}
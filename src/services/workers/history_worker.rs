@@ -1,79 +1,303 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use relm4::{ComponentSender, Worker};
 
 use crate::{
     components::sidebar::{SideBar, SidebarInput},
-    pages::history_page::{HistoryPage, HistoryPageInput},
-    services::history_service::history::HistoryService,
-    types::persistent_articel::PersistentArticle,
+    pages::{
+        history_page::{HistoryPage, HistoryPageInput},
+        stats_page::{StatsPage, StatsPageInput},
+    },
+    services::{
+        bookmark_service::bookmarks::BookmarkService,
+        history_service::{
+            export::{self, ExportFormat},
+            history::HistoryService,
+        },
+        news_settings_service::settings::NewsServiceSettings,
+    },
+    types::{
+        history_filter::HistoryFilter, persistent_articel::PersistentArticle,
+        visit_origin::VisitOrigin,
+    },
 };
 
+/// How many more entries are read each time the history page scrolls to the bottom
+const PAGE_SIZE: usize = 50;
+/// Retention limits are enforced at startup and then this often
+const PRUNE_INTERVAL_SECS: u32 = 60 * 60;
+/// How long a deletion can be undone from the history page
+pub const UNDO_SECS: u32 = 10;
+/// Extra wait before purging, so an Undo clicked as the toast closes still arrives first
+const PURGE_GRACE_SECS: u32 = 5;
+/// How many sources and sections the statistics rank
+const STATS_TOP: usize = 5;
+
 #[derive(Debug)]
 pub struct HistoryWorker {
     service: HistoryService,
+    // saved articles go into exports and imports along with the history
+    bookmarks: BookmarkService,
+    settings: NewsServiceSettings,
     // List of senders to notify (the Sidebar, etc.)
     subscribers: Vec<relm4::ComponentSender<HistoryPage>>,
+    filter: HistoryFilter,
+    // entries loaded so far, grows by PAGE_SIZE with every LoadMore
+    limit: usize,
 }
 
 #[derive(Debug)]
 pub enum HistoryWorkerInput {
     Fetch,
+    Filter(HistoryFilter),
+    LoadMore,
     DeleterAll,
-    Save(PersistentArticle),
+    ClearSince(i64),
+    Undo(i64),
+    Purge(i64),
+    Prune,
+    Save(PersistentArticle, VisitOrigin),
+    // seconds an article was open for
+    AddReadTime(String, i64),
+    Stats(ComponentSender<StatsPage>),
     Delete(String),
+    Export(PathBuf, ExportFormat),
+    Import(PathBuf),
+    // bulk actions on the entries selected on the history page
+    DeleteMany(Vec<String>),
+    BookmarkMany(Vec<String>),
+    ExportMany(Vec<String>, PathBuf, ExportFormat),
     Subscribe(relm4::ComponentSender<HistoryPage>),
 }
 
+impl HistoryWorker {
+    /// Offers to undo a deletion on the history page, and purges it once that chance is gone
+    fn trashed(&self, trashed: rusqlite::Result<(i64, usize)>, sender: &ComponentSender<Self>) {
+        match trashed {
+            Ok((_, 0)) => {}
+            Ok((batch, count)) => {
+                self.notify();
+                for subscriber in &self.subscribers {
+                    subscriber.input(HistoryPageInput::Trashed(batch, count));
+                }
+
+                let sender = sender.clone();
+                gtk::glib::timeout_add_seconds_local_once(
+                    UNDO_SECS + PURGE_GRACE_SECS,
+                    move || {
+                        sender.input(HistoryWorkerInput::Purge(batch));
+                    },
+                );
+            }
+            Err(e) => eprintln!("Failed to delete history: {}", e),
+        }
+    }
+
+    /// Tells the history page how an export or import went
+    fn report(&self, message: String) {
+        for subscriber in &self.subscribers {
+            subscriber.input(HistoryPageInput::ShowResult(message.clone()));
+        }
+    }
+
+    /// Reports the outcome of writing an export to `path`
+    fn exported(&self, exported: Result<(), String>, path: &Path) {
+        match exported {
+            Ok(()) => self.report(format!("Exported to {}", file_name(path))),
+            Err(e) => {
+                eprintln!("History export failed: {}", e);
+                self.report(format!("Export failed: {}", e));
+            }
+        }
+    }
+
+    /// Sends the loaded entries to every subscriber, along with whether more are left
+    fn notify(&self) {
+        // one extra row tells whether there is another page without counting them all
+        let Ok(mut history) = self.service.search_history(&self.filter, self.limit + 1, 0) else {
+            return;
+        };
+        let has_more = history.len() > self.limit;
+        history.truncate(self.limit);
+
+        for subscriber in &self.subscribers {
+            subscriber.input(HistoryPageInput::UpdateHistory(history.clone(), has_more));
+        }
+    }
+}
+
 impl Worker for HistoryWorker {
-    type Init = HistoryService;
+    type Init = (HistoryService, BookmarkService, NewsServiceSettings);
     type Input = HistoryWorkerInput;
     type Output = Vec<Arc<PersistentArticle>>;
 
-    fn init(service: Self::Init, _sender: ComponentSender<Self>) -> Self {
+    fn init(init: Self::Init, sender: ComponentSender<Self>) -> Self {
+        let (service, bookmarks, settings) = init;
+
+        // deletions whose undo window closed with the app last time
+        if let Err(e) = service.purge_all() {
+            eprintln!("Failed to purge deleted history: {}", e);
+        }
+
+        sender.input(HistoryWorkerInput::Prune);
+        // not on every settings change: stepping a limit down one day at a time would
+        // delete history at each step on the way to the value the user wanted
+        gtk::glib::timeout_add_seconds_local(PRUNE_INTERVAL_SECS, move || {
+            sender.input(HistoryWorkerInput::Prune);
+            gtk::glib::ControlFlow::Continue
+        });
+
         Self {
             service,
+            bookmarks,
+            settings,
             subscribers: Vec::new(),
+            filter: HistoryFilter::default(),
+            limit: PAGE_SIZE,
         }
     }
 
-    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>) {
         match msg {
             HistoryWorkerInput::Fetch => {
-                if let Ok(recent) = self.service.get_all_history() {
-                    for sub in &self.subscribers {
-                        sub.input(HistoryPageInput::UpdateHistory(recent.clone()));
-                    }
-                }
+                self.notify();
+            }
+            HistoryWorkerInput::Filter(filter) => {
+                self.filter = filter;
+                self.limit = PAGE_SIZE;
+                self.notify();
+            }
+            HistoryWorkerInput::LoadMore => {
+                self.limit += PAGE_SIZE;
+                self.notify();
             }
             HistoryWorkerInput::DeleterAll => {
-                if let Ok(_) = self.service.clear_all_history() {
-                    if let Ok(updated_history) = self.service.get_all_history() {
-                        for subscriber in &self.subscribers {
-                            let _ = subscriber
-                                .input(HistoryPageInput::UpdateHistory(updated_history.clone()));
-                        }
-                    }
+                self.limit = PAGE_SIZE;
+                let trashed = self.service.trash_all();
+                self.trashed(trashed, &sender);
+            }
+            HistoryWorkerInput::ClearSince(since) => {
+                let trashed = self.service.trash_since(since);
+                self.trashed(trashed, &sender);
+            }
+            HistoryWorkerInput::Undo(batch) => match self.service.restore(batch) {
+                Ok(_) => self.notify(),
+                Err(e) => eprintln!("Failed to restore history: {}", e),
+            },
+            HistoryWorkerInput::Purge(batch) => {
+                if let Err(e) = self.service.purge(batch) {
+                    eprintln!("Failed to purge deleted history: {}", e);
+                }
+            }
+            HistoryWorkerInput::Prune => {
+                match self.service.prune(&self.settings.history_retention()) {
+                    Ok(0) => {}
+                    Ok(_) => self.notify(),
+                    Err(e) => eprintln!("Failed to prune history: {}", e),
+                }
+            }
+            HistoryWorkerInput::Save(article, origin) => {
+                if self.settings.history_retention().private_mode {
+                    return;
                 }
+
+                let _ = self.service.save_to_history(article, &origin);
+                self.notify();
             }
-            HistoryWorkerInput::Save(article) => {
-                let _ = self.service.save_to_history(article);
+            HistoryWorkerInput::AddReadTime(id, secs) => {
+                if self.settings.history_retention().private_mode {
+                    return;
+                }
 
-                if let Ok(recent) = self.service.get_all_history() {
-                    for sub in &self.subscribers {
-                        sub.input(HistoryPageInput::UpdateHistory(recent.clone()));
-                    }
+                if let Err(e) = self.service.add_read_time(&id, secs) {
+                    eprintln!("Failed to record reading time: {}", e);
                 }
             }
+            HistoryWorkerInput::Stats(page) => match self.service.reading_stats(STATS_TOP) {
+                Ok(stats) => page.input(StatsPageInput::Update(stats)),
+                Err(e) => eprintln!("Failed to read history statistics: {}", e),
+            },
             HistoryWorkerInput::Delete(id) => {
-                if let Ok(_) = self.service.delete_entry(id) {
-                    if let Ok(updated_history) = self.service.get_all_history() {
+                let trashed = self.service.trash_entries(&[id]);
+                self.trashed(trashed, &sender);
+            }
+            HistoryWorkerInput::Export(path, format) => {
+                let exported = self
+                    .service
+                    .get_all_records()
+                    .and_then(|history| Ok((history, self.bookmarks.get_all_bookmarks()?)))
+                    .map_err(|e| e.to_string())
+                    .and_then(|(history, saved)| {
+                        std::fs::write(&path, export::write(format, &history, &saved))
+                            .map_err(|e| e.to_string())
+                    });
+
+                self.exported(exported, &path);
+            }
+            HistoryWorkerInput::Import(path) => {
+                let imported = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| export::read_json(&json).map_err(|e| e.to_string()))
+                    .and_then(|(history, saved)| {
+                        let visits = self
+                            .service
+                            .import_history(history)
+                            .map_err(|e| e.to_string())?;
+                        let bookmarks = self
+                            .bookmarks
+                            .import_bookmarks(saved)
+                            .map_err(|e| e.to_string())?;
+                        Ok((visits, bookmarks))
+                    });
+
+                match imported {
+                    Ok((visits, bookmarks)) => {
+                        self.report(format!(
+                            "Imported {} history entries and {} saved articles",
+                            visits, bookmarks
+                        ));
                         for subscriber in &self.subscribers {
-                            let _ = subscriber
-                                .input(HistoryPageInput::UpdateHistory(updated_history.clone()));
+                            subscriber.input(HistoryPageInput::SavedChanged);
                         }
                     }
+                    Err(e) => {
+                        eprintln!("History import failed: {}", e);
+                        self.report(format!("Could not import {}: {}", file_name(&path), e));
+                    }
                 }
+                self.notify();
+            }
+            HistoryWorkerInput::DeleteMany(ids) => {
+                let trashed = self.service.trash_entries(&ids);
+                self.trashed(trashed, &sender);
+            }
+            HistoryWorkerInput::BookmarkMany(ids) => {
+                let saved = self
+                    .service
+                    .get_entries(&ids)
+                    .and_then(|entries| self.bookmarks.save_bookmarks(&entries));
+
+                match saved {
+                    Ok(_) => {
+                        for subscriber in &self.subscribers {
+                            subscriber.input(HistoryPageInput::SavedChanged);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to save history entries: {}", e),
+                }
+            }
+            HistoryWorkerInput::ExportMany(ids, path, format) => {
+                let exported = self
+                    .service
+                    .get_records(&ids)
+                    .map_err(|e| e.to_string())
+                    .and_then(|entries| {
+                        std::fs::write(&path, export::write(format, &entries, &[]))
+                            .map_err(|e| e.to_string())
+                    });
+
+                self.exported(exported, &path);
             }
 
             HistoryWorkerInput::Subscribe(sender) => {
@@ -82,3 +306,10 @@ impl Worker for HistoryWorker {
         }
     }
 }
+
+/// The name shown for a file in export and import messages
+pub fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
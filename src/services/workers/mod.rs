@@ -1 +1,5 @@
-pub mod history_worker;
\ No newline at end of file
+pub mod bookmark_worker;
+pub mod endpoint_worker;
+pub mod history_worker;
+pub mod subscription_worker;
+pub mod topic_worker;
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use relm4::{ComponentSender, Worker};
+
+use crate::{
+    components::sidebar::{SideBar, SidebarInput},
+    rss::opml::Opml,
+    services::{
+        subscription_service::subscriptions::SubscriptionService,
+        workers::history_worker::file_name,
+    },
+    types::feed_subscription::FeedSubscription,
+};
+
+#[derive(Debug)]
+pub struct SubscriptionWorker {
+    service: SubscriptionService,
+    subscribers: Vec<relm4::ComponentSender<SideBar>>,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionWorkerInput {
+    Fetch,
+    Add(FeedSubscription),
+    Delete(i64),
+    ImportOpml(PathBuf),
+    ExportOpml(PathBuf),
+    Subscribe(relm4::ComponentSender<SideBar>),
+}
+
+impl Worker for SubscriptionWorker {
+    type Init = SubscriptionService;
+    type Input = SubscriptionWorkerInput;
+    type Output = ();
+
+    fn init(service: Self::Init, _sender: ComponentSender<Self>) -> Self {
+        Self {
+            service,
+            subscribers: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            SubscriptionWorkerInput::Fetch => self.notify(),
+            SubscriptionWorkerInput::Add(subscription) => {
+                if let Err(e) = self.service.add_subscription(subscription) {
+                    eprintln!("Failed to save subscription: {}", e);
+                }
+                self.notify();
+            }
+            SubscriptionWorkerInput::Delete(id) => {
+                if let Err(e) = self.service.delete_subscription(id) {
+                    eprintln!("Failed to delete subscription: {}", e);
+                }
+                self.notify();
+            }
+            SubscriptionWorkerInput::ImportOpml(path) => {
+                let imported = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|xml| Opml::parse(&xml).map_err(|e| e.to_string()))
+                    .and_then(|subscriptions| {
+                        self.service
+                            .add_subscriptions(subscriptions)
+                            .map_err(|e| e.to_string())
+                    });
+
+                match imported {
+                    Ok(count) => self.report(format!(
+                        "Imported {} feeds from {}",
+                        count,
+                        file_name(&path)
+                    )),
+                    Err(e) => {
+                        eprintln!("OPML import failed: {}", e);
+                        self.report(format!("Could not import {}: {}", file_name(&path), e));
+                    }
+                }
+                self.notify();
+            }
+            SubscriptionWorkerInput::ExportOpml(path) => {
+                if let Ok(subscriptions) = self.service.get_all_subscriptions() {
+                    if let Err(e) = std::fs::write(&path, Opml::write(&subscriptions)) {
+                        eprintln!("OPML export failed: {}", e);
+                    }
+                }
+            }
+            SubscriptionWorkerInput::Subscribe(sender) => {
+                self.subscribers.push(sender);
+            }
+        }
+    }
+}
+
+impl SubscriptionWorker {
+    /// Shows the outcome of an import to the user
+    fn report(&self, message: String) {
+        for subscriber in &self.subscribers {
+            subscriber.input(SidebarInput::ShowResult(message.clone()));
+        }
+    }
+
+    fn notify(&self) {
+        if let Ok(subscriptions) = self.service.get_all_subscriptions() {
+            for subscriber in &self.subscribers {
+                subscriber.input(SidebarInput::UpdateSubscriptions(subscriptions.clone()));
+            }
+        }
+    }
+}
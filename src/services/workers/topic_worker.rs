@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
+
+use relm4::{ComponentSender, Worker};
+
+use crate::{
+    NewsFetchService,
+    components::sidebar::{SideBar, SidebarInput},
+    services::topic_service::topics::TopicService,
+    types::{
+        article_page::PageInfo, fetch_error::FetchError, news_article::NewsArticle,
+        news_handler::NewsHandler, saved_topic::SavedTopic,
+    },
+};
+
+// every check costs a request per provider and topic, so keep it well inside the free tiers
+const CHECK_INTERVAL_SECS: u32 = 60 * 60;
+
+#[derive(Debug)]
+pub struct TopicWorker {
+    service: TopicService,
+    fetch_service: NewsFetchService,
+    subscribers: Vec<relm4::ComponentSender<SideBar>>,
+    // topics waiting for a background check, searched one at a time
+    queue: VecDeque<SavedTopic>,
+    checking: Option<SavedTopic>,
+}
+
+#[derive(Debug)]
+pub enum TopicWorkerInput {
+    Fetch,
+    Add(SavedTopic),
+    Delete(i64),
+    Move(i64, i32),
+    /// The topic page showed these articles, so nothing the topic matched is new any more
+    Seen(i64, Vec<String>),
+    CheckAll,
+    NewsReceived(BTreeMap<String, Vec<Arc<dyn NewsArticle>>>),
+    CheckFailed(FetchError),
+    Ignore,
+    Subscribe(relm4::ComponentSender<SideBar>),
+}
+
+impl NewsHandler for TopicWorkerInput {
+    fn on_news_received(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>) -> Self {
+        TopicWorkerInput::NewsReceived(grouped)
+    }
+    fn on_error(err: FetchError) -> Self {
+        TopicWorkerInput::CheckFailed(err)
+    }
+    // checks only look at the first page
+    fn on_page_loaded(_info: PageInfo) -> Self {
+        TopicWorkerInput::Ignore
+    }
+    fn on_fallback(_failed: String, _served_by: String) -> Self {
+        TopicWorkerInput::Ignore
+    }
+    fn on_offline(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, _fetched_at: i64) -> Self {
+        TopicWorkerInput::NewsReceived(grouped)
+    }
+}
+
+impl Worker for TopicWorker {
+    type Init = (TopicService, NewsFetchService);
+    type Input = TopicWorkerInput;
+    type Output = ();
+
+    fn init(init: Self::Init, sender: ComponentSender<Self>) -> Self {
+        let (service, fetch_service) = init;
+
+        sender.input(TopicWorkerInput::CheckAll);
+        gtk::glib::timeout_add_seconds_local(CHECK_INTERVAL_SECS, move || {
+            sender.input(TopicWorkerInput::CheckAll);
+            gtk::glib::ControlFlow::Continue
+        });
+
+        Self {
+            service,
+            fetch_service,
+            subscribers: Vec::new(),
+            queue: VecDeque::new(),
+            checking: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>) {
+        match msg {
+            TopicWorkerInput::Fetch => self.notify(),
+            TopicWorkerInput::Add(topic) => {
+                if let Err(e) = self.service.add_topic(topic) {
+                    eprintln!("Failed to save topic: {}", e);
+                }
+                self.notify();
+            }
+            TopicWorkerInput::Delete(id) => {
+                match self.service.delete_topic(id) {
+                    Ok(_) => self.queue.retain(|topic| topic.id != id),
+                    Err(e) => eprintln!("Failed to delete topic: {}", e),
+                }
+                self.notify();
+            }
+            TopicWorkerInput::Move(id, offset) => {
+                if let Err(e) = self.service.move_topic(id, offset) {
+                    eprintln!("Failed to move topic: {}", e);
+                }
+                self.notify();
+            }
+            TopicWorkerInput::Seen(id, urls) => {
+                if let Err(e) = self.service.mark_seen(id, urls) {
+                    eprintln!("Failed to mark topic as read: {}", e);
+                }
+                self.notify();
+            }
+            TopicWorkerInput::CheckAll => {
+                if let Ok(topics) = self.service.get_all_topics() {
+                    for topic in topics {
+                        if !self.queue.iter().any(|queued| queued.id == topic.id) {
+                            self.queue.push_back(topic);
+                        }
+                    }
+                }
+                if self.checking.is_none() {
+                    self.check_next(sender);
+                }
+            }
+            TopicWorkerInput::NewsReceived(grouped) => {
+                if let Some(topic) = self.checking.take() {
+                    let urls = grouped
+                        .values()
+                        .flatten()
+                        .map(|article| article.url())
+                        .collect();
+                    if let Err(e) = self.service.record_matches(topic.id, urls) {
+                        eprintln!("Failed to record matches for {}: {}", topic.name, e);
+                    }
+                    self.notify();
+                }
+                self.check_next(sender);
+            }
+            TopicWorkerInput::CheckFailed(e) => {
+                if let Some(topic) = self.checking.take() {
+                    eprintln!("Checking topic {} failed: {}", topic.name, e);
+                }
+                self.check_next(sender);
+            }
+            TopicWorkerInput::Ignore => {}
+            TopicWorkerInput::Subscribe(sender) => {
+                self.subscribers.push(sender);
+            }
+        }
+    }
+}
+
+impl TopicWorker {
+    fn check_next(&mut self, sender: ComponentSender<Self>) {
+        let Some(topic) = self.queue.pop_front() else {
+            return;
+        };
+
+        self.fetch_service.search(
+            topic.parameters(),
+            topic.source.clone(),
+            1,
+            BTreeMap::new(),
+            sender,
+        );
+        self.checking = Some(topic);
+    }
+
+    fn notify(&self) {
+        if let Ok(topics) = self.service.get_all_topics() {
+            for subscriber in &self.subscribers {
+                subscriber.input(SidebarInput::UpdateTopics(topics.clone()));
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+/// A Markdown note and the passages a reader highlighted in one article
+#[derive(Debug, Clone, Default)]
+pub struct ArticleNote {
+    /// Same id as the article's history entry, see `PersistentArticle::id_for`
+    pub article_id: String,
+    pub title: String,
+    pub url: String,
+    pub body: String,
+    pub highlights: Vec<String>,
+    pub updated_at: i64,
+}
+
+impl ArticleNote {
+    pub fn new(article_id: String, title: String, url: String) -> Self {
+        Self {
+            article_id,
+            title,
+            url,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.body.trim().is_empty() && self.highlights.is_empty()
+    }
+
+    /// All notes as one Markdown document, highlights quoted above each note
+    pub fn to_markdown(notes: &[ArticleNote]) -> String {
+        let mut document = String::from("# FrostNews Notes\n");
+
+        for note in notes {
+            document.push_str(&format!("\n## [{}]({})\n\n", note.title, note.url));
+            for highlight in &note.highlights {
+                document.push_str(&format!("> {}\n\n", highlight.replace('\n', "\n> ")));
+            }
+            if !note.body.trim().is_empty() {
+                document.push_str(note.body.trim());
+                document.push('\n');
+            }
+        }
+        document
+    }
+}
@@ -0,0 +1,70 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::types::news_article::NewsArticle;
+
+/// Where a provider continues from: a page number, or the opaque token NewsData hands out
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageCursor {
+    Number(i32),
+    Token(String),
+}
+
+/// One page of results from a single provider
+#[derive(Debug, Clone)]
+pub struct ArticlePage {
+    pub articles: Vec<Arc<dyn NewsArticle>>,
+    pub total_results: Option<usize>,
+    /// `None` once the provider has nothing more to give
+    pub next: Option<PageCursor>,
+}
+
+impl ArticlePage {
+    pub fn new(articles: Vec<Arc<dyn NewsArticle>>) -> Self {
+        Self {
+            articles,
+            total_results: None,
+            next: None,
+        }
+    }
+
+    pub fn total_results(mut self, total: usize) -> Self {
+        self.total_results = Some(total);
+        self
+    }
+
+    pub fn next(mut self, cursor: Option<PageCursor>) -> Self {
+        self.next = cursor;
+        self
+    }
+
+    /// Next page number for providers that count pages, stopping once `total_results` is covered
+    pub fn numbered(self, page: i32, page_size: i32) -> Self {
+        let next = match self.total_results {
+            Some(total) if (page * page_size) as usize >= total => None,
+            _ if self.articles.is_empty() => None,
+            _ => Some(PageCursor::Number(page + 1)),
+        };
+        self.next(next)
+    }
+}
+
+/// Sent along with every fetched page so the page knows how to ask for the following one
+#[derive(Debug, Clone, Default)]
+pub struct PageInfo {
+    /// Cursor per provider name, only for providers that have more results
+    pub next: BTreeMap<String, PageCursor>,
+    pub total_results: usize,
+}
+
+impl PageInfo {
+    pub fn add(&mut self, provider: &str, page: &ArticlePage) {
+        self.total_results += page.total_results.unwrap_or(page.articles.len());
+        if let Some(cursor) = &page.next {
+            self.next.insert(provider.to_string(), cursor.clone());
+        }
+    }
+
+    pub fn has_more(&self) -> bool {
+        !self.next.is_empty()
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::types::persistent_articel::PersistentArticle;
+
+/// A saved article, kept whole so it still opens after the provider drops it
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    pub article: Arc<PersistentArticle>,
+    pub saved_at: i64,
+    pub folder: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl Bookmark {
+    /// Splits comma separated user input into tags, dropping blanks and repeats
+    pub fn parse_tags(input: &str) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in input.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+            if !tags.iter().any(|known| known.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.to_string());
+            }
+        }
+        tags
+    }
+}
@@ -0,0 +1,11 @@
+/// A provider response body kept on disk together with its validators
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Unix timestamp of the last time the server confirmed the body
+    pub fetched_at: i64,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Where to find each article field in the endpoint's JSON response.
+/// Paths are dot separated, array items are addressed by index (`media.0.url`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldMapping {
+    /// Path to the array of articles, empty when the response itself is the array
+    pub articles: String,
+    pub title: String,
+    pub url: String,
+    pub image: Option<String>,
+    pub published_at: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomEndpoint {
+    #[serde(skip)]
+    pub id: i64,
+    pub name: String,
+    pub base_url: String,
+    #[serde(default)]
+    pub query_params: Vec<(String, String)>,
+    pub api_key_header: Option<String>,
+    pub api_key: Option<String>,
+    /// Query parameter that receives the page number, if the API paginates
+    pub page_param: Option<String>,
+    pub mapping: FieldMapping,
+}
+
+impl CustomEndpoint {
+    /// Parses `key=value&key2=value2` as typed into the settings dialog
+    pub fn parse_query_params(raw: &str) -> Vec<(String, String)> {
+        raw.split('&')
+            .filter_map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                let key = key.trim();
+                if key.is_empty() {
+                    None
+                } else {
+                    Some((key.to_string(), value.trim().to_string()))
+                }
+            })
+            .collect()
+    }
+}
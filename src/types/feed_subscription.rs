@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedSubscription {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub folder: Option<String>,
+}
+
+impl FeedSubscription {
+    /// A subscription that has not been stored yet (the id is assigned by SQLite)
+    pub fn new(title: impl Into<String>, url: impl Into<String>, folder: Option<String>) -> Self {
+        Self {
+            id: 0,
+            title: title.into(),
+            url: url.into(),
+            folder: folder.filter(|f| !f.trim().is_empty()),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::custom_api::data_structures::CustomApiError;
+use crate::gnews::data_structures::NewsError;
+use crate::news_api::data_structures::NewsAPICusteomError;
+use crate::newsdata::datap_structures::NewsdataError;
+use crate::rss::data_structures::RssError;
+use crate::utils::reqwest_error_extension::ReqwestErrorExt;
+
+/// Error returned by every `NewsClient`, grouped by what the user can do about it
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FetchError {
+    /// The API key is missing, invalid or not allowed to make the request
+    #[error("{0}")]
+    Auth(String),
+    /// The plan's request allowance is used up until the quota resets
+    #[error("{0}")]
+    Quota(String),
+    /// Too many requests in a short time, `retry_after` is in seconds when the provider says
+    #[error("{message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<u64>,
+    },
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Server(String),
+    #[error("{0}")]
+    Network(String),
+    #[error("{0}")]
+    Decode(String),
+}
+
+impl FetchError {
+    /// Errors tied to the provider or the key, where another provider may still succeed
+    pub fn should_fail_over(&self) -> bool {
+        matches!(
+            self,
+            FetchError::Auth(_) | FetchError::Quota(_) | FetchError::RateLimited { .. }
+        )
+    }
+
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            FetchError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Keeps the category but replaces the text, e.g. to list every provider that failed
+    pub fn with_message(self, message: String) -> Self {
+        match self {
+            FetchError::Auth(_) => FetchError::Auth(message),
+            FetchError::Quota(_) => FetchError::Quota(message),
+            FetchError::RateLimited { retry_after, .. } => FetchError::RateLimited {
+                message,
+                retry_after,
+            },
+            FetchError::BadRequest(_) => FetchError::BadRequest(message),
+            FetchError::Server(_) => FetchError::Server(message),
+            FetchError::Network(_) => FetchError::Network(message),
+            FetchError::Decode(_) => FetchError::Decode(message),
+        }
+    }
+
+    /// Categorises a bare HTTP status for sources that have no error vocabulary of their own
+    fn from_status(status: u16, message: String) -> Self {
+        match status {
+            401 | 403 => FetchError::Auth(message),
+            429 => FetchError::RateLimited {
+                message,
+                retry_after: None,
+            },
+            500..=599 => FetchError::Server(message),
+            _ => FetchError::BadRequest(message),
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_decode() {
+            FetchError::Decode(err.to_user_friendly_message())
+        } else {
+            FetchError::Network(err.to_user_friendly_message())
+        }
+    }
+}
+
+impl From<NewsError> for FetchError {
+    fn from(err: NewsError) -> Self {
+        match err {
+            NewsError::Unauthorized => FetchError::Auth(err.to_string()),
+            // GNews answers 403 once the daily quota is used up
+            NewsError::Forbidden => FetchError::Quota(err.to_string()),
+            NewsError::TooManyRequests(retry_after) => FetchError::RateLimited {
+                message: err.to_string(),
+                retry_after,
+            },
+            NewsError::BadRequest => FetchError::BadRequest(err.to_string()),
+            NewsError::InternalServerError | NewsError::ServiceUnavailable => {
+                FetchError::Server(err.to_string())
+            }
+            NewsError::Network(e) => e.into(),
+            NewsError::Unknown(status) => FetchError::from_status(status, err.to_string()),
+        }
+    }
+}
+
+impl From<NewsAPICusteomError> for FetchError {
+    fn from(err: NewsAPICusteomError) -> Self {
+        match err {
+            NewsAPICusteomError::Api {
+                ref code,
+                retry_after,
+                ..
+            } => match code.as_str() {
+                "apiKeyDisabled" | "apiKeyInvalid" | "apiKeyMissing" => {
+                    FetchError::Auth(err.to_string())
+                }
+                "apiKeyExhausted" => FetchError::Quota(err.to_string()),
+                "rateLimited" => FetchError::RateLimited {
+                    message: err.to_string(),
+                    retry_after,
+                },
+                "unexpectedError" => FetchError::Server(err.to_string()),
+                _ => FetchError::BadRequest(err.to_string()),
+            },
+            NewsAPICusteomError::Network(e) => e.into(),
+            NewsAPICusteomError::Serialization(_) => FetchError::Decode(err.to_string()),
+            NewsAPICusteomError::Unknown => FetchError::Server(err.to_string()),
+        }
+    }
+}
+
+impl From<NewsdataError> for FetchError {
+    fn from(err: NewsdataError) -> Self {
+        match err {
+            NewsdataError::Unauthorized | NewsdataError::Forbidden => {
+                FetchError::Auth(err.to_string())
+            }
+            NewsdataError::RateLimitExceeded(retry_after) => FetchError::RateLimited {
+                message: err.to_string(),
+                retry_after,
+            },
+            NewsdataError::InternalServerError => FetchError::Server(err.to_string()),
+            NewsdataError::NetworkError(e) => e.into(),
+            NewsdataError::Unknown(status) => FetchError::from_status(status, err.to_string()),
+            _ => FetchError::BadRequest(err.to_string()),
+        }
+    }
+}
+
+impl From<RssError> for FetchError {
+    fn from(err: RssError) -> Self {
+        match err {
+            RssError::Network(e) => e.into(),
+            RssError::Http(status) => FetchError::from_status(status, err.to_string()),
+            RssError::Parse(_) | RssError::UnsupportedFormat => FetchError::Decode(err.to_string()),
+            RssError::NoFeeds => FetchError::BadRequest(err.to_string()),
+        }
+    }
+}
+
+impl From<CustomApiError> for FetchError {
+    fn from(err: CustomApiError) -> Self {
+        match err {
+            CustomApiError::Network(e) => e.into(),
+            CustomApiError::Http(status) => FetchError::from_status(status, err.to_string()),
+            CustomApiError::Decode(_) | CustomApiError::MissingArticles(_) => {
+                FetchError::Decode(err.to_string())
+            }
+            CustomApiError::InvalidUrl(_) => FetchError::BadRequest(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+/// Narrows the reading history down to a full-text query and a window of visit times
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryFilter {
+    pub query: String,
+    /// Earliest visit time shown, in unix seconds
+    pub since: Option<i64>,
+    /// Visits at or after this time are left out, in unix seconds
+    pub until: Option<i64>,
+}
+
+impl HistoryFilter {
+    pub fn is_empty(&self) -> bool {
+        self.query.trim().is_empty() && self.since.is_none() && self.until.is_none()
+    }
+
+    /// FTS5 expression for the query. Every word has to appear, matched as a prefix, and is
+    /// quoted so characters like `-` or `:` are not read as query syntax.
+    pub fn match_expression(&self) -> Option<String> {
+        let terms: Vec<String> = self
+            .query
+            .split_whitespace()
+            .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+            .collect();
+
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::persistent_articel::PersistentArticle;
+
+/// A history entry together with its visit statistics, as exported and imported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    #[serde(flatten)]
+    pub article: PersistentArticle,
+    /// Archives from before visits were counted have none, the visit time stands in
+    #[serde(default)]
+    pub first_visit: Option<i64>,
+    #[serde(default = "single_visit")]
+    pub visit_count: i64,
+    #[serde(default)]
+    pub read_secs: i64,
+}
+
+impl HistoryRecord {
+    pub fn first_visit(&self) -> i64 {
+        self.first_visit.unwrap_or(self.article.visit_time)
+    }
+}
+
+fn single_visit() -> i64 {
+    1
+}
@@ -0,0 +1,10 @@
+/// How long reading history is kept. Zero for either limit means no limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HistoryRetention {
+    /// Visits older than this many days are removed
+    pub keep_days: u32,
+    /// Only the most recent entries up to this count are kept
+    pub max_entries: u32,
+    /// Nothing new is recorded while on
+    pub private_mode: bool,
+}
@@ -1,10 +1,25 @@
 pub mod app_config;
+pub mod article_note;
+pub mod article_page;
+pub mod bookmark;
 pub mod cache;
+pub mod cached_response;
+pub mod custom_endpoint;
+pub mod feed_subscription;
+pub mod fetch_error;
+pub mod history_filter;
+pub mod history_record;
+pub mod history_retention;
 pub mod news_article;
 pub mod news_category;
 pub mod news_client;
 pub mod news_handler;
 pub mod news_language;
 pub mod news_source;
+pub mod provider_usage;
+pub mod reading_stats;
 pub mod request_parameters;
+pub mod saved_topic;
+pub mod stored_article;
+pub mod visit_origin;
 pub mod  persistent_articel;
\ No newline at end of file
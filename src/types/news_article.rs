@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::{newsdata::datap_structures::NewsDataArticle, types::persistent_articel::PersistentArticle};
+use crate::{custom_api::data_structures::CustomArticle, newsdata::datap_structures::NewsDataArticle, rss::data_structures::FeedItem, types::{persistent_articel::PersistentArticle, stored_article::StoredArticle}};
 
 
 pub trait NewsArticle: std::fmt::Debug + Send + Sync {
@@ -13,6 +13,21 @@ pub trait NewsArticle: std::fmt::Debug + Send + Sync {
     fn content(&self) -> Option<String>;
     fn source(&self) -> String;
     fn video_url(&self) ->  Option<String>;
+
+    /// Name of the provider that served the article, set when several providers are aggregated
+    fn provider(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the provider itself flagged the article as a copy of another story
+    fn is_duplicate(&self) -> bool {
+        false
+    }
+
+    /// Other copies of the same story that were collapsed into this one
+    fn coverage(&self) -> Vec<Arc<dyn NewsArticle>> {
+        Vec::new()
+    }
 }
 
 
@@ -26,6 +41,62 @@ impl<T: NewsArticle + ?Sized> NewsArticle for Arc<T> {
     fn content(&self) -> Option<String> { (**self).content() }
     fn source(&self) -> String { (**self).source() }
     fn video_url(&self) ->  Option<String>{(**self).video_url()}
+    fn provider(&self) -> Option<String> { (**self).provider() }
+    fn is_duplicate(&self) -> bool { (**self).is_duplicate() }
+    fn coverage(&self) -> Vec<Arc<dyn NewsArticle>> { (**self).coverage() }
+}
+
+/// Wraps an article with the name of the provider it came from
+#[derive(Debug, Clone)]
+pub struct TaggedArticle {
+    pub provider: String,
+    pub article: Arc<dyn NewsArticle>,
+}
+
+impl TaggedArticle {
+    pub fn tag(provider: impl Into<String>, article: Arc<dyn NewsArticle>) -> Arc<dyn NewsArticle> {
+        Arc::new(Self {
+            provider: provider.into(),
+            article,
+        })
+    }
+}
+
+impl NewsArticle for TaggedArticle {
+    fn author(&self) -> Option<String> { self.article.author() }
+    fn title(&self) -> String { self.article.title() }
+    fn description(&self) -> Option<String> { self.article.description() }
+    fn url(&self) -> String { self.article.url() }
+    fn url_to_image(&self) -> Option<String> { self.article.url_to_image() }
+    fn published_at(&self) -> String { self.article.published_at() }
+    fn content(&self) -> Option<String> { self.article.content() }
+    fn source(&self) -> String { self.article.source() }
+    fn video_url(&self) ->  Option<String>{ self.article.video_url() }
+    fn provider(&self) -> Option<String> { Some(self.provider.clone()) }
+    fn is_duplicate(&self) -> bool { self.article.is_duplicate() }
+    fn coverage(&self) -> Vec<Arc<dyn NewsArticle>> { self.article.coverage() }
+}
+
+/// A story together with the copies of it that other outlets or providers published
+#[derive(Debug, Clone)]
+pub struct CoveredArticle {
+    pub article: Arc<dyn NewsArticle>,
+    pub coverage: Vec<Arc<dyn NewsArticle>>,
+}
+
+impl NewsArticle for CoveredArticle {
+    fn author(&self) -> Option<String> { self.article.author() }
+    fn title(&self) -> String { self.article.title() }
+    fn description(&self) -> Option<String> { self.article.description() }
+    fn url(&self) -> String { self.article.url() }
+    fn url_to_image(&self) -> Option<String> { self.article.url_to_image() }
+    fn published_at(&self) -> String { self.article.published_at() }
+    fn content(&self) -> Option<String> { self.article.content() }
+    fn source(&self) -> String { self.article.source() }
+    fn video_url(&self) ->  Option<String>{ self.article.video_url() }
+    fn provider(&self) -> Option<String> { self.article.provider() }
+    fn is_duplicate(&self) -> bool { self.article.is_duplicate() }
+    fn coverage(&self) -> Vec<Arc<dyn NewsArticle>> { self.coverage.clone() }
 }
 
 impl NewsArticle for crate::news_api::data_structures::NewsAPIArticle {
@@ -142,9 +213,88 @@ impl NewsArticle for NewsDataArticle{
     fn video_url(&self) ->  Option<String>{
        self.video_url.clone()
     }
+
+    fn is_duplicate(&self) -> bool {
+        self.duplicate
+    }
+}
+
+
+impl NewsArticle for FeedItem {
+    fn author(&self) -> Option<String> {
+        self.author.clone()
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn url_to_image(&self) -> Option<String> {
+        self.image_url.clone()
+    }
+
+    fn published_at(&self) -> String {
+        self.published_at.clone()
+    }
+
+    fn content(&self) -> Option<String> {
+        self.content.clone()
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    fn video_url(&self) ->  Option<String>{
+        None
+    }
 }
 
+impl NewsArticle for CustomArticle {
+    fn author(&self) -> Option<String> {
+        None
+    }
 
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn url_to_image(&self) -> Option<String> {
+        self.image_url.clone()
+    }
+
+    fn published_at(&self) -> String {
+        self.published_at.clone()
+    }
+
+    fn content(&self) -> Option<String> {
+        None
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    fn video_url(&self) ->  Option<String>{
+        None
+    }
+}
 
 impl NewsArticle for PersistentArticle{
     fn author(&self) -> Option<String> {
@@ -183,3 +333,41 @@ impl NewsArticle for PersistentArticle{
        None
     }
 }
+
+impl NewsArticle for StoredArticle {
+    fn author(&self) -> Option<String> {
+        self.author.clone()
+    }
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn url_to_image(&self) -> Option<String> {
+        self.image_url.clone()
+    }
+
+    fn published_at(&self) -> String {
+        self.published_at.clone()
+    }
+
+    fn content(&self) -> Option<String> {
+        self.content.clone()
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    fn video_url(&self) ->  Option<String>{
+       None
+    }
+}
@@ -1,3 +1,5 @@
+use crate::types::{custom_endpoint::CustomEndpoint, feed_subscription::FeedSubscription};
+
 #[derive(Debug, Clone)]
 pub enum NewsSection {
     General,
@@ -7,10 +9,39 @@ pub enum NewsSection {
     Technology,
     Science,
     Sports,
+    Feed(FeedSubscription),
+    CustomEndpoint(CustomEndpoint),
 }
 
 impl NewsSection {
     pub fn to_key(&self) -> String {
-        format!("{:?}", self)
+        match self {
+            Self::Feed(subscription) => format!("Feed({})", subscription.id),
+            Self::CustomEndpoint(endpoint) => format!("CustomEndpoint({})", endpoint.id),
+            _ => format!("{:?}", self),
+        }
+    }
+
+    /// The name shown for the section, the title for feeds and custom endpoints
+    pub fn label(&self) -> String {
+        match self {
+            Self::Feed(subscription) => subscription.title.clone(),
+            Self::CustomEndpoint(endpoint) => endpoint.name.clone(),
+            _ => format!("{:?}", self),
+        }
+    }
+
+    /// The topic name the news APIs use for this section, `None` for feeds and custom endpoints
+    pub fn category(&self) -> Option<&'static str> {
+        match self {
+            Self::General => Some("general"),
+            Self::Business => Some("business"),
+            Self::Health => Some("health"),
+            Self::Entertainment => Some("entertainment"),
+            Self::Technology => Some("technology"),
+            Self::Science => Some("science"),
+            Self::Sports => Some("sports"),
+            Self::Feed(_) | Self::CustomEndpoint(_) => None,
+        }
     }
 }
@@ -1,10 +1,17 @@
+use crate::custom_api::custom_api_client::CustomApiClient;
+use crate::custom_api::data_structures::CustomArticle;
 use crate::gnews::data_structures::{GNewsArticle, GNewsSource};
 use crate::gnews::gnews_client::GNewsClient;
 use crate::news_api::data_structures::{NewsAPIArticle, Source};
 use crate::news_api::news_api_client::NewsAPIClient;
 use crate::newsdata::datap_structures::NewsDataArticle;
 use crate::newsdata::newsdata_client::{NewsEndpoint, NewsdataClient};
+use crate::rss::data_structures::FeedItem;
+use crate::rss::rss_client::RssClient;
+use crate::types::article_page::{ArticlePage, PageCursor};
+use crate::types::fetch_error::FetchError;
 use crate::types::news_article::NewsArticle;
+use crate::types::news_category::NewsSection;
 use crate::types::request_parameters::RequestParameters;
 use crate::utils::generator::Generator;
 use std::sync::Arc;
@@ -19,9 +26,17 @@ pub trait NewsClient {
     async fn fetch_general(
         &self,
         parameters: RequestParameters,
-    ) -> Result<Vec<Arc<dyn NewsArticle>>, String>;
-    async fn fetch_business(&self) -> Result<Vec<Arc<dyn NewsArticle>>, String>;
-    async fn fetch_testnews(&self,  parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, String>;
+    ) -> Result<ArticlePage, FetchError>;
+    /// Top stories of a sidebar section, using the section as the provider's category
+    async fn fetch_section(
+        &self,
+        section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError>;
+    /// Articles matching `parameters.get_query()`, honouring the date range, language,
+    /// sort order and `search_in` filters where the provider supports them
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError>;
+    async fn fetch_testnews(&self,  parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError>;
 }
 
 #[async_trait]
@@ -57,7 +72,7 @@ impl NewsClient for NewsAPIClient {
     async fn fetch_general(
         &self,
         parameters: RequestParameters,
-    ) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    ) -> Result<ArticlePage, FetchError> {
         let response = self
             .fetch_top_headlines(
                 parameters.get_country(),
@@ -76,21 +91,40 @@ impl NewsClient for NewsAPIClient {
                     .into_iter()
                     .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
                     .collect();
-                Ok(arc_articles)
+                // NewsAPI sends 20 articles per page unless told otherwise
+                Ok(ArticlePage::new(arc_articles)
+                    .total_results(res.total_results.unwrap_or(0).max(0) as usize)
+                    .numbered(
+                        parameters.get_page().filter(|p| *p > 0).unwrap_or(1),
+                        parameters.get_page_size().unwrap_or(20),
+                    ))
             }
-            Err(e) => Err(e.to_string()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn fetch_section(
+        &self,
+        section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        match section.category() {
+            Some(category) => self.fetch_general(parameters.category(category)).await,
+            None => self.fetch_general(parameters).await,
         }
     }
 
-    async fn fetch_business(&self) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError> {
         let response = self
-            .fetch_top_headlines(
-                Some("us".into()),
-                Some("business".into()),
-                None,
-                None,
-                Some(100),
-                None,
+            .fetch_everything(
+                parameters.get_query(),
+                parameters.get_from(),
+                parameters.get_to(),
+                parameters.get_language(),
+                parameters.get_sort_by(),
+                parameters.get_search_in(),
+                parameters.get_page_size(),
+                parameters.get_page().filter(|p| *p > 0),
             )
             .await;
 
@@ -101,13 +135,19 @@ impl NewsClient for NewsAPIClient {
                     .into_iter()
                     .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
                     .collect();
-                Ok(arc_articles)
+                // /everything defaults to 100 articles per page
+                Ok(ArticlePage::new(arc_articles)
+                    .total_results(res.total_results.unwrap_or(0).max(0) as usize)
+                    .numbered(
+                        parameters.get_page().filter(|p| *p > 0).unwrap_or(1),
+                        parameters.get_page_size().unwrap_or(100),
+                    ))
             }
-            Err(e) => Err(e.to_string()),
+            Err(e) => Err(e.into()),
         }
     }
 
-    async fn fetch_testnews(&self,  _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    async fn fetch_testnews(&self,  _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError> {
         let mut articles = Vec::new();
         for i in 0..100 {
             let article = NewsAPIArticle {
@@ -137,7 +177,7 @@ impl NewsClient for NewsAPIClient {
 #[async_trait]
 impl NewsClient for GNewsClient {
     fn name(&self) -> &'static str {
-        "NewsAPI"
+        "GNews"
     }
 
     fn supported_languages(&self) -> Vec<(&'static str, &'static str)> {
@@ -193,13 +233,14 @@ impl NewsClient for GNewsClient {
     async fn fetch_general(
         &self,
         parameters: RequestParameters,
-    ) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    ) -> Result<ArticlePage, FetchError> {
         let response = self
             .fetch_top_headlines(
                 parameters.get_category(),
                 parameters.get_language(),
                 parameters.get_country(),
                 parameters.get_page_size(),
+                parameters.get_page().filter(|p| *p > 0),
             )
             .await;
 
@@ -210,15 +251,48 @@ impl NewsClient for GNewsClient {
                     .into_iter()
                     .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
                     .collect();
-                Ok(arc_articles)
+                // GNews sends 10 articles per page unless told otherwise
+                Ok(ArticlePage::new(arc_articles)
+                    .total_results(res.total_articles.max(0) as usize)
+                    .numbered(
+                        parameters.get_page().filter(|p| *p > 0).unwrap_or(1),
+                        parameters.get_page_size().unwrap_or(10),
+                    ))
             }
-            Err(e) => Err(e.to_string()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn fetch_section(
+        &self,
+        section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        match section.category() {
+            Some(category) => self.fetch_general(parameters.category(category)).await,
+            None => self.fetch_general(parameters).await,
         }
     }
 
-    async fn fetch_business(&self) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError> {
+        // GNews wants full timestamps and calls relevancy "relevance"
+        let sort_by = parameters.get_sort_by().map(|sort| match sort.as_str() {
+            "relevancy" => "relevance".to_string(),
+            _ => "publishedAt".to_string(),
+        });
+
         let response = self
-            .fetch_top_headlines(Some("business".into()), None, Some("us".into()), None)
+            .search(
+                parameters.get_query().unwrap_or_default(),
+                parameters.get_language(),
+                parameters.get_country(),
+                parameters.get_page_size(),
+                parameters.get_from().map(|date| format!("{}T00:00:00Z", date)),
+                parameters.get_to().map(|date| format!("{}T23:59:59Z", date)),
+                sort_by,
+                parameters.get_search_in(),
+                parameters.get_page().filter(|p| *p > 0),
+            )
             .await;
 
         match response {
@@ -228,13 +302,18 @@ impl NewsClient for GNewsClient {
                     .into_iter()
                     .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
                     .collect();
-                Ok(arc_articles)
+                Ok(ArticlePage::new(arc_articles)
+                    .total_results(res.total_articles.max(0) as usize)
+                    .numbered(
+                        parameters.get_page().filter(|p| *p > 0).unwrap_or(1),
+                        parameters.get_page_size().unwrap_or(10),
+                    ))
             }
-            Err(e) => Err(e.to_string()),
+            Err(e) => Err(e.into()),
         }
     }
 
-    async fn fetch_testnews(&self,   _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    async fn fetch_testnews(&self,   _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError> {
         let mut articles = Vec::new();
         for _ in 0..100 {
             let article = GNewsArticle {
@@ -277,18 +356,36 @@ impl NewsClient for NewsdataClient {
     async fn fetch_general(
         &self,
         parameters: RequestParameters,
-    ) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
-        let response = self
-            .endpoint(NewsEndpoint::Market)
-            .language("en")
-            .fetch()
-            .await
-            .map_err(|e| {
-                eprintln!("API Request failed: {}", e);
-                e.to_string()
-            })?;
+    ) -> Result<ArticlePage, FetchError> {
+        let cursor = parameters.get_cursor();
+
+        // NewsData pages with the token of the previous response, a page number alone leads nowhere
+        if cursor.is_none() && parameters.get_page().unwrap_or(0) > 1 {
+            return Ok(ArticlePage::new(Vec::new()));
+        }
+
+        let mut request = self.endpoint(NewsEndpoint::Latest);
+        if let Some(language) = parameters.get_language().filter(|l| !l.is_empty()) {
+            request = request.language(self.format_language_code(&language));
+        }
+        if let Some(country) = parameters.get_country().filter(|c| !c.is_empty()) {
+            request = request.country(country.to_lowercase());
+        }
+        if let Some(category) = parameters.get_category() {
+            request = request.category(category);
+        }
+        if let Some(query) = parameters.get_query() {
+            request = request.q(query);
+        }
+        if let Some(cursor) = cursor {
+            request = request.page(cursor);
+        }
+
+        let response = request.fetch().await.map_err(|e| {
+            eprintln!("API Request failed: {}", e);
+            FetchError::from(e)
+        })?;
 
-        // Now deserialization succeeds!
         let articles: Vec<Arc<dyn NewsArticle>> = response
             .results
             .into_iter()
@@ -299,35 +396,79 @@ impl NewsClient for NewsdataClient {
             })
             .collect();
 
-        Ok(articles)
+        let page = ArticlePage::new(articles).total_results(response.total_results.max(0) as usize);
+        let next = response
+            .next_page
+            .filter(|_| !page.articles.is_empty())
+            .map(PageCursor::Token);
+        Ok(page.next(next))
     }
 
-    async fn fetch_business(&self) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
-        let response = self
-            .endpoint(NewsEndpoint::Market)
-            .language("en")
-            .video(true)
-            .fetch()
-            .await
-            .map_err(|e| {
-                eprintln!("API Request failed: {}", e);
-                e.to_string()
-            })?;
+    async fn fetch_section(
+        &self,
+        section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        // NewsData calls its general headlines "top"
+        match section.category() {
+            Some("general") => self.fetch_general(parameters.category("top")).await,
+            Some(category) => self.fetch_general(parameters.category(category)).await,
+            None => self.fetch_general(parameters).await,
+        }
+    }
+
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError> {
+        let cursor = parameters.get_cursor();
+        if cursor.is_none() && parameters.get_page().unwrap_or(0) > 1 {
+            return Ok(ArticlePage::new(Vec::new()));
+        }
+
+        // only the archive endpoint takes a date range
+        let dated = parameters.get_from().is_some() || parameters.get_to().is_some();
+        let mut request = self.endpoint(if dated {
+            NewsEndpoint::Archive
+        } else {
+            NewsEndpoint::Latest
+        });
+
+        let query = parameters.get_query().unwrap_or_default();
+        request = match parameters.get_search_in().as_deref() {
+            Some("title") => request.q_in_title(query),
+            _ => request.q(query),
+        };
+        if let Some(language) = parameters.get_language().filter(|l| !l.is_empty()) {
+            request = request.language(self.format_language_code(&language));
+        }
+        if let Some(from) = parameters.get_from() {
+            request = request.from_date(from);
+        }
+        if let Some(to) = parameters.get_to() {
+            request = request.to_date(to);
+        }
+        if parameters.get_sort_by().as_deref() == Some("relevancy") {
+            request = request.sort("relevancy");
+        }
+        if let Some(cursor) = cursor {
+            request = request.page(cursor);
+        }
+
+        let response = request.fetch().await.map_err(FetchError::from)?;
 
         let articles: Vec<Arc<dyn NewsArticle>> = response
             .results
             .into_iter()
-            .map(|article_data| {
-                // Arc::new(article_data) works now because NewsDataArticle
-                // is compatible with the JSON strings.
-                Arc::new(article_data) as Arc<dyn NewsArticle>
-            })
+            .map(|article_data| Arc::new(article_data) as Arc<dyn NewsArticle>)
             .collect();
 
-        Ok(articles)
+        let page = ArticlePage::new(articles).total_results(response.total_results.max(0) as usize);
+        let next = response
+            .next_page
+            .filter(|_| !page.articles.is_empty())
+            .map(PageCursor::Token);
+        Ok(page.next(next))
     }
 
-    async fn fetch_testnews(&self,   _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, String> {
+    async fn fetch_testnews(&self,   _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError> {
         let mut articles = Vec::new();
         for _ in 0..100 {
             let article = GNewsArticle {
@@ -352,3 +493,179 @@ impl NewsClient for NewsdataClient {
         Ok(arc_articles)
     }
 }
+
+#[async_trait]
+impl NewsClient for RssClient {
+    fn name(&self) -> &'static str {
+        "RSS"
+    }
+
+    fn supported_languages(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("", "All Languages")]
+    }
+
+    fn format_language_code(&self, code: &str) -> String {
+        code.to_string()
+    }
+
+    async fn fetch_general(
+        &self,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        // Feeds are not paginated, everything arrives on the first page
+        if parameters.get_page().unwrap_or(0) > 1 {
+            return Ok(ArticlePage::new(Vec::new()));
+        }
+
+        let items = self.fetch_all().await.map_err(FetchError::from)?;
+        let query = parameters.get_query().map(|q| q.to_lowercase());
+
+        let arc_articles: Vec<Arc<dyn NewsArticle>> = items
+            .into_iter()
+            .filter(|item| match &query {
+                Some(q) => item.title.to_lowercase().contains(q),
+                None => true,
+            })
+            .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
+            .collect();
+        let total = arc_articles.len();
+        Ok(ArticlePage::new(arc_articles).total_results(total))
+    }
+
+    async fn fetch_section(
+        &self,
+        section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        let category = match section.category() {
+            Some("general") | None => return self.fetch_general(parameters).await,
+            Some(category) => category,
+        };
+
+        if parameters.get_page().unwrap_or(0) > 1 {
+            return Ok(ArticlePage::new(Vec::new()));
+        }
+
+        let items = self.fetch_all().await.map_err(FetchError::from)?;
+
+        let arc_articles: Vec<Arc<dyn NewsArticle>> = items
+            .into_iter()
+            .filter(|item| {
+                item.categories
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(category))
+            })
+            .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
+            .collect();
+        let total = arc_articles.len();
+        Ok(ArticlePage::new(arc_articles).total_results(total))
+    }
+
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError> {
+        // feeds have no search of their own, the headline filter of fetch_general does the job
+        self.fetch_general(parameters).await
+    }
+
+    async fn fetch_testnews(&self,  _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError> {
+        let mut articles = Vec::new();
+        for i in 0..100 {
+            let article = FeedItem {
+                title: "This is an example of a news title. It could even be as long as it wants that really doesn't depend on us but on the News Provider. We jsut have to render it right ".into(),
+                url: format!("https://test_feed_urls.com/{i}"),
+                description: Some("This is an example of a news Description. It could also even be as long as it wants that really doesn't depend on us but on the News Provider. We jsut have to render it right ".into()),
+                content: None,
+                image_url: Some("https://test_feed_urls.com".into()),
+                published_at: Generator::generate_date(),
+                author: Some("Authour Name".into()),
+                source: "Feed Name".into(),
+                categories: Vec::new(),
+            };
+            articles.push(article);
+        }
+
+        let arc_articles: Vec<Arc<dyn NewsArticle>> = articles
+            .into_iter()
+            .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
+            .collect();
+        Ok(arc_articles)
+    }
+}
+
+#[async_trait]
+impl NewsClient for CustomApiClient {
+    fn name(&self) -> &'static str {
+        "Custom Endpoint"
+    }
+
+    fn supported_languages(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("", "All Languages")]
+    }
+
+    fn format_language_code(&self, code: &str) -> String {
+        code.to_string()
+    }
+
+    async fn fetch_general(
+        &self,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        let page = parameters.get_page().filter(|p| *p > 0);
+
+        // Without a page parameter the endpoint only has a single page
+        if page.unwrap_or(1) > 1 && !self.is_paginated() {
+            return Ok(ArticlePage::new(Vec::new()));
+        }
+
+        let articles = self
+            .fetch_articles(page)
+            .await
+            .map_err(FetchError::from)?;
+
+        let arc_articles: Vec<Arc<dyn NewsArticle>> = articles
+            .into_iter()
+            .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
+            .collect();
+
+        // custom endpoints don't report a total, keep going until a page comes back empty
+        let next = (self.is_paginated() && !arc_articles.is_empty())
+            .then(|| PageCursor::Number(page.unwrap_or(1) + 1));
+        Ok(ArticlePage::new(arc_articles).next(next))
+    }
+
+    async fn fetch_section(
+        &self,
+        _section: &NewsSection,
+        parameters: RequestParameters,
+    ) -> Result<ArticlePage, FetchError> {
+        self.fetch_general(parameters).await
+    }
+
+    async fn search(&self, parameters: RequestParameters) -> Result<ArticlePage, FetchError> {
+        let query = parameters.get_query().unwrap_or_default().to_lowercase();
+        let mut page = self.fetch_general(parameters).await?;
+        page.articles
+            .retain(|article| article.title().to_lowercase().contains(&query));
+        Ok(page)
+    }
+
+    async fn fetch_testnews(&self,  _parameters: RequestParameters) -> Result<Vec<Arc<dyn NewsArticle>>, FetchError> {
+        let mut articles = Vec::new();
+        for i in 0..100 {
+            let article = CustomArticle {
+                title: "This is an example of a news title. It could even be as long as it wants that really doesn't depend on us but on the News Provider. We jsut have to render it right ".into(),
+                url: format!("https://test_endpoint_urls.com/{i}"),
+                image_url: Some("https://test_endpoint_urls.com".into()),
+                published_at: Generator::generate_date(),
+                description: Some("This is an example of a news Description. It could also even be as long as it wants that really doesn't depend on us but on the News Provider. We jsut have to render it right ".into()),
+                source: "Custom Endpoint".into(),
+            };
+            articles.push(article);
+        }
+
+        let arc_articles: Vec<Arc<dyn NewsArticle>> = articles
+            .into_iter()
+            .map(|a| Arc::new(a) as Arc<dyn NewsArticle>)
+            .collect();
+        Ok(arc_articles)
+    }
+}
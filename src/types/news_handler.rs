@@ -1,8 +1,15 @@
 use std::{collections::BTreeMap, sync::Arc};
 
-use crate::types::news_article::NewsArticle;
+use crate::types::{article_page::PageInfo, fetch_error::FetchError, news_article::NewsArticle};
 
 pub trait NewsHandler {
     fn on_news_received(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>) -> Self;
-    fn on_error(err: String) -> Self;
+    fn on_error(err: FetchError) -> Self;
+    /// Sent right before the articles of a page with the cursors to load the next one
+    fn on_page_loaded(info: PageInfo) -> Self;
+    /// Sent before the articles when the selected source failed and another one served the request
+    fn on_fallback(failed: String, served_by: String) -> Self;
+    /// Sent instead of an error when the network is down and stored articles are available,
+    /// `fetched_at` is the Unix time of the newest stored fetch
+    fn on_offline(grouped: BTreeMap<String, Vec<Arc<dyn NewsArticle>>>, fetched_at: i64) -> Self;
 }
@@ -1,7 +1,8 @@
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum NewsSource {
     NewsAPI,
     GNews,
     NewsData,
+    Rss,
 }
\ No newline at end of file
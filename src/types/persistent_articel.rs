@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersistentArticle {
     pub id: String,
     pub title: String,
@@ -21,9 +22,7 @@ impl PersistentArticle {
         published_at: String,
         image_url: String,
     ) -> Self {
-        let mut hasher = Sha256::new();
-        hasher.update(url.as_bytes());
-        let id = hex::encode(hasher.finalize());
+        let id = Self::id_for(&url);
         let now = chrono::Utc::now().timestamp();
 
         Self {
@@ -37,4 +36,11 @@ impl PersistentArticle {
             visit_time: now,
         }
     }
+
+    /// The id articles are stored under: the SHA-256 of their url
+    pub fn id_for(url: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(url.as_bytes());
+        hex::encode(hasher.finalize())
+    }
 }
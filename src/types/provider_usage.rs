@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+/// Requests made against one provider in its current quota window
+#[derive(Debug, Clone)]
+pub struct ProviderUsage {
+    pub provider: String,
+    pub requests: u32,
+    pub daily_limit: u32,
+    /// Remaining allowance as last reported by the provider's rate-limit headers
+    pub reported_remaining: Option<u32>,
+    pub resets_at: DateTime<Utc>,
+}
+
+impl ProviderUsage {
+    /// Best guess at what is left: the provider's own number when it sends one,
+    /// otherwise the free tier minus what this app has used
+    pub fn remaining(&self) -> u32 {
+        let counted = self.daily_limit.saturating_sub(self.requests);
+        match self.reported_remaining {
+            Some(reported) => reported.min(counted),
+            None => counted,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Days, NaiveDate};
+
+/// What the reading history adds up to. Deleted entries are left out.
+#[derive(Debug, Clone, Default)]
+pub struct ReadingStats {
+    /// Entries in the history, each article counted once
+    pub articles: usize,
+    pub visits: i64,
+    /// Time spent with articles open, in seconds
+    pub read_secs: i64,
+    /// Articles read on each day with reading, oldest first
+    pub per_day: Vec<(NaiveDate, usize)>,
+    /// Publishers with the most articles read, most first
+    pub top_sources: Vec<(String, usize)>,
+    /// Sections with the most articles read, most first
+    pub top_sections: Vec<(String, usize)>,
+}
+
+impl ReadingStats {
+    /// Days in a row with reading up to today. Today only breaks the streak once it is over.
+    pub fn current_streak(&self, today: NaiveDate) -> usize {
+        let days: HashSet<NaiveDate> = self.per_day.iter().map(|(day, _)| *day).collect();
+
+        let mut day = if days.contains(&today) {
+            Some(today)
+        } else {
+            today.pred_opt()
+        };
+        let mut streak = 0;
+        while let Some(current) = day.filter(|day| days.contains(day)) {
+            streak += 1;
+            day = current.pred_opt();
+        }
+        streak
+    }
+
+    pub fn longest_streak(&self) -> usize {
+        let mut longest = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+
+        for (day, _) in &self.per_day {
+            run = if previous.and_then(|previous| previous.succ_opt()) == Some(*day) {
+                run + 1
+            } else {
+                1
+            };
+            longest = longest.max(run);
+            previous = Some(*day);
+        }
+        longest
+    }
+
+    /// Articles read on each of the last `days` days up to today, oldest first, including
+    /// the days without reading
+    pub fn last_days(&self, today: NaiveDate, days: u64) -> Vec<(NaiveDate, usize)> {
+        let counts: HashMap<NaiveDate, usize> = self.per_day.iter().copied().collect();
+
+        (0..days)
+            .rev()
+            .filter_map(|back| today.checked_sub_days(Days::new(back)))
+            .map(|day| (day, counts.get(&day).copied().unwrap_or(0)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn stats(days: &[u32]) -> ReadingStats {
+        ReadingStats {
+            per_day: days.iter().map(|day| (date(*day), 2)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn counts_the_streak_up_to_today() {
+        assert_eq!(stats(&[8, 9, 10]).current_streak(date(10)), 3);
+    }
+
+    #[test]
+    fn keeps_yesterdays_streak_before_reading_today() {
+        assert_eq!(stats(&[8, 9]).current_streak(date(10)), 2);
+    }
+
+    #[test]
+    fn a_gap_breaks_the_streak() {
+        assert_eq!(stats(&[5, 6, 7, 9, 10]).current_streak(date(10)), 2);
+        assert_eq!(stats(&[5, 6, 7]).current_streak(date(10)), 0);
+        assert_eq!(stats(&[1, 2, 3, 4, 6, 7]).longest_streak(), 4);
+    }
+
+    #[test]
+    fn a_single_day_is_a_streak_of_one() {
+        let stats = stats(&[10]);
+        assert_eq!(stats.current_streak(date(10)), 1);
+        assert_eq!(stats.current_streak(date(11)), 1);
+        assert_eq!(stats.longest_streak(), 1);
+    }
+
+    #[test]
+    fn no_reading_means_no_streak() {
+        let stats = ReadingStats::default();
+        assert_eq!(stats.current_streak(date(10)), 0);
+        assert_eq!(stats.longest_streak(), 0);
+        assert_eq!(
+            stats.last_days(date(10), 3),
+            [(date(8), 0), (date(9), 0), (date(10), 0)]
+        );
+    }
+
+    #[test]
+    fn last_days_fills_days_without_reading() {
+        let stats = ReadingStats {
+            per_day: vec![(date(1), 4), (date(7), 1), (date(9), 3)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            stats.last_days(date(10), 4),
+            [(date(7), 1), (date(8), 0), (date(9), 3), (date(10), 0)]
+        );
+    }
+}
@@ -7,8 +7,12 @@ pub struct RequestParameters {
     to: Option<String>,
     page_size: Option<i32>,
     page: Option<i32>,
+    // token for providers that page with a cursor instead of numbers
+    cursor: Option<String>,
     category: Option<String>,
     sort_by: Option<String>,
+    // "title" limits a search to headlines
+    search_in: Option<String>,
 }
 
 impl RequestParameters {
@@ -21,8 +25,10 @@ impl RequestParameters {
             to: None,
             page_size: None,
             page: None,
+            cursor: None,
             category: None,
             sort_by: None,
+            search_in: None,
         }
     }
 }
@@ -56,6 +62,13 @@ impl RequestParameters {
         self
     }
 
+    /// Clears the from and to dates, for providers that cannot filter by them
+    pub fn without_dates(mut self) -> Self {
+        self.from = None;
+        self.to = None;
+        self
+    }
+
     pub fn page_size(mut self, size: i32) -> Self {
         self.page_size = Some(size);
         self
@@ -66,6 +79,11 @@ impl RequestParameters {
         self
     }
 
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
     pub fn category(mut self, category: impl Into<String>) -> Self {
         self.category = Some(category.into());
         self
@@ -76,6 +94,11 @@ impl RequestParameters {
         self
     }
 
+    pub fn search_in(mut self, search_in: impl Into<String>) -> Self {
+        self.search_in = Some(search_in.into());
+        self
+    }
+
     // --- GETTERS ---
     // Returning references to avoid unnecessary allocations when checking values.
 
@@ -107,6 +130,10 @@ impl RequestParameters {
         self.page
     }
 
+    pub fn get_cursor(&self) -> Option<String> {
+        self.cursor.clone()
+    }
+
     pub fn get_category(&self) -> Option<String> {
         self.category.clone()
     }
@@ -114,4 +141,8 @@ impl RequestParameters {
     pub fn get_sort_by(&self) -> Option<String> {
         self.sort_by.clone()
     }
+
+    pub fn get_search_in(&self) -> Option<String> {
+        self.search_in.clone()
+    }
 }
@@ -0,0 +1,56 @@
+use crate::types::{news_source::NewsSource, request_parameters::RequestParameters};
+
+/// A search saved under a name, shown in the sidebar and checked for new matches
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedTopic {
+    pub id: i64,
+    pub name: String,
+    pub query: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub language: Option<String>,
+    pub sort_by: String,
+    /// `None` searches every configured source
+    pub source: Option<NewsSource>,
+    pub title_only: bool,
+    /// Matches found since the topic was last opened
+    pub unread: u32,
+}
+
+impl SavedTopic {
+    /// A topic that has not been stored yet (the id is assigned by SQLite)
+    pub fn new(name: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            name: name.into(),
+            query: query.into(),
+            from: None,
+            to: None,
+            language: None,
+            sort_by: "publishedAt".to_string(),
+            source: None,
+            title_only: false,
+            unread: 0,
+        }
+    }
+
+    pub fn parameters(&self) -> RequestParameters {
+        let mut parameters = RequestParameters::new()
+            .query(self.query.clone())
+            .sort_by(self.sort_by.clone());
+
+        if let Some(from) = &self.from {
+            parameters = parameters.from(from.clone());
+        }
+        if let Some(to) = &self.to {
+            parameters = parameters.to(to.clone());
+        }
+        if let Some(language) = &self.language {
+            parameters = parameters.language(language.clone());
+        }
+        if self.title_only {
+            parameters = parameters.search_in("title");
+        }
+        parameters
+    }
+}
@@ -0,0 +1,42 @@
+use crate::types::news_article::NewsArticle;
+
+/// A fetched article kept in the local article store for offline reading
+#[derive(Debug, Clone)]
+pub struct StoredArticle {
+    pub section: String,
+    pub url: String,
+    pub provider: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub content: Option<String>,
+    pub image_url: Option<String>,
+    pub published_at: String,
+    pub source: String,
+    pub author: Option<String>,
+    pub fetched_at: i64,
+}
+
+impl StoredArticle {
+    pub fn from_article(
+        article: &dyn NewsArticle,
+        section: &str,
+        provider: &str,
+        fetched_at: i64,
+    ) -> Self {
+        Self {
+            section: section.to_string(),
+            url: article.url(),
+            provider: article
+                .provider()
+                .or_else(|| (!provider.is_empty()).then(|| provider.to_string())),
+            title: article.title(),
+            description: article.description(),
+            content: article.content(),
+            image_url: article.url_to_image(),
+            published_at: article.published_at(),
+            source: article.source(),
+            author: article.author(),
+            fetched_at,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// Where an opened article came from, kept with its history entry for the reading statistics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VisitOrigin {
+    /// The publisher, as the provider names it
+    pub source: Option<String>,
+    /// The section of the app the article was opened from, `None` for search results and
+    /// saved articles
+    pub section: Option<String>,
+}
+
+impl VisitOrigin {
+    /// Some providers give no publisher, an empty source is left out
+    pub fn new(source: String, section: Option<String>) -> Self {
+        Self {
+            source: Some(source).filter(|source| !source.trim().is_empty()),
+            section,
+        }
+    }
+}
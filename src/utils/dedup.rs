@@ -0,0 +1,301 @@
+use std::{collections::HashSet, sync::Arc};
+
+use url::Url;
+
+use crate::types::news_article::{CoveredArticle, NewsArticle};
+
+/// Query parameters that only track where a click came from
+const TRACKING_PARAMS: [&str; 12] = [
+    "fbclid", "gclid", "dclid", "msclkid", "ocid", "cmpid", "smid", "mc_cid", "mc_eid", "ref",
+    "ref_src", "outputtype",
+];
+
+const MIN_SHINGLES: usize = 3;
+
+struct Story {
+    article: Arc<dyn NewsArticle>,
+    url: String,
+    shingles: HashSet<String>,
+    copies: Vec<Arc<dyn NewsArticle>>,
+}
+
+/// Collapses copies of the same story coming from several providers or outlets
+pub struct StoryDeduplicator {
+    // share of title shingles two stories need in common to count as the same
+    similarity: f64,
+    // looser bound used when the provider already flagged the article as a duplicate
+    flagged_similarity: f64,
+}
+
+impl StoryDeduplicator {
+    pub fn new() -> StoryDeduplicator {
+        StoryDeduplicator {
+            similarity: 0.5,
+            flagged_similarity: 0.35,
+        }
+    }
+
+    /// Keeps the first copy of every story (in the order given) and attaches the others to it
+    pub fn dedupe(&self, articles: Vec<Arc<dyn NewsArticle>>) -> Vec<Arc<dyn NewsArticle>> {
+        let mut stories: Vec<Story> = Vec::new();
+
+        for article in articles {
+            let url = Self::canonical_url(&article.url());
+            let shingles = Self::shingles(&article.title());
+            let threshold = if article.is_duplicate() {
+                self.flagged_similarity
+            } else {
+                self.similarity
+            };
+
+            // very short titles ("Live updates", "Breaking") say too little to compare
+            let comparable = shingles.len() >= MIN_SHINGLES;
+            // articles without a link would all share the empty key
+            let existing = stories.iter_mut().find(|story| {
+                (!url.is_empty() && story.url == url)
+                    || (comparable
+                        && story.shingles.len() >= MIN_SHINGLES
+                        && Self::jaccard(&story.shingles, &shingles) >= threshold)
+            });
+
+            match existing {
+                Some(story) => {
+                    // prefer an original over a copy the provider flagged
+                    if story.article.is_duplicate() && !article.is_duplicate() {
+                        let flagged = std::mem::replace(&mut story.article, article);
+                        story.copies.push(flagged);
+                    } else {
+                        story.copies.push(article);
+                    }
+                }
+                None => stories.push(Story {
+                    article,
+                    url,
+                    shingles,
+                    copies: Vec::new(),
+                }),
+            }
+        }
+
+        stories
+            .into_iter()
+            .map(|story| {
+                if story.copies.is_empty() {
+                    story.article
+                } else {
+                    Arc::new(CoveredArticle {
+                        article: story.article,
+                        coverage: story.copies,
+                    }) as Arc<dyn NewsArticle>
+                }
+            })
+            .collect()
+    }
+
+    /// Normalises a link so the same page shared through different channels compares equal:
+    /// drops the scheme, `www.`/`amp.` hosts, AMP paths, tracking params, fragments and trailing slashes
+    pub fn canonical_url(raw: &str) -> String {
+        let Ok(url) = Url::parse(raw.trim()) else {
+            return raw.trim().trim_end_matches('/').to_lowercase();
+        };
+
+        let host = url.host_str().unwrap_or_default().to_lowercase();
+        let host = host
+            .strip_prefix("www.")
+            .or_else(|| host.strip_prefix("amp."))
+            .unwrap_or(&host)
+            .to_string();
+
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty() && *segment != "amp")
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut path = segments.join("/");
+        for suffix in [".amp", ".amp.html"] {
+            if let Some(stripped) = path.strip_suffix(suffix) {
+                path = stripped.to_string();
+            }
+        }
+
+        let mut query: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| {
+                let key = key.to_lowercase();
+                !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+            })
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        query.sort();
+
+        let mut canonical = format!("{}/{}", host, path);
+        if !query.is_empty() {
+            let query: Vec<String> = query
+                .into_iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            canonical.push('?');
+            canonical.push_str(&query.join("&"));
+        }
+
+        canonical.trim_end_matches('/').to_string()
+    }
+
+    /// Word pairs of the normalised title, without the " - Outlet" suffix some providers append
+    fn shingles(title: &str) -> HashSet<String> {
+        let title = match title.rsplit_once(" - ").or_else(|| title.rsplit_once(" | ")) {
+            Some((head, outlet)) if outlet.split_whitespace().count() <= 4 => head,
+            _ => title,
+        };
+
+        let words: Vec<String> = title
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_string())
+            .collect();
+
+        words
+            .windows(2)
+            .map(|pair| format!("{} {}", pair[0], pair[1]))
+            .collect()
+    }
+
+    fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+        if a.is_empty() || b.is_empty() {
+            return 0.0;
+        }
+
+        let shared = a.intersection(b).count();
+        shared as f64 / (a.len() + b.len() - shared) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rss::data_structures::FeedItem;
+
+    fn article(title: &str, url: &str) -> Arc<dyn NewsArticle> {
+        Arc::new(FeedItem {
+            title: title.to_string(),
+            url: url.to_string(),
+            description: None,
+            content: None,
+            image_url: None,
+            published_at: "2024-01-01T00:00:00Z".to_string(),
+            author: None,
+            source: "Example".to_string(),
+            categories: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn strips_tracking_parameters() {
+        assert_eq!(
+            StoryDeduplicator::canonical_url(
+                "https://www.example.com/story?utm_source=feed&id=7&fbclid=abc&ref=home"
+            ),
+            StoryDeduplicator::canonical_url("https://example.com/story?id=7"),
+        );
+        assert_eq!(
+            StoryDeduplicator::canonical_url("https://example.com/story?b=2&a=1"),
+            "example.com/story?a=1&b=2",
+        );
+    }
+
+    #[test]
+    fn folds_amp_variants() {
+        let canonical = StoryDeduplicator::canonical_url("https://example.com/news/story");
+        for amp in [
+            "https://example.com/news/story/amp",
+            "https://example.com/amp/news/story",
+            "https://amp.example.com/news/story",
+            "https://example.com/news/story?outputType=amp",
+            "https://example.com/news/story.amp.html",
+        ] {
+            assert_eq!(StoryDeduplicator::canonical_url(amp), canonical, "{}", amp);
+        }
+    }
+
+    #[test]
+    fn ignores_trailing_slashes_scheme_and_fragments() {
+        assert_eq!(
+            StoryDeduplicator::canonical_url("http://Example.com/story/#comments"),
+            StoryDeduplicator::canonical_url("https://example.com/story"),
+        );
+    }
+
+    #[test]
+    fn keeps_empty_and_unparsable_urls() {
+        assert_eq!(StoryDeduplicator::canonical_url(""), "");
+        assert_eq!(
+            StoryDeduplicator::canonical_url("  Not A URL/ "),
+            "not a url"
+        );
+    }
+
+    #[test]
+    fn merges_the_same_link() {
+        let stories = StoryDeduplicator::new().dedupe(vec![
+            article(
+                "Parliament passes budget",
+                "https://example.com/budget?utm_medium=rss",
+            ),
+            article(
+                "Budget vote: what changes",
+                "https://www.example.com/budget/",
+            ),
+        ]);
+
+        assert_eq!(stories.len(), 1);
+        assert_eq!(stories[0].title(), "Parliament passes budget");
+        assert_eq!(stories[0].coverage().len(), 1);
+    }
+
+    #[test]
+    fn merges_titles_above_the_threshold() {
+        let stories = StoryDeduplicator::new().dedupe(vec![
+            article(
+                "Rust 2024 edition released with async closures",
+                "https://a.example/rust",
+            ),
+            article(
+                "Rust 2024 edition released with async closures today - Outlet",
+                "https://b.example/rust",
+            ),
+        ]);
+
+        assert_eq!(stories.len(), 1);
+        assert_eq!(stories[0].coverage().len(), 1);
+    }
+
+    #[test]
+    fn keeps_titles_below_the_threshold() {
+        let stories = StoryDeduplicator::new().dedupe(vec![
+            article(
+                "Rust 2024 edition released with async closures",
+                "https://a.example/rust",
+            ),
+            article(
+                "Rust 2024 edition ships with new borrow checker",
+                "https://b.example/rust",
+            ),
+        ]);
+
+        assert_eq!(stories.len(), 2);
+    }
+
+    #[test]
+    fn does_not_merge_missing_links() {
+        let stories = StoryDeduplicator::new().dedupe(vec![
+            article("Storm closes schools across the north", ""),
+            article("Central bank holds interest rates", ""),
+        ]);
+
+        assert_eq!(stories.len(), 2);
+    }
+}
@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use crate::services::{
+    response_cache_service::response_cache::ResponseCache, usage_service::usage::UsageTracker,
+};
+use crate::types::cached_response::CachedResponse;
+
+use reqwest::{
+    RequestBuilder, Response,
+    header::{
+        CONTENT_TYPE, ETAG, HeaderMap, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+        RETRY_AFTER,
+    },
+};
+
+/// Seconds to wait according to a `Retry-After` header, given either as seconds or as an HTTP date
+pub fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&Utc) - Utc::now();
+    Some(wait.num_seconds().max(0) as u64)
+}
+
+/// (limit, remaining) from the rate-limit headers a provider sends, in either the
+/// `X-RateLimit-*` or the standard `RateLimit-*` spelling
+pub fn rate_limit(headers: &HeaderMap) -> (Option<u32>, Option<u32>) {
+    let number = |names: [&str; 2]| {
+        names.iter().find_map(|name| {
+            headers
+                .get(*name)?
+                .to_str()
+                .ok()?
+                .split([',', ';'])
+                .next()?
+                .trim()
+                .parse::<u32>()
+                .ok()
+        })
+    };
+
+    (
+        number(["x-ratelimit-limit", "ratelimit-limit"]),
+        number(["x-ratelimit-remaining", "ratelimit-remaining"]),
+    )
+}
+
+/// Shared request layer for the provider clients: retries timeouts, connection errors,
+/// 5xx and 429 answers with jittered exponential backoff, honouring `Retry-After`
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    // longest wait between attempts, a `Retry-After` beyond it goes back to the caller
+    max_delay: Duration,
+    timeout: Duration,
+    // counts every request that reached the provider against its daily quota
+    usage: Option<(UsageTracker, &'static str)>,
+    cache: Option<ResponseCache>,
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            timeout: Duration::from_secs(15),
+            usage: None,
+            cache: None,
+        }
+    }
+
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn track_usage(mut self, tracker: UsageTracker, provider: &'static str) -> Self {
+        self.usage = Some((tracker, provider));
+        self
+    }
+
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Serves fresh cached bodies without a network call and revalidates stale ones
+    /// with a conditional GET before falling back to a full request
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let Some(cache) = &self.cache else {
+            return self.send_with_retries(request).await;
+        };
+        let Some(key) = request
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .map(|r| ResponseCache::key(r.url()))
+        else {
+            return self.send_with_retries(request).await;
+        };
+
+        let cached = cache.get(&key);
+        let mut request = request;
+
+        if let Some(cached) = &cached {
+            if cache.is_fresh(cached) {
+                return Ok(Self::cached_response(cached));
+            }
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = self.send_with_retries(request).await?;
+
+        if response.status().as_u16() == 304 {
+            if let Some(cached) = &cached {
+                cache.touch(&key);
+                return Ok(Self::cached_response(cached));
+            }
+        }
+
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let content_type = header(CONTENT_TYPE);
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        let body = response.bytes().await?.to_vec();
+
+        let fresh = CachedResponse {
+            status,
+            content_type,
+            body,
+            etag,
+            last_modified,
+            fetched_at: chrono::Utc::now().timestamp(),
+        };
+        cache.store(&key, &fresh);
+
+        Ok(Self::cached_response(&fresh))
+    }
+
+    /// Rebuilds a `Response` the clients can read like a network one
+    fn cached_response(cached: &CachedResponse) -> Response {
+        let mut builder = http::Response::builder().status(cached.status);
+        if let Some(content_type) = &cached.content_type {
+            builder = builder.header(CONTENT_TYPE, content_type);
+        }
+        builder
+            .body(cached.body.clone())
+            .expect("a stored status and content type are always valid")
+            .into()
+    }
+
+    async fn send_with_retries(&self, request: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut attempt = 1;
+
+        loop {
+            // requests with a streaming body can't be replayed, send those only once
+            let Some(current) = request.try_clone() else {
+                return request.timeout(self.timeout).send().await;
+            };
+
+            let result = current.timeout(self.timeout).send().await;
+
+            if let (Some((tracker, provider)), Ok(response)) = (&self.usage, &result) {
+                let (limit, remaining) = rate_limit(response.headers());
+                tracker.record(provider, limit, remaining);
+            }
+
+            let transient = match &result {
+                Ok(response) => {
+                    response.status().is_server_error() || response.status().as_u16() == 429
+                }
+                Err(e) => e.is_timeout() || e.is_connect(),
+            };
+
+            if !transient || attempt >= self.max_attempts {
+                return result;
+            }
+
+            let wait = match &result {
+                Ok(response) => retry_after(response.headers()).map(Duration::from_secs),
+                Err(_) => None,
+            }
+            .unwrap_or_else(|| self.backoff(attempt));
+
+            if wait > self.max_delay {
+                return result;
+            }
+
+            gtk::glib::timeout_future(wait).await;
+            attempt += 1;
+        }
+    }
+
+    /// base * 2^(attempt - 1), capped, with the upper half randomised so clients don't retry in lockstep
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+        let half = exponential / 2;
+        let jitter = rand::rng().random_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(retry_after: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(retry_after).unwrap());
+        headers
+    }
+
+    fn http_date(offset: chrono::Duration) -> String {
+        (Utc::now() + offset)
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string()
+    }
+
+    #[test]
+    fn reads_delta_seconds() {
+        assert_eq!(retry_after(&headers("120")), Some(120));
+        assert_eq!(retry_after(&headers(" 0 ")), Some(0));
+    }
+
+    #[test]
+    fn reads_http_dates() {
+        let wait = retry_after(&headers(&http_date(chrono::Duration::seconds(90)))).unwrap();
+        assert!((88..=90).contains(&wait), "{}", wait);
+    }
+
+    #[test]
+    fn clamps_past_dates_to_zero() {
+        assert_eq!(
+            retry_after(&headers(&http_date(chrono::Duration::hours(-1)))),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn ignores_missing_and_garbage_headers() {
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+        assert_eq!(retry_after(&headers("soon")), None);
+        assert_eq!(retry_after(&headers("-5")), None);
+    }
+
+    #[test]
+    fn backoff_stays_between_half_and_the_capped_exponential() {
+        let policy = RetryPolicy::new();
+
+        // 500ms doubling per attempt, capped at 30s
+        for (attempt, millis) in [(1, 500), (2, 1_000), (4, 4_000), (7, 30_000), (40, 30_000)] {
+            let exponential = Duration::from_millis(millis);
+            for _ in 0..20 {
+                let wait = policy.backoff(attempt);
+                assert!(wait >= exponential / 2, "attempt {}: {:?}", attempt, wait);
+                assert!(wait <= exponential, "attempt {}: {:?}", attempt, wait);
+            }
+        }
+    }
+}
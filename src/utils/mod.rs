@@ -2,4 +2,5 @@ pub mod time_organizer;
 pub mod image_loader;
 pub mod generator;
 pub mod reqwest_error_extension;
-pub mod page_pignation;
\ No newline at end of file
+pub mod page_pignation;pub mod dedup;
+pub mod http;
@@ -1,10 +1,15 @@
 use std::{collections::BTreeMap, sync::Arc};
 
-use crate::types::news_article::NewsArticle;
+use crate::types::{
+    article_page::{PageCursor, PageInfo},
+    news_article::NewsArticle,
+};
 
 #[derive(Debug, Clone)]
 pub struct NewsPagination {
     pub pages: std::collections::BTreeMap<i32, BTreeMap<String, Vec<Arc<dyn NewsArticle>>>>,
+    // cursors each provider handed back with a page, used to request the page after it
+    pub cursors: BTreeMap<i32, BTreeMap<String, PageCursor>>,
     pub current_page: i32,
     pub total_results: usize,
 }
@@ -13,6 +18,7 @@ impl NewsPagination {
     pub fn new() -> Self {
         Self {
             pages: std::collections::BTreeMap::new(),
+            cursors: BTreeMap::new(),
             current_page: 1,
             total_results: 0,
         }
@@ -20,7 +26,27 @@ impl NewsPagination {
 
     pub fn reset(&mut self) {
         self.pages = std::collections::BTreeMap::new();
+        self.cursors = BTreeMap::new();
         self.current_page = 1;
         self.total_results = 0
     }
+
+    /// Remembers where every provider continues after `page`
+    pub fn record(&mut self, page: i32, info: PageInfo) {
+        self.total_results = info.total_results;
+        self.cursors.insert(page, info.next);
+    }
+
+    /// Cursors needed to load `page`, empty for the first page
+    pub fn cursors_for(&self, page: i32) -> BTreeMap<String, PageCursor> {
+        self.cursors.get(&(page - 1)).cloned().unwrap_or_default()
+    }
+
+    /// Whether any provider reported more results after `page`
+    pub fn has_next(&self, page: i32) -> bool {
+        self.cursors
+            .get(&page)
+            .map(|cursors| !cursors.is_empty())
+            .unwrap_or(false)
+    }
 }